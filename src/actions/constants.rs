@@ -1,15 +1,43 @@
 pub const UPDATE_RESOURCES: &str = "update_resources";
+pub const PAY_RESOURCES: &str = "pay_resources";
 pub const BUILD_ROOMS: &str = "build_rooms";
 pub const BUILD_FIELDS: &str = "build_fields";
+pub const BUILD_PASTURES: &str = "build_pastures";
+pub const BUILD_STABLES: &str = "build_stables";
+pub const PLACE_DOGS: &str = "place_dogs";
+pub const SOW: &str = "sow";
+pub const HARVEST_FIELDS: &str = "harvest_fields";
+pub const EXCAVATE: &str = "excavate";
+pub const BUILD_MINES: &str = "build_mines";
 pub const SPAWN_GNOME: &str = "spawn_gnome";
+pub const FORGE_WEAPON: &str = "forge_weapon";
+pub const UPGRADE_WEAPON: &str = "upgrade_weapon";
+pub const UPGRADE_ALL_WEAPONS: &str = "upgrade_all_weapons";
 pub const FIRST_PLAYER: &str = "first_player";
 pub const SET_FIRST_PLAYER: &str = "set_first_player";
+pub const CONVERT_RESOURCES: &str = "convert_resources";
+pub const FEED: &str = "feed";
+pub const BREED_ANIMALS: &str = "breed_animals";
 
 pub const ALL_PLAYER_ACTIONS: &'static [&str] = &[
     UPDATE_RESOURCES,
+    PAY_RESOURCES,
     BUILD_ROOMS,
     BUILD_FIELDS,
+    BUILD_PASTURES,
+    BUILD_STABLES,
+    PLACE_DOGS,
+    SOW,
+    HARVEST_FIELDS,
+    EXCAVATE,
+    BUILD_MINES,
     SPAWN_GNOME,
+    FORGE_WEAPON,
+    UPGRADE_WEAPON,
+    UPGRADE_ALL_WEAPONS,
     FIRST_PLAYER,
+    CONVERT_RESOURCES,
+    FEED,
+    BREED_ANIMALS,
 ];
 
@@ -2,23 +2,58 @@ pub mod constants;
 
 use std::any::Any;
 use std::collections::HashMap;
+use std::collections::hash_map::Entry;
 
 use actions::{constants as ActionsConstants};
-use constants::{GameStatus, FeedingAndBreedingStatus};
-use models::game::{Game, PlayerRoom, PlayerField};
-
-pub trait MoveAction {
+use constants::{GameStatus, FeedingAndBreedingStatus, ResourceType, MAX_WEAPON_STRENGTH};
+use errors::{GameError};
+use models::game::{Game, PlayerRoom, PlayerField, PlayerCavern, PlayerSownField};
+use models::moves::{MovesData};
+use moves::{constants as MovesConstants};
+use moves::feeding::{get_converted_food};
+
+pub trait MoveAction: MoveActionClone {
     fn get_name(&self) -> &str;
 
-    fn perform(&self, game: &mut Game);
+    // Failed action leaves the game untouched
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError>;
+
+    // Action bringing the game back to its current state once this action is performed
+    fn get_undo(&self, game: &Game) -> Result<Box<MoveAction>, GameError>;
+
+    // Tests only, the game reports a failed action with try_perform
+    #[cfg(test)]
+    fn perform(&self, game: &mut Game) {
+        if let Err(e) = self.try_perform(game) {
+            panic!(format!("{}", e));
+        }
+    }
 
     fn get_info(&self) -> String;
 
     fn as_any(&self) -> &Any;
 }
 
+// Every action is cloneable, so options built once can be reused as the base of other options
+pub trait MoveActionClone {
+    fn box_clone(&self) -> Box<MoveAction>;
+}
+
+impl<T: 'static + MoveAction + Clone> MoveActionClone for T {
+    fn box_clone(&self) -> Box<MoveAction> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<MoveAction> {
+    fn clone(&self) -> Box<MoveAction> {
+        self.box_clone()
+    }
+}
+
 
 // ----- Player actions -----
+#[derive(Clone)]
 pub struct Actions {
     pub actions: Vec<Box<MoveAction>>,
     pub args: HashMap<String, String>,
@@ -32,10 +67,102 @@ impl Actions {
             .collect()
     }
 
+    // Either every action is applied or the game is left untouched
+    pub fn try_perform(&self, game: &mut Game) -> Result<UndoLog, GameError> {
+        self._try_perform(game, true)
+    }
+
+    // Part of a move tried while building its options, animals may lack room until the move releases them
+    pub fn try_perform_part(&self, game: &mut Game) -> Result<UndoLog, GameError> {
+        self._try_perform(game, false)
+    }
+
+    fn _try_perform(&self, game: &mut Game, check_rules: bool) -> Result<UndoLog, GameError> {
+        let mut undo_log = UndoLog { actions: Vec::new() };
+        let mut result = self._perform_logged(game, &mut undo_log);
+        // Debug builds check the rules after every move, a move breaking them is reverted like a failed one
+        if cfg!(debug_assertions) && check_rules && result.is_ok() {
+            result = game.validate();
+        }
+        match result {
+            Ok(()) => Ok(undo_log),
+            Err(e) => match undo_log.revert(game) {
+                Ok(()) => Err(e),
+                // Game is left half-applied, so both failures are reported
+                Err(revert_error) => Err(GameError::InvalidState(
+                    format!("{}, then reverting it failed: {}", e, revert_error)
+                )),
+            },
+        }
+    }
+
+    fn _perform_logged(&self, game: &mut Game, undo_log: &mut UndoLog) -> Result<(), GameError> {
+        for action in self.actions.iter() {
+            // Undo is built on the state before the action, but logged only once the action is done
+            let undo = action.get_undo(game)?;
+            action.try_perform(game)?;
+            undo_log.actions.push(undo);
+        }
+        Ok(())
+    }
+
+    #[cfg(test)]
     pub fn perform(&self, game: &mut Game) {
-        self.actions
-            .iter()
-            .for_each(|a| a.perform(game))
+        if let Err(e) = self.try_perform(game) {
+            panic!(format!("{}", e));
+        }
+    }
+
+    // Walks the resource changes in order, so resources gained by the move can pay for its later costs
+    pub fn is_affordable(&self, game: &Game) -> bool {
+        let mut resources: HashMap<String, HashMap<String, i32>> = HashMap::new();
+        for action in self.actions.iter() {
+            let (player, delta) = match action.get_name() {
+                ActionsConstants::UPDATE_RESOURCES => {
+                    let update: &UpdateResources = action.as_any().downcast_ref::<UpdateResources>().unwrap();
+                    (&update.player, update.update_hash.clone())
+                },
+                ActionsConstants::PAY_RESOURCES => {
+                    let payment: &PayResources = action.as_any().downcast_ref::<PayResources>().unwrap();
+                    let delta = payment.price
+                        .iter()
+                        .map(|(key, value)| (key.clone(), -(*value as i32)))
+                        .collect();
+                    (&payment.player, delta)
+                },
+                ActionsConstants::CONVERT_RESOURCES => {
+                    let conversion: &ConvertResources = action.as_any().downcast_ref::<ConvertResources>().unwrap();
+                    let mut delta: HashMap<String, i32> = conversion.conversions
+                        .iter()
+                        .map(|(key, value)| (key.clone(), -(*value as i32)))
+                        .collect();
+                    *delta.entry(ResourceType::Food.str_key()).or_insert(0) += conversion.food as i32;
+                    (&conversion.player, delta)
+                },
+                _ => continue,
+            };
+
+            let stock = match resources.entry(player.clone()) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => match game.find_player(player) {
+                    Ok(found) => entry.insert(found.resources
+                        .iter()
+                        .map(|(key, value)| (key.clone(), *value as i32))
+                        .collect()
+                    ),
+                    // Player who isn't in the game can't pay for anything
+                    Err(_) => return false,
+                },
+            };
+            for (key, value) in delta.into_iter() {
+                let current = stock.entry(key).or_insert(0);
+                *current += value;
+                if *current < 0 {
+                    return false;
+                }
+            }
+        }
+        true
     }
 
     pub fn from_vec(actions: Vec<Box<MoveAction>>) -> Actions {
@@ -46,10 +173,33 @@ impl Actions {
     }
 }
 
+// Inverse actions of the performed ones in the order they were performed
+pub struct UndoLog {
+    pub actions: Vec<Box<MoveAction>>,
+}
+
+impl UndoLog {
+    pub fn get_info(&self) -> Vec<String> {
+        self.actions
+            .iter()
+            .rev()
+            .map(|a| a.get_info())
+            .collect()
+    }
+
+    pub fn revert(&self, game: &mut Game) -> Result<(), GameError> {
+        for action in self.actions.iter().rev() {
+            action.try_perform(game)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Clone)]
 pub struct UpdateResources {
     pub player: String,
-    pub update_hash: HashMap<String, u32>,
+    // Negative values are spent and fail the action when the player lacks them
+    pub update_hash: HashMap<String, i32>,
 }
 
 impl MoveAction for UpdateResources {
@@ -57,10 +207,18 @@ impl MoveAction for UpdateResources {
         ActionsConstants::UPDATE_RESOURCES
     }
 
-    fn perform(&self, game: &mut Game) {
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
         game
-            .get_player_mut(&self.player)
-            .change_resources(self.update_hash.clone());
+            .find_player_mut(&self.player)?
+            .update_resources(self.update_hash.clone())?;
+        Ok(())
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(UpdateResources {
+            player: self.player.clone(),
+            update_hash: self.update_hash.iter().map(|(key, value)| (key.clone(), -value)).collect(),
+        }))
     }
 
     fn get_info(&self) -> String {
@@ -72,6 +230,40 @@ impl MoveAction for UpdateResources {
     }
 }
 
+#[derive(Clone)]
+pub struct PayResources {
+    pub player: String,
+    pub price: HashMap<String, u32>,
+}
+
+impl MoveAction for PayResources {
+    fn get_name(&self) -> &str {
+        ActionsConstants::PAY_RESOURCES
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game
+            .find_player_mut(&self.player)?
+            .spend_resources(self.price.clone())?;
+        Ok(())
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(UpdateResources {
+            player: self.player.clone(),
+            update_hash: self.price.iter().map(|(key, value)| (key.clone(), *value as i32)).collect(),
+        }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Paying {:?} by {:?}", self.price, self.player)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
 #[derive(Clone)]
 pub struct BuildRooms {
     pub player: String,
@@ -83,10 +275,19 @@ impl MoveAction for BuildRooms {
         ActionsConstants::BUILD_ROOMS
     }
 
-    fn perform(&self, game: &mut Game) {
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.check_rooms_available(&self.rooms)?;
         game
-            .get_player_mut(&self.player)
-            .add_rooms(self.rooms.clone());
+            .find_player_mut(&self.player)?
+            .add_rooms(self.rooms.clone())?;
+        Ok(())
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(RemoveRooms {
+            player: self.player.clone(),
+            positions: self.rooms.iter().map(|r| r.position).collect(),
+        }))
     }
 
     fn get_info(&self) -> String {
@@ -98,6 +299,75 @@ impl MoveAction for BuildRooms {
     }
 }
 
+#[derive(Clone)]
+pub struct Excavate {
+    pub player: String,
+    pub caverns: Vec<PlayerCavern>,
+}
+
+impl MoveAction for Excavate {
+    fn get_name(&self) -> &str {
+        ActionsConstants::EXCAVATE
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.excavate(self.caverns.clone())?;
+        Ok(())
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(RemoveCaverns {
+            player: self.player.clone(),
+            positions: self.caverns.iter().map(|c| c.position).collect(),
+        }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Excavating {:?} for {:?}", self.caverns, self.player)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct BuildMines {
+    pub player: String,
+    pub mines: Vec<PlayerCavern>,
+}
+
+impl MoveAction for BuildMines {
+    fn get_name(&self) -> &str {
+        ActionsConstants::BUILD_MINES
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.build_mines(self.mines.clone())?;
+        Ok(())
+    }
+
+    fn get_undo(&self, game: &Game) -> Result<Box<MoveAction>, GameError> {
+        let player = game.find_player(&self.player)?;
+        Ok(Box::new(RestoreCaverns {
+            player: self.player.clone(),
+            caverns: player.caverns
+                .iter()
+                .filter(|c| self.mines.iter().any(|m| m.position == c.position))
+                .cloned()
+                .collect(),
+        }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Building mines {:?} for {:?}", self.mines, self.player)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
 #[derive(Clone)]
 pub struct BuildFields {
     pub player: String,
@@ -109,8 +379,16 @@ impl MoveAction for BuildFields {
         ActionsConstants::BUILD_FIELDS
     }
 
-    fn perform(&self, game: &mut Game) {
-        game.get_player_mut(&self.player).add_fields(self.fields.clone());
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.add_fields(self.fields.clone())?;
+        Ok(())
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(RemoveFields {
+            player: self.player.clone(),
+            positions: self.fields.iter().map(|f| f.position).collect(),
+        }))
     }
 
     fn get_info(&self) -> String {
@@ -123,21 +401,30 @@ impl MoveAction for BuildFields {
 }
 
 #[derive(Clone)]
-pub struct SpawnGnome {
+pub struct BuildPastures {
     pub player: String,
+    pub pastures: Vec<u32>,
 }
 
-impl MoveAction for SpawnGnome {
+impl MoveAction for BuildPastures {
     fn get_name(&self) -> &str {
-        ActionsConstants::SPAWN_GNOME
+        ActionsConstants::BUILD_PASTURES
     }
 
-    fn perform(&self, game: &mut Game) {
-        game.get_player_mut(&self.player).spawn_new_gnome();
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.build_pastures(self.pastures.clone())?;
+        Ok(())
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(RemovePastures {
+            player: self.player.clone(),
+            positions: self.pastures.clone(),
+        }))
     }
 
     fn get_info(&self) -> String {
-        format!("Spawning new gnome for {:?}", self.player)
+        format!("Building pasture on {:?} for {:?}", self.pastures, self.player)
     }
 
     fn as_any(&self) -> &Any {
@@ -146,21 +433,30 @@ impl MoveAction for SpawnGnome {
 }
 
 #[derive(Clone)]
-pub struct SetFirstPlayer {
+pub struct BuildStables {
     pub player: String,
+    pub stables: Vec<u32>,
 }
 
-impl MoveAction for SetFirstPlayer {
+impl MoveAction for BuildStables {
     fn get_name(&self) -> &str {
-        ActionsConstants::SET_FIRST_PLAYER
+        ActionsConstants::BUILD_STABLES
     }
 
-    fn perform(&self, game: &mut Game) {
-        game.first_move = self.player.clone();
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.build_stables(self.stables.clone())?;
+        Ok(())
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(RemoveStables {
+            player: self.player.clone(),
+            positions: self.stables.clone(),
+        }))
     }
 
     fn get_info(&self) -> String {
-        format!("Next first player is {:?}", self.player)
+        format!("Building stables on {:?} for {:?}", self.stables, self.player)
     }
 
     fn as_any(&self) -> &Any {
@@ -168,36 +464,64 @@ impl MoveAction for SetFirstPlayer {
     }
 }
 
-// ----- Game actions -----
-
 #[derive(Clone)]
-pub struct ReorderPlayers {
+pub struct PlaceDogs {
     pub player: String,
+    pub dogs: Vec<u32>,
 }
 
-impl MoveAction for ReorderPlayers {
+impl MoveAction for PlaceDogs {
     fn get_name(&self) -> &str {
-        ActionsConstants::FIRST_PLAYER
+        ActionsConstants::PLACE_DOGS
     }
 
-    fn perform(&self, game: &mut Game) {
-        let old_order = game.order.clone();
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.place_dogs(self.dogs.clone())?;
+        Ok(())
+    }
 
-        let position = old_order
-            .iter()
-            .position(|p| *p == self.player)
-            .unwrap();
+    fn get_undo(&self, game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(RestoreDogs {
+            player: self.player.clone(),
+            dogs: game.find_player(&self.player)?.dogs.clone(),
+        }))
+    }
 
-        let before = old_order.into_iter().take(position).collect::<Vec<_>>();
-        let mut after = game.order.clone().into_iter().skip(position).collect::<Vec<_>>();
+    fn get_info(&self) -> String {
+        format!("Placing dogs on {:?} for {:?}", self.dogs, self.player)
+    }
 
-        after.extend(before);
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
 
-        game.order = after;
+#[derive(Clone)]
+pub struct Sow {
+    pub player: String,
+    pub grain: Vec<u32>,
+    pub vegetables: Vec<u32>,
+}
+
+impl MoveAction for Sow {
+    fn get_name(&self) -> &str {
+        ActionsConstants::SOW
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.sow(self.grain.clone(), self.vegetables.clone())?;
+        Ok(())
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(RemoveSownFields {
+            player: self.player.clone(),
+            positions: self.grain.iter().chain(self.vegetables.iter()).cloned().collect(),
+        }))
     }
 
     fn get_info(&self) -> String {
-        format!("First player is {:?}", self.player)
+        format!("Sowing grain on {:?} and vegetables on {:?} for {:?}", self.grain, self.vegetables, self.player)
     }
 
     fn as_any(&self) -> &Any {
@@ -206,19 +530,29 @@ impl MoveAction for ReorderPlayers {
 }
 
 #[derive(Clone)]
-pub struct IncreaseTurn {}
+pub struct HarvestFields {
+    pub player: String,
+}
 
-impl MoveAction for IncreaseTurn {
+impl MoveAction for HarvestFields {
     fn get_name(&self) -> &str {
-        ""
+        ActionsConstants::HARVEST_FIELDS
     }
 
-    fn perform(&self, game: &mut Game) {
-        game.turn += 1;
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.harvest_fields();
+        Ok(())
+    }
+
+    fn get_undo(&self, game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(RestoreHarvest {
+            player: self.player.clone(),
+            sown_fields: game.find_player(&self.player)?.sown_fields.clone(),
+        }))
     }
 
     fn get_info(&self) -> String {
-        format!("Game turn +1")
+        format!("Harvesting crops from the fields for {:?}", self.player)
     }
 
     fn as_any(&self) -> &Any {
@@ -227,21 +561,26 @@ impl MoveAction for IncreaseTurn {
 }
 
 #[derive(Clone)]
-pub struct ReserveGnome {
+pub struct SpawnGnome {
     pub player: String,
 }
 
-impl MoveAction for ReserveGnome {
+impl MoveAction for SpawnGnome {
     fn get_name(&self) -> &str {
-        ""
+        ActionsConstants::SPAWN_GNOME
     }
 
-    fn perform(&self, game: &mut Game) {
-        game.get_player_mut(&self.player).moved_gnomes += 1;
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.spawn_new_gnome()?;
+        Ok(())
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(RemoveChild { player: self.player.clone() }))
     }
 
     fn get_info(&self) -> String {
-        format!("Reserve gnome for {:?}", self.player)
+        format!("Spawning new gnome for {:?}", self.player)
     }
 
     fn as_any(&self) -> &Any {
@@ -250,22 +589,27 @@ impl MoveAction for ReserveGnome {
 }
 
 #[derive(Clone)]
-pub struct BlockMove {
+pub struct ForgeWeapon {
     pub player: String,
-    pub player_move: String,
+    pub strength: u32,
 }
 
-impl MoveAction for BlockMove {
+impl MoveAction for ForgeWeapon {
     fn get_name(&self) -> &str {
-        ""
+        ActionsConstants::FORGE_WEAPON
     }
 
-    fn perform(&self, game: &mut Game) {
-        game.get_player_mut(&self.player).moves.push(self.player_move.clone());
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.forge_weapon(self.strength)?;
+        Ok(())
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(RemoveWeapon { player: self.player.clone(), strength: self.strength }))
     }
 
     fn get_info(&self) -> String {
-        format!("Blocking move {:?}", self.player_move)
+        format!("Forging weapon of strength {:?} for {:?}", self.strength, self.player)
     }
 
     fn as_any(&self) -> &Any {
@@ -274,21 +618,37 @@ impl MoveAction for BlockMove {
 }
 
 #[derive(Clone)]
-pub struct ChangeStatus {
-    pub status: GameStatus,
+pub struct UpgradeWeapon {
+    pub player: String,
+    pub weapon: u32,
 }
 
-impl MoveAction for ChangeStatus {
+impl MoveAction for UpgradeWeapon {
     fn get_name(&self) -> &str {
-        ""
+        ActionsConstants::UPGRADE_WEAPON
     }
 
-    fn perform(&self, game: &mut Game) {
-        game.status = self.status.clone();
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.upgrade_weapon(self.weapon)?;
+        Ok(())
+    }
+
+    fn get_undo(&self, game: &Game) -> Result<Box<MoveAction>, GameError> {
+        let player = game.find_player(&self.player)?;
+        // Weapon of the full strength is left as it is by the upgrade
+        let upgraded = |weapons: &Vec<u32>| -> Vec<usize> {
+            weapons.iter().position(|w| *w == self.weapon && *w < MAX_WEAPON_STRENGTH).into_iter().collect()
+        };
+        Ok(Box::new(ChangeWeapons {
+            player: self.player.clone(),
+            warriors: upgraded(&player.warriors),
+            moved_warriors: upgraded(&player.moved_warriors),
+            delta: -1,
+        }))
     }
 
     fn get_info(&self) -> String {
-        format!("Changing game status {:?}", self.status)
+        format!("Upgrading weapon {:?} for {:?}", self.weapon, self.player)
     }
 
     fn as_any(&self) -> &Any {
@@ -297,21 +657,35 @@ impl MoveAction for ChangeStatus {
 }
 
 #[derive(Clone)]
-pub struct NextUser {
+pub struct UpgradeAllWeapons {
     pub player: String,
 }
 
-impl MoveAction for NextUser {
+impl MoveAction for UpgradeAllWeapons {
     fn get_name(&self) -> &str {
-        ""
+        ActionsConstants::UPGRADE_ALL_WEAPONS
     }
 
-    fn perform(&self, game: &mut Game) {
-        game.next = self.player.clone();
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.upgrade_all_weapons();
+        Ok(())
+    }
+
+    fn get_undo(&self, game: &Game) -> Result<Box<MoveAction>, GameError> {
+        let player = game.find_player(&self.player)?;
+        let upgraded = |weapons: &Vec<u32>| -> Vec<usize> {
+            (0..weapons.len()).filter(|i| weapons[*i] < MAX_WEAPON_STRENGTH).collect()
+        };
+        Ok(Box::new(ChangeWeapons {
+            player: self.player.clone(),
+            warriors: upgraded(&player.warriors),
+            moved_warriors: upgraded(&player.moved_warriors),
+            delta: -1,
+        }))
     }
 
     fn get_info(&self) -> String {
-        format!("Next user is {:?}", self.player)
+        format!("Upgrading all weapons for {:?}", self.player)
     }
 
     fn as_any(&self) -> &Any {
@@ -319,21 +693,27 @@ impl MoveAction for NextUser {
     }
 }
 
-
 #[derive(Clone)]
-pub struct ReleaseMoves {}
+pub struct SetFirstPlayer {
+    pub player: String,
+}
 
-impl MoveAction for ReleaseMoves {
+impl MoveAction for SetFirstPlayer {
     fn get_name(&self) -> &str {
-        ""
+        ActionsConstants::SET_FIRST_PLAYER
     }
 
-    fn perform(&self, game: &mut Game) {
-        game.players.iter_mut().for_each(|ref mut p| { p.moves.clear() });
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.first_move = self.player.clone();
+        Ok(())
+    }
+
+    fn get_undo(&self, game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(SetFirstPlayer { player: game.first_move.clone() }))
     }
 
     fn get_info(&self) -> String {
-        format!("Releasing all moves")
+        format!("Next first player is {:?}", self.player)
     }
 
     fn as_any(&self) -> &Any {
@@ -342,21 +722,37 @@ impl MoveAction for ReleaseMoves {
 }
 
 #[derive(Clone)]
-pub struct OpenNewMove {
-    pub new_move: String,
+pub struct ConvertResources {
+    pub player: String,
+    pub conversions: HashMap<String, u32>,
+    pub food: u32,
 }
 
-impl MoveAction for OpenNewMove {
+impl MoveAction for ConvertResources {
     fn get_name(&self) -> &str {
-        ""
+        ActionsConstants::CONVERT_RESOURCES
     }
 
-    fn perform(&self, game: &mut Game) {
-        game.available_moves.push(self.new_move.clone())
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        let player = game.find_player_mut(&self.player)?;
+        player.spend_resources(self.conversions.clone())?;
+        player.change_resources(hash_map! {
+            ResourceType::Food.str_key() => self.food
+        });
+        Ok(())
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        let mut update_hash: HashMap<String, i32> = self.conversions
+            .iter()
+            .map(|(key, value)| (key.clone(), *value as i32))
+            .collect();
+        *update_hash.entry(ResourceType::Food.str_key()).or_insert(0) -= self.food as i32;
+        Ok(Box::new(UpdateResources { player: self.player.clone(), update_hash }))
     }
 
     fn get_info(&self) -> String {
-        format!("Adding new available move: {:?}", self.new_move)
+        format!("Converting {:?} into {:?} food for {:?}", self.conversions, self.food, self.player)
     }
 
     fn as_any(&self) -> &Any {
@@ -365,24 +761,1171 @@ impl MoveAction for OpenNewMove {
 }
 
 #[derive(Clone)]
-pub struct SetFeedingAndBreedingStatus {
-    pub status: FeedingAndBreedingStatus,
+pub struct Feed {
+    pub player: String,
+    pub food: u32,
+    pub fines: u32,
 }
 
-impl MoveAction for SetFeedingAndBreedingStatus {
+impl MoveAction for Feed {
     fn get_name(&self) -> &str {
-        ""
+        ActionsConstants::FEED
     }
 
-    fn perform(&self, game: &mut Game) {
-        game.feeding_and_breeding_status = self.status.clone();
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        let player = game.find_player_mut(&self.player)?;
+        player.spend_resources(hash_map! {
+            ResourceType::Food.str_key() => self.food
+        })?;
+        player.fines += self.fines;
+        Ok(())
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(ReturnFood { player: self.player.clone(), food: self.food, fines: self.fines }))
     }
 
     fn get_info(&self) -> String {
-        format!("New feed severity: {:?}", self.status)
+        format!("Feeding {:?} with {:?} food, begging {:?}", self.player, self.food, self.fines)
     }
 
     fn as_any(&self) -> &Any {
         self
     }
-}
\ No newline at end of file
+}
+
+#[derive(Clone)]
+pub struct BreedAnimals {
+    pub player: String,
+    pub animals: Vec<ResourceType>,
+}
+
+impl MoveAction for BreedAnimals {
+    fn get_name(&self) -> &str {
+        ActionsConstants::BREED_ANIMALS
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        let mut update_hash: HashMap<String, u32> = HashMap::new();
+        self.animals.iter().for_each(|a| {
+            update_hash.insert(a.str_key(), 1);
+        });
+        game.find_player_mut(&self.player)?.change_resources(update_hash);
+        Ok(())
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(UpdateResources {
+            player: self.player.clone(),
+            update_hash: self.animals.iter().map(|a| (a.str_key(), -1)).collect(),
+        }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Breeding {:?} for {:?}", self.animals, self.player)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+// ----- Game actions -----
+
+#[derive(Clone)]
+pub struct ReorderPlayers {
+    pub player: String,
+}
+
+impl MoveAction for ReorderPlayers {
+    fn get_name(&self) -> &str {
+        ActionsConstants::FIRST_PLAYER
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        let old_order = game.order.clone();
+
+        let position = old_order
+            .iter()
+            .position(|p| *p == self.player)
+            .ok_or_else(|| GameError::UnknownPlayer(self.player.clone()))?;
+
+        let before = old_order.into_iter().take(position).collect::<Vec<_>>();
+        let mut after = game.order.clone().into_iter().skip(position).collect::<Vec<_>>();
+
+        after.extend(before);
+
+        game.order = after;
+        Ok(())
+    }
+
+    fn get_undo(&self, game: &Game) -> Result<Box<MoveAction>, GameError> {
+        // Order is only rotated, so rotating it back to the previous first player restores it
+        let first = game.order
+            .first()
+            .ok_or_else(|| GameError::InvalidState(String::from("No players to reorder")))?;
+        Ok(Box::new(ReorderPlayers { player: first.clone() }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("First player is {:?}", self.player)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct IncreaseTurn {}
+
+impl MoveAction for IncreaseTurn {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.turn += 1;
+        Ok(())
+    }
+
+    fn get_undo(&self, game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(RestoreTurn { turn: game.turn }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Game turn +1")
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct ReserveGnome {
+    pub player: String,
+}
+
+impl MoveAction for ReserveGnome {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.reserve_gnome()?;
+        Ok(())
+    }
+
+    fn get_undo(&self, game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(ReleaseGnome {
+            player: self.player.clone(),
+            weapon: game.find_player(&self.player)?.get_next_gnome_weapon(),
+        }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Reserve gnome for {:?}", self.player)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct BlockMove {
+    pub player: String,
+    pub player_move: String,
+}
+
+impl MoveAction for BlockMove {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.moves.push(self.player_move.clone());
+        Ok(())
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(UnblockMove { player: self.player.clone(), player_move: self.player_move.clone() }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Blocking move {:?}", self.player_move)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+// Animals without room at the end of a move are converted into food instead of being let go
+#[derive(Clone)]
+pub struct ReleaseAnimals {
+    pub player: String,
+}
+
+impl MoveAction for ReleaseAnimals {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        let player = game.find_player_mut(&self.player)?;
+        let mut update_hash: HashMap<String, i32> = HashMap::new();
+        let mut food = 0;
+        for (animal, count) in player.get_animals_without_room().into_iter() {
+            update_hash.insert(animal.str_key(), -(count as i32));
+            food += get_converted_food(&animal, count);
+        }
+        if !update_hash.is_empty() {
+            update_hash.insert(ResourceType::Food.str_key(), food as i32);
+        }
+        player.update_resources(update_hash)
+    }
+
+    fn get_undo(&self, game: &Game) -> Result<Box<MoveAction>, GameError> {
+        let player = game.find_player(&self.player)?;
+        let mut update_hash: HashMap<String, i32> = HashMap::new();
+        let mut food = 0;
+        for (animal, count) in player.get_animals_without_room().into_iter() {
+            update_hash.insert(animal.str_key(), count as i32);
+            food += get_converted_food(&animal, count);
+        }
+        if !update_hash.is_empty() {
+            update_hash.insert(ResourceType::Food.str_key(), -(food as i32));
+        }
+        Ok(Box::new(UpdateResources { player: self.player.clone(), update_hash }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Converting animals without room into food for {:?}", self.player)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct ChangeStatus {
+    pub status: GameStatus,
+}
+
+impl MoveAction for ChangeStatus {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.status = self.status.clone();
+        Ok(())
+    }
+
+    fn get_undo(&self, game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(ChangeStatus { status: game.status.clone() }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Changing game status {:?}", self.status)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct NextUser {
+    pub player: String,
+}
+
+impl MoveAction for NextUser {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.next = self.player.clone();
+        Ok(())
+    }
+
+    fn get_undo(&self, game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(NextUser { player: game.next.clone() }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Next user is {:?}", self.player)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+
+#[derive(Clone)]
+pub struct ReleaseMoves {}
+
+impl MoveAction for ReleaseMoves {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.players.iter_mut().for_each(|ref mut p| {
+            p.moves.clear();
+            p.moved_gnomes = 0;
+            p.moved_warriors.clear();
+        });
+        Ok(())
+    }
+
+    fn get_undo(&self, game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(ReturnMoves {
+            placements: game.players
+                .iter()
+                .filter(|p| p.moved_gnomes > 0 || !p.moves.is_empty())
+                .map(|p| GnomePlacement {
+                    player: p.name.clone(),
+                    moves: p.moves.clone(),
+                    moved_gnomes: p.moved_gnomes,
+                    moved_warriors: p.moved_warriors.clone(),
+                })
+                .collect(),
+        }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Releasing all moves")
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct GrowUpChildren {}
+
+impl MoveAction for GrowUpChildren {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.players.iter_mut().for_each(|ref mut p| p.grow_up_children());
+        Ok(())
+    }
+
+    fn get_undo(&self, game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(ReturnChildren {
+            children: game.players
+                .iter()
+                .filter(|p| p.child_gnomes > 0)
+                .map(|p| (p.name.clone(), p.child_gnomes))
+                .collect(),
+        }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Children become adults")
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct OpenNewMove {
+    pub new_move: String,
+}
+
+impl MoveAction for OpenNewMove {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.available_moves.push(self.new_move.clone());
+        Ok(())
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(CloseMove { player_move: self.new_move.clone() }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Adding new available move: {:?}", self.new_move)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct SetFeedingAndBreedingStatus {
+    pub status: FeedingAndBreedingStatus,
+}
+
+impl MoveAction for SetFeedingAndBreedingStatus {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.feeding_and_breeding_status = self.status.clone();
+        Ok(())
+    }
+
+    fn get_undo(&self, game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(SetFeedingAndBreedingStatus { status: game.feeding_and_breeding_status.clone() }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("New feed severity: {:?}", self.status)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct TakeAccumulated {
+    pub player_move: String,
+}
+
+impl MoveAction for TakeAccumulated {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        for (_, count) in _get_accumulated_goods(&mut game.moves, &self.player_move)?.into_iter() {
+            *count = 0;
+        }
+        Ok(())
+    }
+
+    fn get_undo(&self, game: &Game) -> Result<Box<MoveAction>, GameError> {
+        // Counters are read on a copy, the game is borrowed immutably here
+        let mut moves = game.moves.clone();
+        Ok(Box::new(ReturnAccumulated {
+            player_move: self.player_move.clone(),
+            goods: _get_accumulated_goods(&mut moves, &self.player_move)?
+                .into_iter()
+                .map(|(resource, count)| (resource.str_key(), *count))
+                .collect(),
+        }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Taking accumulated goods from {:?}", self.player_move)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+// Counters of the goods lying on the action space
+fn _get_accumulated_goods<'a>(moves: &'a mut MovesData, player_move: &str
+) -> Result<Vec<(ResourceType, &'a mut u32)>, GameError> {
+    let goods = match player_move {
+        MovesConstants::DRIFT_MINING => vec![(ResourceType::Stone, &mut moves.drift_mining.stone)],
+        MovesConstants::LOGGING => vec![(ResourceType::Wood, &mut moves.logging.wood)],
+        MovesConstants::WOOD_GATHERING => vec![(ResourceType::Wood, &mut moves.wood_gathering.wood)],
+        MovesConstants::EXCAVATION => vec![(ResourceType::Stone, &mut moves.excavation.stone)],
+        MovesConstants::CLEARING => vec![(ResourceType::Wood, &mut moves.clearing.wood)],
+        MovesConstants::STARTING_PLAYER => vec![(ResourceType::Food, &mut moves.starting_player.food)],
+        MovesConstants::RUBY_MINING => vec![(ResourceType::Gem, &mut moves.ruby_mining.gems)],
+        MovesConstants::SHEEP_FARMING => vec![(ResourceType::Sheep, &mut moves.sheep_farming.sheep)],
+        MovesConstants::DONKEY_FARMING => vec![(ResourceType::Donkey, &mut moves.donkey_farming.donkeys)],
+        MovesConstants::ORE_DELIVERY => vec![
+            (ResourceType::Stone, &mut moves.ore_delivery.stone),
+            (ResourceType::Coal, &mut moves.ore_delivery.coal),
+        ],
+        MovesConstants::RUBY_DELIVERY => vec![(ResourceType::Gem, &mut moves.ruby_delivery.gems)],
+        MovesConstants::ORE_MINING => vec![(ResourceType::Coal, &mut moves.ore_mining.coal)],
+        MovesConstants::SUSTENANCE => vec![(ResourceType::Food, &mut moves.sustenance.food)],
+        MovesConstants::DEPOT => vec![
+            (ResourceType::Wood, &mut moves.depot.wood),
+            (ResourceType::Coal, &mut moves.depot.coal),
+        ],
+        MovesConstants::LARGE_DEPOT => vec![
+            (ResourceType::Wood, &mut moves.large_depot.wood),
+            (ResourceType::Stone, &mut moves.large_depot.stone),
+            (ResourceType::Coal, &mut moves.large_depot.coal),
+        ],
+        _ => return Err(GameError::InvalidArgument(format!("Move {} does not accumulate goods", player_move))),
+    };
+    Ok(goods)
+}
+
+// ----- Undo actions -----
+
+#[derive(Clone)]
+pub struct RemoveRooms {
+    pub player: String,
+    pub positions: Vec<u32>,
+}
+
+impl MoveAction for RemoveRooms {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.remove_rooms(&self.positions)
+    }
+
+    fn get_undo(&self, game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(BuildRooms {
+            player: self.player.clone(),
+            rooms: game.find_player(&self.player)?.rooms
+                .iter()
+                .filter(|r| self.positions.contains(&r.position))
+                .cloned()
+                .collect(),
+        }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Removing rooms on {:?} for {:?}", self.positions, self.player)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct RemoveCaverns {
+    pub player: String,
+    pub positions: Vec<u32>,
+}
+
+impl MoveAction for RemoveCaverns {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.remove_caverns(&self.positions)
+    }
+
+    fn get_undo(&self, game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(Excavate {
+            player: self.player.clone(),
+            caverns: game.find_player(&self.player)?.caverns
+                .iter()
+                .filter(|c| self.positions.contains(&c.position))
+                .cloned()
+                .collect(),
+        }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Filling caverns on {:?} for {:?}", self.positions, self.player)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+// Caverns turned into mines get back their previous types
+#[derive(Clone)]
+pub struct RestoreCaverns {
+    pub player: String,
+    pub caverns: Vec<PlayerCavern>,
+}
+
+impl MoveAction for RestoreCaverns {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.change_caverns(self.caverns.clone())
+    }
+
+    fn get_undo(&self, game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(RestoreCaverns {
+            player: self.player.clone(),
+            caverns: game.find_player(&self.player)?.caverns
+                .iter()
+                .filter(|c| self.caverns.iter().any(|r| r.position == c.position))
+                .cloned()
+                .collect(),
+        }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Restoring caverns {:?} for {:?}", self.caverns, self.player)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct RemoveFields {
+    pub player: String,
+    pub positions: Vec<u32>,
+}
+
+impl MoveAction for RemoveFields {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.remove_fields(&self.positions)
+    }
+
+    fn get_undo(&self, game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(BuildFields {
+            player: self.player.clone(),
+            fields: game.find_player(&self.player)?.fields
+                .iter()
+                .filter(|f| self.positions.contains(&f.position))
+                .cloned()
+                .collect(),
+        }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Removing fields on {:?} for {:?}", self.positions, self.player)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct RemovePastures {
+    pub player: String,
+    pub positions: Vec<u32>,
+}
+
+impl MoveAction for RemovePastures {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.remove_pastures(&self.positions)
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(BuildPastures { player: self.player.clone(), pastures: self.positions.clone() }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Removing pasture on {:?} for {:?}", self.positions, self.player)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct RemoveStables {
+    pub player: String,
+    pub positions: Vec<u32>,
+}
+
+impl MoveAction for RemoveStables {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.remove_stables(&self.positions)
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(BuildStables { player: self.player.clone(), stables: self.positions.clone() }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Removing stables on {:?} for {:?}", self.positions, self.player)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+// Dogs are put back without the placement checks, they were placed before the undone action
+#[derive(Clone)]
+pub struct RestoreDogs {
+    pub player: String,
+    pub dogs: Vec<u32>,
+}
+
+impl MoveAction for RestoreDogs {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.dogs = self.dogs.clone();
+        Ok(())
+    }
+
+    fn get_undo(&self, game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(RestoreDogs { player: self.player.clone(), dogs: game.find_player(&self.player)?.dogs.clone() }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Restoring dogs on {:?} for {:?}", self.dogs, self.player)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct RemoveSownFields {
+    pub player: String,
+    pub positions: Vec<u32>,
+}
+
+impl MoveAction for RemoveSownFields {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.remove_sown_fields(&self.positions)
+    }
+
+    fn get_undo(&self, game: &Game) -> Result<Box<MoveAction>, GameError> {
+        let sown: Vec<&PlayerSownField> = game.find_player(&self.player)?.sown_fields
+            .iter()
+            .filter(|f| self.positions.contains(&f.position))
+            .collect();
+        let positions = |crop: ResourceType| -> Vec<u32> {
+            sown.iter().filter(|f| f.crop == crop).map(|f| f.position).collect()
+        };
+        Ok(Box::new(Sow {
+            player: self.player.clone(),
+            grain: positions(ResourceType::Wheat),
+            vegetables: positions(ResourceType::Pumpkin),
+        }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Removing sown fields on {:?} for {:?}", self.positions, self.player)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct RemoveChild {
+    pub player: String,
+}
+
+impl MoveAction for RemoveChild {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.remove_child()
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(SpawnGnome { player: self.player.clone() }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Removing child gnome of {:?}", self.player)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct ReleaseGnome {
+    pub player: String,
+    pub weapon: Option<u32>,
+}
+
+impl MoveAction for ReleaseGnome {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.release_gnome(self.weapon)
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(ReserveGnome { player: self.player.clone() }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Releasing gnome with weapon {:?} of {:?}", self.weapon, self.player)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct RemoveWeapon {
+    pub player: String,
+    pub strength: u32,
+}
+
+impl MoveAction for RemoveWeapon {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.remove_weapon(self.strength)
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(ForgeWeapon { player: self.player.clone(), strength: self.strength }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Removing weapon {:?} of {:?}", self.strength, self.player)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct UnblockMove {
+    pub player: String,
+    pub player_move: String,
+}
+
+impl MoveAction for UnblockMove {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        let player = game.find_player_mut(&self.player)?;
+        match player.moves.iter().rposition(|m| *m == self.player_move) {
+            Some(position) => {
+                player.moves.remove(position);
+                Ok(())
+            },
+            None => Err(GameError::InvalidState(format!("Move {} is not blocked by {}", self.player_move, self.player))),
+        }
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(BlockMove { player: self.player.clone(), player_move: self.player_move.clone() }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Unblocking move {:?}", self.player_move)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct RestoreTurn {
+    pub turn: u64,
+}
+
+impl MoveAction for RestoreTurn {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.turn = self.turn;
+        Ok(())
+    }
+
+    fn get_undo(&self, game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(RestoreTurn { turn: game.turn }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Restoring game turn {:?}", self.turn)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct RestoreHarvest {
+    pub player: String,
+    // Sown fields as they were before the harvest
+    pub sown_fields: Vec<PlayerSownField>,
+}
+
+impl MoveAction for RestoreHarvest {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.return_harvest(self.sown_fields.clone())
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(HarvestFields { player: self.player.clone() }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Returning harvested crops on {:?} for {:?}", self.sown_fields, self.player)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct ChangeWeapons {
+    pub player: String,
+    // Indexes of the changed weapons
+    pub warriors: Vec<usize>,
+    pub moved_warriors: Vec<usize>,
+    pub delta: i32,
+}
+
+impl MoveAction for ChangeWeapons {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        game.find_player_mut(&self.player)?.change_weapons(&self.warriors, &self.moved_warriors, self.delta)
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(ChangeWeapons {
+            player: self.player.clone(),
+            warriors: self.warriors.clone(),
+            moved_warriors: self.moved_warriors.clone(),
+            delta: -self.delta,
+        }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Changing weapons {:?} by {:?} for {:?}", self.warriors, self.delta, self.player)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct ReturnFood {
+    pub player: String,
+    pub food: u32,
+    pub fines: u32,
+}
+
+impl MoveAction for ReturnFood {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        let player = game.find_player_mut(&self.player)?;
+        if player.fines < self.fines {
+            return Err(GameError::InvalidState(format!("No {} fines of {} to remove", self.fines, self.player)));
+        }
+        player.fines -= self.fines;
+        player.change_resources(hash_map! {
+            ResourceType::Food.str_key() => self.food
+        });
+        Ok(())
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(Feed { player: self.player.clone(), food: self.food, fines: self.fines }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Returning {:?} food and {:?} fines to {:?}", self.food, self.fines, self.player)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct GnomePlacement {
+    pub player: String,
+    pub moves: Vec<String>,
+    pub moved_gnomes: u32,
+    pub moved_warriors: Vec<u32>,
+}
+
+#[derive(Clone)]
+pub struct ReturnMoves {
+    pub placements: Vec<GnomePlacement>,
+}
+
+impl MoveAction for ReturnMoves {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        for placement in self.placements.iter() {
+            if game.find_player(&placement.player)?.moved_gnomes > 0 {
+                return Err(GameError::InvalidState(format!("Gnomes of {} are placed already", placement.player)));
+            }
+        }
+        for placement in self.placements.iter() {
+            let player = game.find_player_mut(&placement.player)?;
+            player.moves = placement.moves.clone();
+            player.moved_gnomes = placement.moved_gnomes;
+            player.moved_warriors = placement.moved_warriors.clone();
+        }
+        Ok(())
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(ReleaseMoves {}))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Returning placed gnomes")
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct ReturnChildren {
+    pub children: HashMap<String, u32>,
+}
+
+impl MoveAction for ReturnChildren {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        for (name, count) in self.children.iter() {
+            let player = game.find_player(name)?;
+            if player.child_gnomes != 0 || player.gnomes < *count {
+                return Err(GameError::InvalidState(format!("No {} grown up gnomes of {} to return", count, name)));
+            }
+        }
+        for (name, count) in self.children.iter() {
+            game.find_player_mut(name)?.return_children(*count)?;
+        }
+        Ok(())
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(GrowUpChildren {}))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Returning children {:?}", self.children)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct CloseMove {
+    pub player_move: String,
+}
+
+impl MoveAction for CloseMove {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        match game.available_moves.iter().rposition(|m| *m == self.player_move) {
+            Some(position) => {
+                game.available_moves.remove(position);
+                Ok(())
+            },
+            None => Err(GameError::InvalidState(format!("Move {} is not available", self.player_move))),
+        }
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(OpenNewMove { new_move: self.player_move.clone() }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Removing available move {:?}", self.player_move)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct ReturnAccumulated {
+    pub player_move: String,
+    pub goods: HashMap<String, u32>,
+}
+
+impl MoveAction for ReturnAccumulated {
+    fn get_name(&self) -> &str {
+        ""
+    }
+
+    fn try_perform(&self, game: &mut Game) -> Result<(), GameError> {
+        for (resource, count) in _get_accumulated_goods(&mut game.moves, &self.player_move)?.into_iter() {
+            *count += *self.goods.get(&resource.str_key()).unwrap_or(&0);
+        }
+        Ok(())
+    }
+
+    fn get_undo(&self, _game: &Game) -> Result<Box<MoveAction>, GameError> {
+        Ok(Box::new(TakeAccumulated { player_move: self.player_move.clone() }))
+    }
+
+    fn get_info(&self) -> String {
+        format!("Returning {:?} to {:?}", self.goods, self.player_move)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
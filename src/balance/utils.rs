@@ -2,9 +2,13 @@ use std::collections::{HashMap};
 use std::fs;
 use serde_yaml;
 
-use constants::{ALL_RESOURCES, TRIBAL_ANIMALS, ResourceType};
-use actions::{constants as ActionsConstants, Actions, UpdateResources, BuildRooms};
+use constants::{ALL_RESOURCES, TRIBAL_ANIMALS, ResourceType, InsideElement};
+use actions::{
+    constants as ActionsConstants, Actions, UpdateResources, PayResources, BuildRooms, ConvertResources, Feed, BreedAnimals,
+    HarvestFields,
+};
 use balance::{constants as BalanceConstants};
+use errors::{GameError};
 use rooms::{constants as RoomConstants};
 use models::game::{Game, Player};
 use moves::{constants as MovesConstants};
@@ -18,63 +22,72 @@ pub struct BalanceConfig {
 }
 
 impl BalanceConfig {
-    pub fn write_to_yaml(&self, path: String) {
-        let file = fs::File::create(path).expect("Error game file");
-        serde_yaml::to_writer(file, &self).unwrap()
+    pub fn write_to_yaml(&self, path: String) -> Result<(), GameError> {
+        let file = fs::File::create(&path)
+            .map_err(|e| GameError::File(format!("Error opening balance file {}: {}", path, e)))?;
+        serde_yaml::to_writer(file, &self)
+            .map_err(|e| GameError::File(format!("Error writing balance file {}: {}", path, e)))
     }
 
-    pub fn read_from_yaml(path: String) -> BalanceConfig {
-        let file = fs::File::open(path)
-            .expect("Error reading game file");
-        serde_yaml::from_reader(file).unwrap()
+    pub fn try_read_from_yaml(path: String) -> Result<BalanceConfig, GameError> {
+        let file = fs::File::open(&path)
+            .map_err(|e| GameError::File(format!("Error reading balance file {}: {}", path, e)))?;
+        serde_yaml::from_reader(file)
+            .map_err(|e| GameError::File(format!("Error parsing balance file {}: {}", path, e)))
     }
 
-    pub fn calculate(balance_item: &HashMap<String, f32>, game: &Game, player: &Player) -> f32 {
+    pub fn try_calculate(balance_item: &HashMap<String, f32>, game: &Game, player: &Player) -> Result<f32, GameError> {
         let mut weight: f32 = 0f32;
 
-        weight += (game.turn as f32) * *balance_item.get(&String::from(BalanceConstants::TURN)).unwrap();
+        weight += (game.turn as f32) * _get_weight(balance_item, BalanceConstants::TURN)?;
 
-        weight += (player.get_free_gnome_slots() as f32) * *balance_item.get(&String::from(BalanceConstants::FREE_GNOME_SLOTS_COUNT)).unwrap();
+        weight += (player.get_free_gnome_slots() as f32) * _get_weight(balance_item, BalanceConstants::FREE_GNOME_SLOTS_COUNT)?;
 
-        weight += (player.get_free_room_slots() as f32) * *balance_item.get(&String::from(BalanceConstants::FREE_SLOTS_FOR_ROOM)).unwrap();
-        weight += (player.get_free_gnome_slots() as f32) * *balance_item.get(&String::from(BalanceConstants::FREE_SLOTS_FOR_FIELD)).unwrap();
+        weight += (player.get_free_room_slots() as f32) * _get_weight(balance_item, BalanceConstants::FREE_SLOTS_FOR_ROOM)?;
+        weight += (player.get_clearing_slots().len() as f32) * _get_weight(balance_item, BalanceConstants::FREE_SLOTS_FOR_FIELD)?;
 
-//        weight += (player.get_free_gnome_slots() as f32) * *balance_item.get(&String::from(BalanceConstants::FREE_SLOTS_FOR_CAVERNS)).unwrap();
-//        weight += (player.get_free_gnome_slots() as f32) * *balance_item.get(&String::from(BalanceConstants::FREE_SLOTS_FOR_MINES)).unwrap();
-//        weight += (player.get_free_gnome_slots() as f32) * *balance_item.get(&String::from(BalanceConstants::NEIGHBOURS_WITH_FIELDS)).unwrap();
+        weight += (player.get_excavation_slots().len() as f32) * _get_weight(balance_item, BalanceConstants::FREE_SLOTS_FOR_CAVERNS)?;
+        weight += (player.get_ore_mine_pair_slots().len() as f32) * _get_weight(balance_item, BalanceConstants::FREE_SLOTS_FOR_MINES)?;
+//        weight += (player.get_free_gnome_slots() as f32) * _get_weight(balance_item, BalanceConstants::NEIGHBOURS_WITH_FIELDS)?;
 
-        weight += (player.get_green_rooms_count() as f32) * *balance_item.get(&String::from(BalanceConstants::GREEN_ROOMS_COUNT)).unwrap();
-        weight += (player.get_ginger_rooms_count() as f32) * *balance_item.get(&String::from(BalanceConstants::GINGER_ROOMS_COUNT)).unwrap();
-        weight += (player.get_yellow_rooms_count() as f32) * *balance_item.get(&String::from(BalanceConstants::YELLOW_ROOMS_COUNT)).unwrap();
+        weight += (player.get_green_rooms_count() as f32) * _get_weight(balance_item, BalanceConstants::GREEN_ROOMS_COUNT)?;
+        weight += (player.get_ginger_rooms_count() as f32) * _get_weight(balance_item, BalanceConstants::GINGER_ROOMS_COUNT)?;
+        weight += (player.get_yellow_rooms_count() as f32) * _get_weight(balance_item, BalanceConstants::YELLOW_ROOMS_COUNT)?;
 
-        weight += (player.get_all_gnomes_count() as f32) * *balance_item.get(&String::from(BalanceConstants::GNOMES_COUNT)).unwrap();
-        weight += (*player.warriors.iter().max().unwrap_or(&0u32) as f32) * *balance_item.get(&String::from(BalanceConstants::MAX_WARRIOR_LEVEL)).unwrap();
-        weight += (player.warriors.iter().count() as f32) * *balance_item.get(&String::from(BalanceConstants::WARRIOR_GNOMES_COUNT)).unwrap();
-        weight += ((player.get_all_gnomes_count() - player.warriors.iter().count() as u32) as f32) * *balance_item.get(&String::from(BalanceConstants::PEACEFUL_GNOMES_COUNT)).unwrap();
+        weight += (player.get_all_gnomes_count() as f32) * _get_weight(balance_item, BalanceConstants::GNOMES_COUNT)?;
+        weight += (*player.warriors.iter().max().unwrap_or(&0u32) as f32) * _get_weight(balance_item, BalanceConstants::MAX_WARRIOR_LEVEL)?;
+        weight += (player.warriors.iter().count() as f32) * _get_weight(balance_item, BalanceConstants::WARRIOR_GNOMES_COUNT)?;
+        weight += ((player.get_all_gnomes_count() - player.warriors.iter().count() as u32) as f32) * _get_weight(balance_item, BalanceConstants::PEACEFUL_GNOMES_COUNT)?;
 
-        weight += (player.fines as f32) * *balance_item.get(&String::from(BalanceConstants::FINES_COUNT)).unwrap();
+        weight += (player.fines as f32) * _get_weight(balance_item, BalanceConstants::FINES_COUNT)?;
 
-        weight += (player.get_all_gnomes_count() as f32) * *balance_item.get(&String::from(BalanceConstants::GNOMES_COUNT)).unwrap();
+        weight += (player.get_all_gnomes_count() as f32) * _get_weight(balance_item, BalanceConstants::GNOMES_COUNT)?;
 
-//        weight += (player.get_free_gnome_slots() as f32) * *balance_item.get(&String::from(BalanceConstants::FREE_ROOMS_COUNT)).unwrap();
-//        weight += (player.get_free_gnome_slots() as f32) * *balance_item.get(&String::from(BalanceConstants::FREE_HALLS_COUNT)).unwrap();
-//        weight += (player.get_free_gnome_slots() as f32) * *balance_item.get(&String::from(BalanceConstants::FREE_MINE_HALLS_COUNT)).unwrap();
-//        weight += (player.get_free_gnome_slots() as f32) * *balance_item.get(&String::from(BalanceConstants::FREE_FIELDS_COUNT)).unwrap();
+        weight += (player.get_furnishable_slots().len() as f32) * _get_weight(balance_item, BalanceConstants::FREE_ROOMS_COUNT)?;
+        weight += (player.get_caverns_count(InsideElement::Hall) as f32) * _get_weight(balance_item, BalanceConstants::FREE_HALLS_COUNT)?;
+        weight += (player.get_caverns_count(InsideElement::MineHall) as f32) * _get_weight(balance_item, BalanceConstants::FREE_MINE_HALLS_COUNT)?;
+//        weight += (player.get_free_gnome_slots() as f32) * _get_weight(balance_item, BalanceConstants::FREE_FIELDS_COUNT)?;
 
-        ALL_RESOURCES.iter().for_each(|r| {
-            weight += (*player.resources.get(&r.str_key()).unwrap_or(&0) as f32) * *balance_item.get(&resource_count(&r)).unwrap();
-
-        });
+        for r in ALL_RESOURCES.iter() {
+            weight += (*player.resources.get(&r.str_key()).unwrap_or(&0) as f32) * _get_weight(balance_item, &resource_count(&r))?;
+        }
 
-        TRIBAL_ANIMALS.iter().for_each(|r| {
-            weight += (player.get_resource_clear_slots(r) as f32) * *balance_item.get(&clear_resource_slots_count(&r)).unwrap();
-            weight += (player.get_resource_max_slots(r) as f32) * *balance_item.get(&max_resource_slots_count(&r)).unwrap();
-        });
+        for r in TRIBAL_ANIMALS.iter() {
+            weight += (player.get_resource_clear_slots(r) as f32) * _get_weight(balance_item, &clear_resource_slots_count(&r))?;
+            weight += (player.get_resource_max_slots(r) as f32) * _get_weight(balance_item, &max_resource_slots_count(&r))?;
+        }
 
-        weight
+        Ok(weight)
     }
 }
 
+fn _get_weight(balance_item: &HashMap<String, f32>, key: &str) -> Result<f32, GameError> {
+    balance_item
+        .get(key)
+        .cloned()
+        .ok_or_else(|| GameError::MissingBalance(String::from(key)))
+}
+
 pub fn resource_count(resource: &ResourceType) -> String{
     format!("{}{}", BalanceConstants::RESOURCE, &resource.str_key())
 }
@@ -149,16 +162,24 @@ pub fn generate_resources_with_items() -> HashMap<String, HashMap<String, f32>>
 
 pub fn generate_moves_with_items() -> HashMap<String, HashMap<String, f32>> {
     let mut hash: HashMap<String, HashMap<String, f32>> = HashMap::new();
-    MovesConstants::TWO_PLAYERS_MOVES.into_iter().for_each(|r| {
+    MovesConstants::ALL_MOVES.into_iter().for_each(|r| {
         hash.insert(String::from(*r), generate_balance_item());
     });
     hash
 }
 
+// Actions weighed by the resources they move, they don't need their own balance
+const RESOURCE_ACTIONS: &'static [&str] = &[
+    ActionsConstants::UPDATE_RESOURCES,
+    ActionsConstants::PAY_RESOURCES,
+    ActionsConstants::BREED_ANIMALS,
+    ActionsConstants::HARVEST_FIELDS,
+];
+
 pub fn generate_actions_with_items() -> HashMap<String, HashMap<String, f32>> {
     let mut hash: HashMap<String, HashMap<String, f32>> = HashMap::new();
     ActionsConstants::ALL_PLAYER_ACTIONS.into_iter().for_each(|r| {
-        if *r != ActionsConstants::UPDATE_RESOURCES {
+        if !RESOURCE_ACTIONS.contains(r) {
             hash.insert(String::from(*r), generate_balance_item());
         }
     });
@@ -173,37 +194,71 @@ pub fn generate_balance_config() -> BalanceConfig {
     }
 }
 
-pub fn get_balance_weight(game: &Game, player_name: &str, balance_config: &BalanceConfig, actions: &Actions) -> i32 {
+pub fn try_get_balance_weight(game: &Game, player_name: &str, balance_config: &BalanceConfig, actions: &Actions
+) -> Result<i32, GameError> {
     let mut weight: f32 = 0f32;
 
-    let player = game.get_player(player_name);
+    let player = game.find_player(player_name)?;
+    let calculate = |items: &HashMap<String, HashMap<String, f32>>, key: &str| -> Result<f32, GameError> {
+        match items.get(key) {
+            Some(balance_item) => BalanceConfig::try_calculate(balance_item, game, player),
+            None => Err(GameError::MissingBalance(String::from(key))),
+        }
+    };
 
-    actions.actions.iter().for_each(|action| {
+    for action in actions.actions.iter() {
         if !ActionsConstants::ALL_PLAYER_ACTIONS.contains(&action.get_name()) {
-            return
+            continue
         }
         match action.get_name() {
             ActionsConstants::UPDATE_RESOURCES => {
                 let resources_update: &UpdateResources = action.as_any().downcast_ref::<UpdateResources>().unwrap();
-                resources_update.update_hash.iter().for_each(|(resource, count)| {
-                    let score = BalanceConfig::calculate(balance_config.resources.get(resource).unwrap(), game, player);
-                    weight += (*count as f32) * score;
-                });
+                for (resource, count) in resources_update.update_hash.iter() {
+                    weight += (*count as f32) * calculate(&balance_config.resources, resource)?;
+                }
+            },
+            ActionsConstants::PAY_RESOURCES => {
+                let pay_resources: &PayResources = action.as_any().downcast_ref::<PayResources>().unwrap();
+                for (resource, count) in pay_resources.price.iter() {
+                    weight -= (*count as f32) * calculate(&balance_config.resources, resource)?;
+                }
             },
             ActionsConstants::BUILD_ROOMS => {
                 let build_rooms: &BuildRooms = action.as_any().downcast_ref::<BuildRooms>().unwrap();
-                build_rooms.rooms.iter().for_each(|room| {
-                    let score = BalanceConfig::calculate(balance_config.rooms.get(&room.room_type).unwrap(), game, player);
-                    weight += score;
-                });
+                for room in build_rooms.rooms.iter() {
+                    weight += calculate(&balance_config.rooms, &room.room_type)?;
+                }
+            },
+            ActionsConstants::CONVERT_RESOURCES => {
+                let convert: &ConvertResources = action.as_any().downcast_ref::<ConvertResources>().unwrap();
+                for (resource, count) in convert.conversions.iter() {
+                    weight -= (*count as f32) * calculate(&balance_config.resources, resource)?;
+                }
+                weight += (convert.food as f32) * calculate(&balance_config.resources, &ResourceType::Food.str_key())?;
+            },
+            ActionsConstants::FEED => {
+                let feed: &Feed = action.as_any().downcast_ref::<Feed>().unwrap();
+                weight -= (feed.food as f32) * calculate(&balance_config.resources, &ResourceType::Food.str_key())?;
+                weight += (feed.fines as f32) * calculate(&balance_config.actions, action.get_name())?;
+            },
+            ActionsConstants::BREED_ANIMALS => {
+                let breed_animals: &BreedAnimals = action.as_any().downcast_ref::<BreedAnimals>().unwrap();
+                for animal in breed_animals.animals.iter() {
+                    weight += calculate(&balance_config.resources, &animal.str_key())?;
+                }
+            },
+            ActionsConstants::HARVEST_FIELDS => {
+                // Every sown field gives one crop
+                let harvest_fields: &HarvestFields = action.as_any().downcast_ref::<HarvestFields>().unwrap();
+                for field in game.find_player(&harvest_fields.player)?.sown_fields.iter() {
+                    weight += calculate(&balance_config.resources, &field.crop.str_key())?;
+                }
             },
             _ => {
-                let action_balance = balance_config.actions.get(action.get_name()).unwrap();
-                weight += BalanceConfig::calculate(action_balance, game, player);
+                weight += calculate(&balance_config.actions, action.get_name())?;
             }
         }
-    });
-
+    }
 
-    weight.round() as i32
+    Ok(weight.round() as i32)
 }
\ No newline at end of file
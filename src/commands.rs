@@ -0,0 +1,233 @@
+use std::collections::HashMap;
+
+use actions::{
+    constants as ActionsConstants, Actions, NextUser, ChangeStatus, ReserveGnome, BlockMove, PlaceDogs, ReleaseAnimals,
+};
+use balance::utils::{BalanceConfig, try_get_balance_weight};
+use constants::{GameStatus, FeedingAndBreedingStatus};
+use errors::{GameError};
+use models::game::{Game};
+use models::history::{HistoryEntry};
+use models::schedule::{RoundSchedule};
+use moves::config::{MovesConfig};
+use moves::core::{ActionsFromMove, get_from_string, collect_actions, _same_args};
+use moves::feeding::{CONVERT_ARG, parse_conversions, get_conversion_actions, collect_feeding_actions, get_feeding_args};
+use moves::ruby::{
+    RUBY_TRADE, get_ruby_trade, get_out_of_order_moves, get_out_of_order_payment,
+    collect_out_of_order_actions, collect_ruby_trade_actions,
+};
+use utils::{get_game_turn_actions, get_start_feeding_and_breeding_actions};
+
+
+pub const NEXT_ROUND: &str = "next_round";
+
+pub const SLOTS_ARG: &str = "slots";
+pub const NEW_MOVE_ARG: &str = "new_move";
+
+pub fn check_status(game: &Game, expected: GameStatus) -> Result<(), GameError> {
+    if game.status != expected {
+        return Err(GameError::WrongStatus { expected, actual: game.status.clone() });
+    }
+    Ok(())
+}
+
+// Move of the next player with the gnome placement and turn bookkeeping around it
+pub fn get_move_command_actions(game: &Game, moves_config: &MovesConfig, name: &str, args: &HashMap<String, String>
+) -> Result<Actions, GameError> {
+    check_status(game, GameStatus::PlayerMove)?;
+    let mov = get_from_string(name)?;
+    let mut move_args = args.clone();
+    let convert = move_args.remove(CONVERT_ARG);
+    let mut actions = mov.try_get_actions(game.clone(), moves_config, &move_args)?;
+
+    actions.actions.insert(0, Box::from(ReserveGnome {
+        player: game.next.clone(),
+    }));
+
+    if game.get_free_moves()?.iter().all(|m| m.get_name() != name) {
+        if get_out_of_order_moves(game)?.iter().all(|m| m.get_name() != name) {
+            return Err(GameError::MoveNotPossible(format!("Move {} is already taken", name)));
+        }
+        actions.actions.insert(0, get_out_of_order_payment(&game.next));
+    }
+
+    if let Some(value) = convert {
+        let conversion = get_conversion_actions(game.find_player(&game.next)?, &parse_conversions(&value)?)?;
+        for (i, action) in conversion.actions.into_iter().enumerate() {
+            actions.actions.insert(i, action);
+        }
+    }
+
+    if !actions.is_affordable(game) {
+        return Err(GameError::NotEnoughResources(
+            format!("Player {} can't afford move {} with {:?}", game.next, name, move_args)
+        ));
+    }
+
+    actions.actions.push(Box::from(BlockMove {
+        player: game.next.clone(),
+        player_move: String::from(name),
+    }));
+
+    if game.is_last_move() {
+        actions.actions.push(Box::from(ChangeStatus {
+            status: GameStatus::NextTurnPending
+        }));
+    } else {
+        actions.actions.push(Box::from(NextUser {
+            player: game.get_next_user()?,
+        }));
+    }
+    Ok(actions)
+}
+
+pub fn get_ruby_trade_command_actions(game: &Game, args: &HashMap<String, String>) -> Result<Actions, GameError> {
+    check_status(game, GameStatus::PlayerMove)?;
+    get_ruby_trade(game, &game.next, args)
+}
+
+pub fn get_place_dogs_command_actions(game: &Game, args: &HashMap<String, String>) -> Result<Actions, GameError> {
+    let slots = args.get(SLOTS_ARG).map(|s| s.as_str()).unwrap_or("");
+    let dogs: Vec<u32> = slots
+        .split(",")
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse::<u32>())
+        .collect::<Result<Vec<u32>, _>>()
+        .map_err(|e| GameError::InvalidArgument(format!("Wrong slots: {}", e)))?;
+    Ok(Actions::from_vec(vec![Box::new(PlaceDogs { player: game.next.clone(), dogs })]))
+}
+
+// Feeding option of the next player, the last player in the order finishes the harvest
+pub fn get_feed_command_actions(game: &Game, args: &HashMap<String, String>) -> Result<Actions, GameError> {
+    check_status(game, GameStatus::FeedingAndBreeding)?;
+    let feeding_args = get_feeding_args(args)?;
+    let mut actions = match collect_feeding_actions(game)?
+        .into_iter()
+        .find(|o| _same_args(&o.actions.args, &feeding_args)) {
+        Some(option) => option.actions,
+        None => return Err(GameError::MoveNotPossible(format!("Feeding is not possible with {:?}", args))),
+    };
+
+    let position = game.order
+        .iter()
+        .position(|p| *p == game.next)
+        .ok_or_else(|| GameError::UnknownPlayer(game.next.clone()))?;
+    match game.order.get(position + 1) {
+        Some(player) => actions.actions.push(Box::from(NextUser {
+            player: player.clone(),
+        })),
+        None => actions.actions.push(Box::from(ChangeStatus {
+            status: GameStatus::NextTurnCalculating,
+        })),
+    }
+    Ok(actions)
+}
+
+pub fn get_command_actions(game: &Game, moves_config: &MovesConfig, command: &str, args: &HashMap<String, String>
+) -> Result<Actions, GameError> {
+    let mut actions = match command {
+        RUBY_TRADE => get_ruby_trade_command_actions(game, args),
+        ActionsConstants::PLACE_DOGS => get_place_dogs_command_actions(game, args),
+        ActionsConstants::FEED => get_feed_command_actions(game, args),
+        _ => get_move_command_actions(game, moves_config, command, args),
+    }?;
+    // Animals brought by the command or left without room by moved dogs don't stay on the board
+    actions.actions.push(Box::new(ReleaseAnimals {
+        player: game.next.clone(),
+    }));
+    Ok(actions)
+}
+
+// Harvest marker of the current round, rounds are counted by the game turn
+pub fn get_round_harvest(game: &Game, schedule: &RoundSchedule) -> Result<Option<FeedingAndBreedingStatus>, GameError> {
+    match schedule.get_rounds(game.players.len() as u32)?.into_iter().nth(game.turn.saturating_sub(1) as usize) {
+        Some(round) => Ok(round.harvest),
+        None => Err(GameError::InvalidState(format!("Round {} is not in the schedule", game.turn))),
+    }
+}
+
+// Round without a harvest is ready for the next one at once, otherwise every player is fed first
+pub fn perform_harvest(game: &mut Game, schedule: &RoundSchedule) -> Result<Vec<String>, GameError> {
+    check_status(game, GameStatus::NextTurnPending)?;
+    let actions = match get_round_harvest(game, schedule)? {
+        Some(status) => get_start_feeding_and_breeding_actions(game, status)?,
+        None => Actions::from_vec(vec![Box::from(ChangeStatus {
+            status: GameStatus::NextTurnCalculating,
+        })]),
+    };
+    actions.try_perform(game)?;
+    Ok(actions.get_info())
+}
+
+// Finished round is harvested first, the new move is opened once the harvest is over.
+// Accumulated goods grow on the moves, so the round change can't be reverted with an undo log
+pub fn perform_next_round(game: &mut Game, moves_config: &MovesConfig, schedule: &RoundSchedule, new_move: Option<&str>
+) -> Result<Vec<String>, GameError> {
+    if let Some(new_move) = new_move {
+        get_from_string(new_move)?;
+    }
+    let mut info: Vec<String> = Vec::new();
+    if game.status != GameStatus::NextTurnCalculating {
+        info = perform_harvest(game, schedule)?;
+        if game.status == GameStatus::FeedingAndBreeding {
+            return Ok(info);
+        }
+    }
+
+    let actions = get_game_turn_actions(game, new_move)?;
+    info.extend(actions.get_info());
+    actions.try_perform(game)?;
+    for mov in game.clone().get_all_moves()? {
+        mov.on_next_turn(game, moves_config);
+    }
+    Ok(info)
+}
+
+// Performs any command written to the history, returns the performed actions info
+pub fn perform_command(game: &mut Game, moves_config: &MovesConfig, schedule: &RoundSchedule, command: &str,
+                       args: &HashMap<String, String>
+) -> Result<Vec<String>, GameError> {
+    let info = if command == NEXT_ROUND {
+        perform_next_round(game, moves_config, schedule, args.get(NEW_MOVE_ARG).map(|m| m.as_str()))?
+    } else {
+        let actions = get_command_actions(game, moves_config, command, args)?;
+        actions.try_perform(game)?;
+        actions.get_info()
+    };
+    Ok(info)
+}
+
+pub fn replay(game: &mut Game, moves_config: &MovesConfig, schedule: &RoundSchedule, entries: &[HistoryEntry]
+) -> Result<(), GameError> {
+    for entry in entries.iter() {
+        if entry.player != game.next {
+            return Err(GameError::InvalidState(format!(
+                "Command {} leading to {} was given by {} but {} is next", entry.command, entry.snapshot, entry.player, game.next
+            )));
+        }
+        perform_command(game, moves_config, schedule, &entry.command, &entry.args)?;
+    }
+    Ok(())
+}
+
+// Options of the next player from the best to the worst one for the given balance, feeding ones during the harvest
+pub fn get_ranked_actions(game: &Game, moves_config: &MovesConfig, balance_config: &BalanceConfig
+) -> Result<Vec<(i32, ActionsFromMove)>, GameError> {
+    let options = if game.status == GameStatus::FeedingAndBreeding {
+        collect_feeding_actions(game)?
+    } else {
+        check_status(game, GameStatus::PlayerMove)?;
+        let mut options = collect_actions(game, moves_config, game.get_free_moves()?)?;
+        options.extend(collect_out_of_order_actions(game, moves_config)?);
+        options.extend(collect_ruby_trade_actions(game)?);
+        options
+    };
+
+    let mut ranked: Vec<(i32, ActionsFromMove)> = Vec::new();
+    for option in options.into_iter() {
+        let weight = try_get_balance_weight(game, &game.next, balance_config, &option.actions)?;
+        ranked.push((weight, option));
+    }
+    ranked.sort_by(|a, b| b.0.cmp(&a.0));
+    Ok(ranked)
+}
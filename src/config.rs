@@ -2,6 +2,8 @@ use std::fs;
 
 use serde_yaml;
 
+use errors::{GameError};
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Config {
     pub folder: String,
@@ -9,8 +11,10 @@ pub struct Config {
 
 
 impl Config {
-    pub fn read_from_yaml() -> Config {
-        let file = fs::File::open("config.yml").expect("Config file not found");
-        serde_yaml::from_reader(file).unwrap()
+    pub fn try_read_from_yaml() -> Result<Config, GameError> {
+        let file = fs::File::open("config.yml")
+            .map_err(|e| GameError::File(format!("Config file not found: {}", e)))?;
+        serde_yaml::from_reader(file)
+            .map_err(|e| GameError::File(format!("Error parsing config file: {}", e)))
     }
 }
@@ -15,7 +15,32 @@ pub enum FeedingAndBreedingStatus {
     FeedingOrBreeding,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldPhase {
+    // Crops are harvested from the fields of every player
+    Harvest,
+    Skipped,
+    // Every player either harvests the fields or breeds animals
+    InsteadOfBreeding,
+}
+
+impl FeedingAndBreedingStatus {
+    // Only feeding is left out with NoFeeding, the fields are harvested as usual
+    pub fn get_field_phase(&self) -> FieldPhase {
+        match *self {
+            FeedingAndBreedingStatus::Normal
+            | FeedingAndBreedingStatus::NoBreeding
+            | FeedingAndBreedingStatus::NoFeeding => FieldPhase::Harvest,
+            FeedingAndBreedingStatus::FeedByOne => FieldPhase::Skipped,
+            FeedingAndBreedingStatus::FeedingOrBreeding => FieldPhase::InsteadOfBreeding,
+        }
+    }
+}
+
+pub const ENTRY_CAVERN_SLOT: u32 = 0;
+pub const INITIAL_CAVERN_SLOT: u32 = 1;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub enum InsideElement {
     Room,
     Hall,
@@ -40,9 +65,37 @@ impl InsideElement {
 pub enum OutsideElement {
     Meadow,
     Field,
+    // Small pasture
     Fence,
+    // Half of a large pasture, both halves are stored next to each other
+    LargeFence,
+}
+
+impl OutsideElement {
+    pub fn str_key(&self) -> String {
+        String::from(match *self {
+            OutsideElement::Meadow => "meadow",
+            OutsideElement::Field => "field",
+            OutsideElement::Fence => "fence",
+            OutsideElement::LargeFence => "large_fence",
+        })
+    }
 }
 
+pub const SMALL_PASTURE_SIZE: u32 = 2;
+pub const LARGE_PASTURE_SIZE: u32 = 4;
+pub const SMALL_PASTURE_WOOD: u32 = 2;
+pub const LARGE_PASTURE_WOOD: u32 = 4;
+pub const STABLE_STONE: u32 = 1;
+pub const MAX_STABLES: u32 = 3;
+
+pub const GRAIN_SOWING_COUNT: u32 = 3;
+pub const VEGETABLE_SOWING_COUNT: u32 = 2;
+pub const MAX_SOWN_FIELDS_PER_CROP: u32 = 2;
+
+pub const MAX_FORGE_STRENGTH: u32 = 8;
+pub const MAX_WEAPON_STRENGTH: u32 = 14;
+
 #[derive(Serialize, Deserialize, Debug, Clone, Hash, PartialEq)]
 pub enum ResourceType {
     Gem,
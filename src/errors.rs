@@ -0,0 +1,42 @@
+use std::fmt;
+
+use constants::{GameStatus};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameError {
+    UnknownPlayer(String),
+    UnknownMove(String),
+    UnknownRoom(String),
+    WrongStatus { expected: GameStatus, actual: GameStatus },
+    // Move exists but none of its options matches the given args
+    MoveNotPossible(String),
+    NotEnoughResources(String),
+    IllegalPlacement(String),
+    NoGnome(String),
+    MissingBalance(String),
+    InvalidArgument(String),
+    // Game state breaks one of the rules checked by Game::validate
+    InvalidState(String),
+    File(String),
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            GameError::UnknownPlayer(ref name) => write!(f, "No player {} found", name),
+            GameError::UnknownMove(ref name) => write!(f, "No move for {} found", name),
+            GameError::UnknownRoom(ref name) => write!(f, "No room for {} found", name),
+            GameError::WrongStatus { ref expected, ref actual } => {
+                write!(f, "Status is not '{:?}' but '{:?}'", expected, actual)
+            },
+            GameError::MoveNotPossible(ref message)
+            | GameError::NotEnoughResources(ref message)
+            | GameError::IllegalPlacement(ref message)
+            | GameError::NoGnome(ref message)
+            | GameError::InvalidArgument(ref message)
+            | GameError::InvalidState(ref message)
+            | GameError::File(ref message) => write!(f, "{}", message),
+            GameError::MissingBalance(ref key) => write!(f, "No balance for {} found", key),
+        }
+    }
+}
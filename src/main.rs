@@ -5,59 +5,61 @@ extern crate serde;
 extern crate serde_yaml;
 extern crate clap;
 extern crate rand;
+extern crate rustyline;
 
 #[macro_use]
 pub mod common;
 
 pub mod actions;
+pub mod commands;
 pub mod config;
 pub mod constants;
+pub mod errors;
 pub mod balance;
 pub mod models;
 pub mod moves;
+pub mod play;
+pub mod render;
 pub mod rooms;
 pub mod score;
 pub mod simulation;
 pub mod utils;
 pub mod test;
 
-use clap::{App, SubCommand, Arg, ArgMatches};
+use std::collections::HashMap;
+use std::process;
+
+use clap::{App, AppSettings, SubCommand, Arg, ArgMatches};
+use rustyline::{Editor};
+use rustyline::error::{ReadlineError};
+use rustyline::history::{DefaultHistory};
 
-use constants::{GameStatus};
 use config::{Config};
+use errors::{GameError};
 use balance::utils::{generate_balance_config, BalanceConfig};
+use commands::{NEXT_ROUND, SLOTS_ARG, NEW_MOVE_ARG, get_command_actions, get_ranked_actions, perform_next_round, replay};
 use models::game::{Game};
+use models::history::{HistoryEntry, Head};
 use moves::config::{MovesConfig};
-use actions::{NextUser, ChangeStatus, ReserveGnome, BlockMove};
+use models::schedule::{RoundSchedule};
+use actions::{constants as ActionsConstants};
 use moves::core::{get_from_string};
-use simulation::{simulate_2_players_game, run_multiple_generations};
+use moves::feeding::{CONVERT_ARG, BREED_ARG, HARVEST_FIELDS_ARG};
+use moves::ruby::{RUBY_TRADE, get_out_of_order_moves};
+use play::{PLAY, HINT, QUIT, CommandCompleter};
+use render::{render_game};
+use simulation::{simulate_n_players_game, run_multiple_generations};
 
 
 fn main() {
-    let config = Config::read_from_yaml();
-
-    let (last_game_file, next_game_file) = Game::get_last_game_file(&config);
-
-    let game: &mut Game = &mut Game::read_from_yaml(&config, last_game_file);
-
-    let moves_config = MovesConfig::read_from_yaml(&config, String::from("moves_config.yml"));
+    let config = Config::try_read_from_yaml().unwrap_or_else(|e| _exit_with_error(e));
+    let mut session = Session::load(config).unwrap_or_else(|e| _exit_with_error(e));
 
     let mut app = App::new("Caverna bot")
-        .version("1.0");
-    app = app.subcommand(SubCommand::with_name("show")
-        .about("display game state"));
-    app = app.subcommand(SubCommand::with_name("decide")
-        .about("make decision"));
-    app = app.subcommand(SubCommand::with_name("next_round")
-        .about("calculates next turn")
-        .arg(Arg::with_name("dry_run")
-            .help("Dry run")
-            .long("dry_run")
-            .short("d")
-        ).arg(Arg::with_name("new_move")
-            .help("New move")
-            .long("new_move")
-            .short("n")));
+        .version("1.0")
+        .subcommands(_get_game_sub_commands(&session.game).unwrap_or_else(|e| _exit_with_error(e)));
+    app = app.subcommand(SubCommand::with_name(PLAY)
+        .about("plays the game in an interactive loop"));
     app = app.subcommand(SubCommand::with_name("generate_balance_config")
         .about("generates balance config yaml")
         .arg(Arg::with_name("output")
@@ -66,7 +68,7 @@ fn main() {
             .long("output")
             .short("o")));
     app = app.subcommand(SubCommand::with_name("simulate_game")
-        .about("simulate game between two players")
+        .about("simulate game between two or more players")
         .arg(Arg::with_name("first_config")
             .takes_value(true)
             .help("First balance config file")
@@ -76,7 +78,17 @@ fn main() {
             .takes_value(true)
             .help("Second balance config file")
             .long("second_config")
-            .short("r")));
+            .short("r")
+        ).arg(Arg::with_name("extra_configs")
+            .takes_value(true)
+            .help("Comma separated balance config files for the players after the second one")
+            .long("extra_configs")
+            .short("x")
+        ).arg(Arg::with_name("schedule")
+            .takes_value(true)
+            .help("Round schedule file in the game folder")
+            .long("schedule")
+            .short("s")));
     app = app.subcommand(SubCommand::with_name("run_multiple_generations"))
         .about("runs simulation to get best config")
         .arg(Arg::with_name("output")
@@ -90,154 +102,446 @@ fn main() {
             .long("generations")
             .short("g")
             .default_value("30"));
-
-    {
-        let available_moves = game.get_free_moves();
-        let sub_commands: Vec<App<'static, 'static>> = available_moves
-            .iter()
-            .map(|m| m.get_sub_command())
-            .collect();
-
-        for cmd in sub_commands {
-            app = app.subcommand(cmd.arg(Arg::with_name("dry_run")
-                .help("Dry run")
-                .long("dry_run")
-                .short("d")
-            ));
-        }
-    }
     let matches = app.get_matches();
 
-    match matches.subcommand() {
-        ("show", Some(_)) => {
-            println!("{:?}", game);
-            println!("{:?}", moves_config);
-        },
-        ("decide", Some(_)) => {
-            _decide(game, &moves_config);
-        },
-        ("next_round", Some(cmd)) => {
-            _next_round_game(cmd, game, &config, &moves_config, next_game_file);
-        },
+    let result = match matches.subcommand() {
         ("generate_balance_config", Some(cmd)) => {
             let output_file: &str = cmd.value_of("output").unwrap_or("balance.yaml");
-            generate_balance_config().write_to_yaml(String::from(output_file));
+            generate_balance_config().write_to_yaml(String::from(output_file))
         },
         ("simulate_game", Some(cmd)) => {
-            let first_path: &str = cmd.value_of("first_config").unwrap_or("balance_1.yaml");
-            let second_path: &str = cmd.value_of("second_config").unwrap_or("balance_2.yaml");
-            let first_config = BalanceConfig::read_from_yaml(String::from(first_path));
-            let second_config = BalanceConfig::read_from_yaml(String::from(second_path));
-            simulate_2_players_game(&moves_config, &first_config, &second_config);
+            _simulate_game(cmd, &session.config, &session.moves_config)
         },
         ("run_multiple_generations", Some(cmd)) => {
-            let generations = cmd.value_of("generations").unwrap_or("30").parse::<i32>().unwrap();
-            run_multiple_generations(&moves_config, generations);
+            _parse_generations(cmd)
+                .and_then(|generations| run_multiple_generations(&session.moves_config, &session.schedule, generations))
+                .map(|_| ())
+        },
+        (PLAY, Some(_)) => {
+            _play(&mut session)
         },
         (name, Some(cmd)) => {
-            _perform_move(&name, cmd, game, &config, &moves_config, next_game_file);
+            _execute(name, cmd, &mut session)
         },
-        _ => return,
+        _ => Ok(()),
     };
+    if let Err(e) = result {
+        _exit_with_error(e);
+    }
 }
 
-fn _decide(game: &mut Game, _moves_config: &MovesConfig) {
-    if game.status != constants::GameStatus::PlayerMove {
-        panic!("Status is not '{:?}'", constants::GameStatus::PlayerMove);
-    }
-    game.available_moves
-        .iter()
-        .for_each(|m| {
-            match get_from_string(m) {
-                Ok(_mov) => {
-                    println!("Exploring '{:?}'", m);
-//                    let actions = mov.get_all_actions(game.clone(), &moves_config);
-//                    actions
-//                        .iter()
-//                        .for_each(|a| println!("{:?} - {:?}", a.weight, a.get_info()));
-//                    let info = actions
-//                        .iter()
-//                        .max_by_key(|a| a.weight)
-//                        .unwrap()
-//                        .get_info();
-//                    println!("Max {:?}", info);
-                },
-                Err(_) => panic!(format!("Not found implementation for command: {}", m)),
-            }
-        });
+// Game state shared by the commands, play mode keeps it in memory between them
+struct Session {
+    config: Config,
+    moves_config: MovesConfig,
+    schedule: RoundSchedule,
+    head: Head,
+    game: Game,
+    next_game_file: String,
 }
 
-fn _perform_move(name: &str, cmd: &ArgMatches, game: &mut Game, config: &Config,
-                 moves_config: &MovesConfig, output_file: String
-) {
-    if game.status != constants::GameStatus::PlayerMove {
-        panic!("Status is not '{:?}'", constants::GameStatus::PlayerMove);
+impl Session {
+    fn load(config: Config) -> Result<Session, GameError> {
+        let (last_game_file, next_game_file) = Game::get_last_game_file(&config)?;
+        let head = Head::try_read_from_yaml(&config, &last_game_file)?;
+        let game = Game::try_read_from_yaml(&config, head.snapshot.clone())?;
+        let moves_config = MovesConfig::try_read_from_yaml(&config, String::from("moves_config.yml"))?;
+        let schedule = RoundSchedule::try_read_from_yaml(&config, String::from("round_schedule.yml"))?;
+        Ok(Session { config, moves_config, schedule, head, game, next_game_file })
     }
-    match get_from_string(name) {
-        Ok(mov) => {
-            let args = mov.parse_args(cmd);
-            let mut actions = mov.get_actions(game.clone(), &moves_config, &args);
-
-            actions.actions.push(Box::from(ReserveGnome {
-                player: game.next.clone(),
-            }));
-
-            actions.actions.push(Box::from(BlockMove {
-                player: game.next.clone(),
-                player_move: String::from(name),
-            }));
-
-            if game.is_last_move() {
-                actions.actions.push(Box::from(ChangeStatus {
-                    status: GameStatus::NextTurnPending
-                }));
-            } else {
-                actions.actions.push(Box::from(NextUser {
-                    player: game.get_next_user(),
-                }));
-            }
 
-            println!("Upcoming actions:");
-            actions.get_info().iter().for_each(|p| println!("{}", p));
+    // Game continues from the snapshot the head was moved to
+    fn move_head(&mut self) -> Result<(), GameError> {
+        self.game = Game::try_read_from_yaml(&self.config, self.head.snapshot.clone())?;
+        self.head.write_to_yaml(&self.config)?;
+        println!("Game continues from {}", self.head.snapshot);
+        if !self.head.redo.is_empty() {
+            println!("Commands to redo: {}", self.head.redo.len());
+        }
+        Ok(())
+    }
+}
 
-            println!("----------");
-            if cmd.occurrences_of("dry_run") == 0 {
-                println!("Applying changes");
-                actions.perform(game);
-                game.write_to_yaml(&config, output_file);
-            } else {
-                println!("Dry run");
+// Commands working on the current game, available both from the command line and in the play mode
+fn _get_game_sub_commands(game: &Game) -> Result<Vec<App<'static, 'static>>, GameError> {
+    let mut sub_commands: Vec<App<'static, 'static>> = Vec::new();
+    sub_commands.push(SubCommand::with_name("show")
+        .about("display game state"));
+    sub_commands.push(SubCommand::with_name("validate")
+        .about("checks game state rules"));
+    sub_commands.push(SubCommand::with_name("decide")
+        .about("make decision")
+        .arg(Arg::with_name("config")
+            .takes_value(true)
+            .help("Balance config file")
+            .long("config")
+            .short("c")
+            .default_value("balance.yaml")
+        ).arg(Arg::with_name("top")
+            .takes_value(true)
+            .help("Number of best options to print")
+            .long("top")
+            .short("t")
+            .default_value("5")
+        ).arg(Arg::with_name("apply")
+            .help("Performs the best option")
+            .long("apply")
+            .short("a")
+        ).arg(Arg::with_name("dry_run")
+            .help("Dry run")
+            .long("dry_run")
+            .short("d")
+        ));
+    sub_commands.push(SubCommand::with_name("replay")
+        .about("replays the history log and compares the result with the last game file"));
+    sub_commands.push(SubCommand::with_name("undo")
+        .about("moves the game back by the given number of commands")
+        .arg(Arg::with_name("steps")
+            .help("Number of commands")
+            .default_value("1")));
+    sub_commands.push(SubCommand::with_name("redo")
+        .about("plays again the given number of undone commands")
+        .arg(Arg::with_name("steps")
+            .help("Number of commands")
+            .default_value("1")));
+    sub_commands.push(SubCommand::with_name("goto")
+        .about("continues the game from the given game file")
+        .arg(Arg::with_name("snapshot")
+            .help("Game file, e.g. 5.yml")
+            .required(true)));
+    sub_commands.push(SubCommand::with_name(NEXT_ROUND)
+        .about("starts the harvest of the round when it has one, otherwise calculates next turn")
+        .arg(Arg::with_name("dry_run")
+            .help("Dry run")
+            .long("dry_run")
+            .short("d")
+        ).arg(Arg::with_name(NEW_MOVE_ARG)
+            .help("New move")
+            .long(NEW_MOVE_ARG)
+            .short("n")
+            .takes_value(true)));
+
+    sub_commands.push(SubCommand::with_name(RUBY_TRADE)
+        .about("spends rubies without placing a gnome")
+        .arg(Arg::with_name("buy")
+            .help("Resource or tile to buy")
+            .required(true)
+        ).arg(Arg::with_name("slot")
+            .help("Slot for the bought tile")
+            .long("slot")
+            .short("s")
+            .takes_value(true)
+        ).arg(Arg::with_name("dry_run")
+            .help("Dry run")
+            .long("dry_run")
+            .short("d")
+        ));
+
+    sub_commands.push(SubCommand::with_name(ActionsConstants::FEED)
+        .about("feeds the gnomes of the next player during the harvest")
+        .arg(Arg::with_name(CONVERT_ARG)
+            .help("Comma separated resource:count pairs converted into food")
+            .long(CONVERT_ARG)
+            .short("c")
+            .takes_value(true)
+        ).arg(Arg::with_name(BREED_ARG)
+            .help("Comma separated animals to breed")
+            .long(BREED_ARG)
+            .short("b")
+            .takes_value(true)
+        ).arg(Arg::with_name(HARVEST_FIELDS_ARG)
+            .help("Harvests the fields instead of breeding")
+            .long(HARVEST_FIELDS_ARG)
+        ).arg(Arg::with_name("dry_run")
+            .help("Dry run")
+            .long("dry_run")
+            .short("d")
+        ));
+
+    sub_commands.push(SubCommand::with_name(ActionsConstants::PLACE_DOGS)
+        .about("moves dogs to the meadows, every dog takes a slot from the list")
+        .arg(Arg::with_name(SLOTS_ARG)
+            .help("Comma separated meadow slots")
+            .required(true)
+        ).arg(Arg::with_name("dry_run")
+            .help("Dry run")
+            .long("dry_run")
+            .short("d")
+        ));
+
+    // Occupied action spaces can be used out of order for a ruby
+    let mut available_moves = game.get_free_moves()?;
+    available_moves.extend(get_out_of_order_moves(game)?);
+    for mov in available_moves {
+        sub_commands.push(mov.get_sub_command().arg(Arg::with_name("dry_run")
+            .help("Dry run")
+            .long("dry_run")
+            .short("d")
+        ).arg(Arg::with_name(CONVERT_ARG)
+            .help("Comma separated resource:count pairs converted into food before the move")
+            .long(CONVERT_ARG)
+            .takes_value(true)
+        ));
+    }
+    Ok(sub_commands)
+}
+
+fn _execute(name: &str, cmd: &ArgMatches, session: &mut Session) -> Result<(), GameError> {
+    match name {
+        "show" => {
+            println!("{}", render_game(&session.game));
+            Ok(())
+        },
+        "validate" => {
+            session.game.validate().map(|_| println!("Game state is valid"))
+        },
+        "decide" => {
+            _decide(cmd, session)
+        },
+        "replay" => {
+            _replay(session)
+        },
+        "undo" => {
+            let steps = _parse_steps(cmd)?;
+            session.head.undo(&HistoryEntry::read_all_from_yaml(&session.config)?, steps)?;
+            session.move_head()
+        },
+        "redo" => {
+            let steps = _parse_steps(cmd)?;
+            session.head.redo(steps)?;
+            session.move_head()
+        },
+        "goto" => {
+            let snapshot = cmd.value_of("snapshot").unwrap();
+            let snapshot = if snapshot.ends_with(".yml") { String::from(snapshot) } else { format!("{}.yml", snapshot) };
+            Game::try_read_from_yaml(&session.config, snapshot.clone())?;
+            session.head.goto(&snapshot);
+            session.move_head()
+        },
+        NEXT_ROUND => {
+            let mut args: HashMap<String, String> = HashMap::new();
+            if let Some(new_move) = cmd.value_of(NEW_MOVE_ARG) {
+                args.insert(String::from(NEW_MOVE_ARG), String::from(new_move));
+            }
+            _run_command(NEXT_ROUND, args, cmd, session)
+        },
+        ActionsConstants::FEED => {
+            let mut args: HashMap<String, String> = HashMap::new();
+            for name in [CONVERT_ARG, BREED_ARG].iter() {
+                if let Some(value) = cmd.value_of(name) {
+                    args.insert(String::from(*name), String::from(value));
+                }
+            }
+            if cmd.occurrences_of(HARVEST_FIELDS_ARG) != 0 {
+                args.insert(String::from(HARVEST_FIELDS_ARG), true.to_string());
+            }
+            _run_command(ActionsConstants::FEED, args, cmd, session)
+        },
+        ActionsConstants::PLACE_DOGS => {
+            let args = hash_map! {
+                String::from(SLOTS_ARG) => String::from(cmd.value_of(SLOTS_ARG).unwrap())
+            };
+            _run_command(ActionsConstants::PLACE_DOGS, args, cmd, session)
+        },
+        RUBY_TRADE => {
+            let mut args: HashMap<String, String> = HashMap::new();
+            args.insert(String::from("buy"), String::from(cmd.value_of("buy").unwrap()));
+            if let Some(slot) = cmd.value_of("slot") {
+                args.insert(String::from("slot"), String::from(slot));
+            }
+            _run_command(RUBY_TRADE, args, cmd, session)
+        },
+        _ => {
+            let mut args = get_from_string(name)?.parse_args(cmd);
+            if let Some(value) = cmd.value_of(CONVERT_ARG) {
+                args.insert(String::from(CONVERT_ARG), String::from(value));
             }
+            _run_command(name, args, cmd, session)
         },
-        Err(_) => panic!(format!("Not found implementation for command: {}", name)),
     }
 }
 
-fn _next_round_game(cmd: &ArgMatches, game: &mut Game, config: &Config,
-                    moves_config: &MovesConfig, output_file: String) {
-    if game.status != constants::GameStatus::NextTurnPending {
-        panic!("Status is not '{:?}'", constants::GameStatus::NextTurnPending);
+// Moves and the bot hint are read line by line, every performed command is saved at once
+fn _play(session: &mut Session) -> Result<(), GameError> {
+    let mut editor: Editor<CommandCompleter, DefaultHistory> = Editor::new()
+        .map_err(|e| GameError::File(format!("Error opening terminal: {}", e)))?;
+    println!("{}", render_game(&session.game));
+    loop {
+        let mut sub_commands = _get_game_sub_commands(&session.game)?;
+        sub_commands.push(SubCommand::with_name(HINT)
+            .about("shows the best options of the bot")
+            .arg(Arg::with_name("config")
+                .takes_value(true)
+                .help("Balance config file")
+                .long("config")
+                .short("c")
+                .default_value("balance.yaml")
+            ).arg(Arg::with_name("top")
+                .takes_value(true)
+                .help("Number of best options to print")
+                .long("top")
+                .short("t")
+                .default_value("1")
+            ));
+        sub_commands.push(SubCommand::with_name(QUIT)
+            .about("leaves the play mode"));
+        editor.set_helper(Some(CommandCompleter {
+            commands: sub_commands.iter().map(|c| String::from(c.get_name())).collect(),
+        }));
+
+        let line = match editor.readline(&format!("{}> ", session.game.next)) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) | Err(ReadlineError::Eof) => return Ok(()),
+            Err(e) => return Err(GameError::File(format!("Error reading command: {}", e))),
+        };
+        let words: Vec<&str> = line.split_whitespace().collect();
+        if words.is_empty() {
+            continue;
+        }
+        let _ = editor.add_history_entry(line.as_str());
+
+        let app = App::new(PLAY)
+            .setting(AppSettings::NoBinaryName)
+            .subcommands(sub_commands);
+        let matches = match app.get_matches_from_safe(words) {
+            Ok(matches) => matches,
+            Err(e) => {
+                println!("{}", e.message);
+                continue;
+            },
+        };
+        let result = match matches.subcommand() {
+            (QUIT, Some(_)) => return Ok(()),
+            (HINT, Some(cmd)) => _decide(cmd, session),
+            (name, Some(cmd)) => _execute(name, cmd, session),
+            _ => Ok(()),
+        };
+        if let Err(e) = result {
+            println!("Error: {}", e);
+        }
+    }
+}
+
+fn _exit_with_error(error: GameError) -> ! {
+    eprintln!("Error: {}", error);
+    process::exit(1);
+}
+
+// Dry run performs the command too, so a failing one is reported, but writes neither the game nor the history
+fn _run_command(command: &str, args: HashMap<String, String>, cmd: &ArgMatches, session: &mut Session
+) -> Result<(), GameError> {
+    let Session { ref config, ref moves_config, ref schedule, ref mut head, ref mut game, ref mut next_game_file } = *session;
+    let dry_run = cmd.occurrences_of("dry_run") != 0;
+    let player = game.next.clone();
+    let performed = if command == NEXT_ROUND {
+        let new_move = args.get(NEW_MOVE_ARG).map(|m| m.as_str());
+        // Round change can't be reverted, so the dry run plays it on a copy of the game
+        if dry_run {
+            perform_next_round(&mut game.clone(), moves_config, schedule, new_move)?
+        } else {
+            perform_next_round(game, moves_config, schedule, new_move)?
+        }
+    } else {
+        let actions = get_command_actions(game, moves_config, command, &args)?;
+        println!("Upcoming actions:");
+        actions.get_info().iter().for_each(|p| println!("{}", p));
+
+        println!("----------");
+        let undo_log = actions.try_perform(game)?;
+        if dry_run {
+            undo_log.revert(game)?;
+        }
+        actions.get_info()
+    };
+
+    if dry_run {
+        println!("Dry run");
+        return Ok(());
     }
+    println!("Applying changes");
+    game.write_to_yaml(config, next_game_file.clone())?;
+    HistoryEntry {
+        parent: head.snapshot.clone(),
+        snapshot: next_game_file.clone(),
+        player,
+        command: String::from(command),
+        args,
+        actions: performed,
+    }.append_to_yaml(config)?;
+    head.advance(next_game_file);
+    head.write_to_yaml(config)?;
+    *next_game_file = Game::get_last_game_file(config)?.1;
+    Ok(())
+}
 
-    game.status = constants::GameStatus::PlayerMove;
-    game.next = game.order.first().unwrap().to_string();
+fn _parse_generations(cmd: &ArgMatches) -> Result<i32, GameError> {
+    let generations = cmd.value_of("generations").unwrap_or("30");
+    generations.parse::<i32>()
+        .map_err(|e| GameError::InvalidArgument(format!("Wrong number of generations {}: {}", generations, e)))
+}
 
-    match cmd.value_of("new_move") {
-        Some(new_move) => match get_from_string(new_move) {
-            Ok(_) => {
-                for mov in game.clone().get_all_moves() {
-                    mov.on_next_turn(game, &moves_config);
-                }
+fn _parse_steps(cmd: &ArgMatches) -> Result<usize, GameError> {
+    let steps = cmd.value_of("steps").unwrap();
+    steps.parse::<usize>()
+        .map_err(|e| GameError::InvalidArgument(format!("Wrong number of steps {}: {}", steps, e)))
+}
 
-                if cmd.occurrences_of("dry_run") == 0 {
-                    println!("Applying changes");
-                    game.write_to_yaml(&config, output_file);
-                } else {
-                    println!("Dry run");
-                }
-            }
-            Err(_) => panic!(format!("Not found implementation for command: {}", new_move)),
+fn _replay(session: &Session) -> Result<(), GameError> {
+    let Session { ref config, ref moves_config, ref schedule, ref game, .. } = *session;
+    let last_game_file = &session.head.snapshot;
+    let entries = HistoryEntry::read_all_from_yaml(config)?;
+    let chain = HistoryEntry::get_chain(&entries, last_game_file)?;
+    let first_game_file = chain.first().map(|e| e.parent.clone()).unwrap_or(String::from(last_game_file));
+
+    let mut replayed = Game::try_read_from_yaml(config, first_game_file.clone())?;
+    replay(&mut replayed, moves_config, schedule, &chain)?;
+    if replayed != *game {
+        return Err(GameError::InvalidState(format!(
+            "Replaying {} commands from {} doesn't give {}", chain.len(), first_game_file, last_game_file
+        )));
+    }
+    println!("Replayed {} commands from {} to {}", chain.len(), first_game_file, last_game_file);
+    Ok(())
+}
+
+fn _simulate_game(cmd: &ArgMatches, config: &Config, moves_config: &MovesConfig) -> Result<(), GameError> {
+    let first_path: &str = cmd.value_of("first_config").unwrap_or("balance_1.yaml");
+    let second_path: &str = cmd.value_of("second_config").unwrap_or("balance_2.yaml");
+    let mut configs = vec![
+        BalanceConfig::try_read_from_yaml(String::from(first_path))?,
+        BalanceConfig::try_read_from_yaml(String::from(second_path))?,
+    ];
+    if let Some(extra_paths) = cmd.value_of("extra_configs") {
+        for path in extra_paths.split(",") {
+            configs.push(BalanceConfig::try_read_from_yaml(String::from(path))?);
+        }
+    }
+    let schedule_path: &str = cmd.value_of("schedule").unwrap_or("round_schedule.yml");
+    let schedule = RoundSchedule::try_read_from_yaml(&config, String::from(schedule_path))?;
+    simulate_n_players_game(&moves_config, &schedule, &configs.iter().collect())?;
+    Ok(())
+}
+
+fn _decide(cmd: &ArgMatches, session: &mut Session) -> Result<(), GameError> {
+    let balance_config = BalanceConfig::try_read_from_yaml(String::from(cmd.value_of("config").unwrap()))?;
+    let top = cmd.value_of("top").unwrap();
+    let top = top.parse::<usize>()
+        .map_err(|e| GameError::InvalidArgument(format!("Wrong number of options {}: {}", top, e)))?;
+
+    let ranked = get_ranked_actions(&session.game, &session.moves_config, &balance_config)?;
+    for (i, &(weight, ref option)) in ranked.iter().take(top).enumerate() {
+        println!("{}. {} {:?} - {}", i + 1, option.move_name, option.actions.args, weight);
+        option.actions.get_info().iter().for_each(|p| println!("    {}", p));
+    }
+
+    if cmd.occurrences_of("apply") == 0 {
+        return Ok(());
+    }
+    match ranked.into_iter().next() {
+        Some((_, best)) => {
+            println!("----------");
+            _run_command(&best.move_name, best.actions.args, cmd, session)
         },
-        _ => {},
+        None => Err(GameError::MoveNotPossible(format!("No options left for {}", session.game.next))),
     }
 }
@@ -3,33 +3,46 @@ use std::fs;
 use std::path;
 use serde_yaml;
 
-use constants::{ResourceType, InsideElement, OutsideElement, GameStatus, FeedingAndBreedingStatus};
+use constants::{
+    ResourceType, InsideElement, OutsideElement, GameStatus, FeedingAndBreedingStatus, TRIBAL_ANIMALS,
+    SMALL_PASTURE_SIZE, LARGE_PASTURE_SIZE, MAX_STABLES, MAX_FORGE_STRENGTH, MAX_WEAPON_STRENGTH,
+    GRAIN_SOWING_COUNT, VEGETABLE_SOWING_COUNT, MAX_SOWN_FIELDS_PER_CROP,
+};
 use config::{Config};
-use rooms::constants::{GREEN_ROOMS, GINGER_ROOMS, YELLOW_ROOMS};
+use errors::{GameError};
+use rooms::constants::{GREEN_ROOMS, GINGER_ROOMS, YELLOW_ROOMS, ALL_ROOMS, ENTRY_LEVEL_DWELLING};
 use rooms::core::{Room, get_from_string as get_room};
 use models::moves::{MovesData};
 use moves::core::{get_from_string as get_move, Move};
+use utils::{get_available_slots, get_available_pair_slots, get_neighbour_slots};
 
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct PlayerCavern {
     pub cavern_type: InsideElement,
     pub position: u32,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct PlayerRoom {
     pub room_type: String,
     pub position: u32,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct PlayerField {
     pub field_type: OutsideElement,
     pub position: u32,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PlayerSownField {
+    pub position: u32,
+    pub crop: ResourceType,
+    pub count: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Player {
     pub name: String,
 
@@ -45,11 +58,50 @@ pub struct Player {
 
     pub fields: Vec<PlayerField>,
 
+    // Both slots of every large pasture, neighbouring large pastures can't be told apart by the fields
+    #[serde(default)]
+    pub large_pastures: Vec<(u32, u32)>,
+
+    #[serde(default)]
+    pub stables: Vec<u32>,
+
+    // Forest slot of every placed dog, a meadow can be guarded by several dogs
+    #[serde(default)]
+    pub dogs: Vec<u32>,
+
+    // Crops left on the fields, harvested one per field phase
+    #[serde(default)]
+    pub sown_fields: Vec<PlayerSownField>,
+
     pub resources: HashMap<String, u32>,
 
     pub moves: Vec<String>,
 
     pub warriors: Vec<u32>,
+
+    // Weapons of the armed gnomes already placed this round
+    #[serde(default)]
+    pub moved_warriors: Vec<u32>,
+}
+
+fn _change_weapons(weapons: &[u32], positions: &[usize], delta: i32) -> Result<Vec<u32>, GameError> {
+    let mut result = weapons.to_vec();
+    for position in positions.iter() {
+        let strength = result.get(*position).map(|w| *w as i32 + delta).unwrap_or(0);
+        if strength < 1 || strength > MAX_WEAPON_STRENGTH as i32 {
+            return Err(GameError::InvalidState(format!("Cannot change weapon {} by {}", position, delta)));
+        }
+        result[*position] = strength as u32;
+    }
+    Ok(result)
+}
+
+// Undo of a placement expects everything it takes away to be still in place
+fn _check_slots(name: &str, positions: &[u32], present: &[u32]) -> Result<(), GameError> {
+    match positions.iter().find(|p| !present.contains(p)) {
+        Some(position) => Err(GameError::InvalidState(format!("No {} to remove on slot {}", name, position))),
+        None => Ok(()),
+    }
 }
 
 impl Player {
@@ -59,43 +111,435 @@ impl Player {
         }
     }
 
-    pub fn spawn_new_gnome(&mut self) {
+    pub fn update_resources(&mut self, delta: HashMap<String, i32>) -> Result<(), GameError> {
+        // Checked before changing anything, so a failed update leaves the stock untouched
+        let missing = delta
+            .iter()
+            .find(|&(key, value)| *self.resources.get(key).unwrap_or(&0) as i32 + *value < 0);
+        if let Some((key, value)) = missing {
+            return Err(GameError::NotEnoughResources(format!("Not enough {} to spend {}", key, -value)));
+        }
+        for (key, value) in delta.into_iter() {
+            let current = self.resources.entry(key).or_insert(0);
+            *current = (*current as i32 + value) as u32;
+        }
+        Ok(())
+    }
+
+    pub fn spend_resources(&mut self, delta: HashMap<String, u32>) -> Result<(), GameError> {
+        if let Some((key, value)) = delta.iter().find(|&(key, value)| *self.resources.get(key).unwrap_or(&0) < *value) {
+            return Err(GameError::NotEnoughResources(format!("Not enough {} to spend {}", key, value)));
+        }
+        for (key, value) in delta.into_iter() {
+            *self.resources.entry(key).or_insert(0) -= value;
+        }
+        Ok(())
+    }
+
+    pub fn can_spawn_gnome(&self) -> bool {
+        self.get_free_gnome_slots() > 0
+    }
+
+    pub fn spawn_new_gnome(&mut self) -> Result<(), GameError> {
+        if !self.can_spawn_gnome() {
+            return Err(GameError::NoGnome(String::from("No free dwelling for a new gnome")));
+        }
         self.child_gnomes += 1;
+        Ok(())
     }
 
-    pub fn reserve_gnome(&mut self) {
-        if self.gnomes >= self.moved_gnomes {
-            panic!("All gnomes are moved");
+    pub fn remove_child(&mut self) -> Result<(), GameError> {
+        if self.child_gnomes == 0 {
+            return Err(GameError::InvalidState(String::from("No child gnome to remove")));
+        }
+        self.child_gnomes -= 1;
+        Ok(())
+    }
+
+    pub fn grow_up_children(&mut self) {
+        self.gnomes += self.child_gnomes;
+        self.child_gnomes = 0;
+    }
+
+    // Inverse of grow_up_children, the gnomes grown up last are children again
+    pub fn return_children(&mut self, count: u32) -> Result<(), GameError> {
+        if self.child_gnomes != 0 || self.gnomes < count {
+            return Err(GameError::InvalidState(format!("No {} grown up gnomes to return", count)));
+        }
+        self.gnomes -= count;
+        self.child_gnomes = count;
+        Ok(())
+    }
+
+    pub fn forge_weapon(&mut self, strength: u32) -> Result<(), GameError> {
+        // Weapon is forged for the gnome placed right now, which has to be unarmed
+        if self.moved_gnomes <= self.moved_warriors.len() as u32 {
+            return Err(GameError::NoGnome(String::from("No unarmed gnome to forge weapon for")));
+        }
+        if strength == 0 || strength > MAX_FORGE_STRENGTH {
+            return Err(GameError::InvalidArgument(format!("Cannot forge weapon of strength {}", strength)));
+        }
+        self.warriors.push(strength);
+        self.moved_warriors.push(strength);
+        Ok(())
+    }
+
+    // Inverse of forge_weapon, the last forged weapon is taken from the placed gnome
+    pub fn remove_weapon(&mut self, strength: u32) -> Result<(), GameError> {
+        if self.warriors.last() != Some(&strength) || self.moved_warriors.last() != Some(&strength) {
+            return Err(GameError::InvalidState(format!("No forged weapon {} to remove", strength)));
+        }
+        self.warriors.pop();
+        self.moved_warriors.pop();
+        Ok(())
+    }
+
+    pub fn upgrade_weapon(&mut self, weapon: u32) -> Result<(), GameError> {
+        let moved_position = self.moved_warriors.iter().position(|w| *w == weapon);
+        let position = self.warriors.iter().position(|w| *w == weapon);
+        match (moved_position, position) {
+            (Some(m), Some(p)) => {
+                self.moved_warriors[m] = (weapon + 1).min(MAX_WEAPON_STRENGTH);
+                self.warriors[p] = (weapon + 1).min(MAX_WEAPON_STRENGTH);
+                Ok(())
+            },
+            _ => Err(GameError::NoGnome(format!("No placed gnome with weapon {}", weapon))),
+        }
+    }
+
+    pub fn upgrade_all_weapons(&mut self) {
+        for weapon in self.warriors.iter_mut().chain(self.moved_warriors.iter_mut()) {
+            *weapon = (*weapon + 1).min(MAX_WEAPON_STRENGTH);
+        }
+    }
+
+    // Weapons are picked by their indexes, so upgrades are undone on the very same gnomes
+    pub fn change_weapons(&mut self, warriors: &[usize], moved_warriors: &[usize], delta: i32) -> Result<(), GameError> {
+        let changed_warriors = _change_weapons(&self.warriors, warriors, delta)?;
+        let changed_moved_warriors = _change_weapons(&self.moved_warriors, moved_warriors, delta)?;
+        self.warriors = changed_warriors;
+        self.moved_warriors = changed_moved_warriors;
+        Ok(())
+    }
+
+    // Unarmed gnomes are placed first, then armed ones from the weakest weapon
+    pub fn get_next_gnome_weapon(&self) -> Option<u32> {
+        let unarmed = self.gnomes - self.warriors.len() as u32;
+        let moved_unarmed = self.moved_gnomes - self.moved_warriors.len() as u32;
+        if unarmed > moved_unarmed {
+            return None;
+        }
+
+        let mut free_warriors = self.warriors.clone();
+        for weapon in self.moved_warriors.iter() {
+            let position = free_warriors.iter().position(|w| w == weapon).unwrap();
+            free_warriors.remove(position);
+        }
+        free_warriors.into_iter().min()
+    }
+
+    pub fn place_next_gnome(&mut self) {
+        if let Some(weapon) = self.get_next_gnome_weapon() {
+            self.moved_warriors.push(weapon);
         }
         self.moved_gnomes += 1;
     }
 
-    pub fn add_rooms(&mut self, new_rooms: Vec<PlayerRoom>) {
+    pub fn reserve_gnome(&mut self) -> Result<(), GameError> {
+        if self.moved_gnomes >= self.gnomes {
+            return Err(GameError::NoGnome(String::from("All gnomes are moved")));
+        }
+        self.place_next_gnome();
+        Ok(())
+    }
+
+    // Inverse of reserve_gnome, the weapon of an armed gnome is the last one placed
+    pub fn release_gnome(&mut self, weapon: Option<u32>) -> Result<(), GameError> {
+        if self.moved_gnomes == 0 || (weapon.is_some() && self.moved_warriors.last() != weapon.as_ref()) {
+            return Err(GameError::InvalidState(format!("No placed gnome with weapon {:?} to release", weapon)));
+        }
+        if weapon.is_some() {
+            self.moved_warriors.pop();
+        }
+        self.moved_gnomes -= 1;
+        Ok(())
+    }
+
+    pub fn add_rooms(&mut self, new_rooms: Vec<PlayerRoom>) -> Result<(), GameError> {
         let mut slots: HashSet<u32> = HashSet::from(
-            self.rooms.iter().map(|r| r.position).collect()
+            self.rooms.iter().map(|r| r.position).collect::<HashSet<u32>>()
         );
+        let furnishable_slots = self.get_furnishable_slots();
         for room in new_rooms.iter() {
-            if slots.contains(&room.position) {
-                panic!(format!("Cannon add room {:?} to position {:?}", room.room_type, room.position));
+            if slots.contains(&room.position) || !furnishable_slots.contains(&room.position) {
+                return Err(GameError::IllegalPlacement(
+                    format!("Cannot add room {:?} to position {:?}", room.room_type, room.position)
+                ));
             }
             slots.insert(room.position);
         }
 
         self.rooms.extend(new_rooms);
+        Ok(())
+    }
+
+    pub fn remove_rooms(&mut self, positions: &[u32]) -> Result<(), GameError> {
+        let rooms: Vec<u32> = self.rooms.iter().map(|r| r.position).collect();
+        _check_slots("room", positions, &rooms)?;
+        self.rooms.retain(|r| !positions.contains(&r.position));
+        Ok(())
+    }
+
+    pub fn excavate(&mut self, new_caverns: Vec<PlayerCavern>) -> Result<(), GameError> {
+        let positions: Vec<u32> = new_caverns.iter().map(|c| c.position).collect();
+        let is_valid = match positions.len() {
+            1 => self.get_excavation_slots().contains(&positions[0]),
+            2 => {
+                let pair = (positions[0].min(positions[1]), positions[0].max(positions[1]));
+                self.get_excavation_pair_slots().contains(&pair)
+            },
+            _ => false,
+        };
+        let is_valid = is_valid && new_caverns.iter().all(|c| {
+            c.cavern_type == InsideElement::Room || c.cavern_type == InsideElement::Hall
+        });
+        if !is_valid {
+            return Err(GameError::IllegalPlacement(format!("Cannot excavate {:?}", new_caverns)));
+        }
+
+        self.caverns.extend(new_caverns);
+        Ok(())
     }
 
-    pub fn add_fields(&mut self, new_fields: Vec<PlayerField>) {
+    pub fn remove_caverns(&mut self, positions: &[u32]) -> Result<(), GameError> {
+        _check_slots("cavern", positions, &self.get_excavated_slots())?;
+        self.caverns.retain(|c| !positions.contains(&c.position));
+        Ok(())
+    }
+
+    // Inverse of build_mines, the caverns get back their previous types
+    pub fn change_caverns(&mut self, caverns: Vec<PlayerCavern>) -> Result<(), GameError> {
+        let positions: Vec<u32> = caverns.iter().map(|c| c.position).collect();
+        _check_slots("cavern", &positions, &self.get_excavated_slots())?;
+        for cavern in caverns.into_iter() {
+            if let Some(current) = self.caverns.iter_mut().find(|c| c.position == cavern.position) {
+                current.cavern_type = cavern.cavern_type;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn build_mines(&mut self, new_mines: Vec<PlayerCavern>) -> Result<(), GameError> {
+        let positions: Vec<u32> = new_mines.iter().map(|c| c.position).collect();
+        let is_valid = match new_mines.len() {
+            1 => new_mines[0].cavern_type == InsideElement::GemMine
+                && self.get_ruby_mine_slots().contains(&positions[0]),
+            2 => {
+                let pair = (positions[0].min(positions[1]), positions[0].max(positions[1]));
+                let mut types: Vec<String> = new_mines.iter().map(|c| c.cavern_type.str_key()).collect();
+                types.sort();
+                types == vec![InsideElement::Mine.str_key(), InsideElement::MineHall.str_key()]
+                    && self.get_ore_mine_pair_slots().contains(&pair)
+            },
+            _ => false,
+        };
+        if !is_valid {
+            return Err(GameError::IllegalPlacement(format!("Cannot build mines {:?}", new_mines)));
+        }
+
+        for mine in new_mines.into_iter() {
+            let cavern = self.caverns.iter_mut().find(|c| c.position == mine.position).unwrap();
+            cavern.cavern_type = mine.cavern_type;
+        }
+        Ok(())
+    }
+
+    pub fn add_fields(&mut self, new_fields: Vec<PlayerField>) -> Result<(), GameError> {
         let mut slots: HashSet<u32> = HashSet::from(
-            self.fields.iter().map(|r| r.position).collect()
+            self.fields.iter().map(|r| r.position).collect::<HashSet<u32>>()
         );
         for field in new_fields.iter() {
             if slots.contains(&field.position) {
-                panic!(format!("Cannon add field {:?} to position {:?}", field.field_type, field.position));
+                return Err(GameError::IllegalPlacement(
+                    format!("Cannot add field {:?} to position {:?}", field.field_type, field.position)
+                ));
             }
             slots.insert(field.position);
         }
 
+        let positions: Vec<u32> = new_fields.iter().map(|f| f.position).collect();
+        let is_valid = match positions.len() {
+            1 => self.get_clearing_slots().contains(&positions[0]),
+            2 => {
+                let pair = (positions[0].min(positions[1]), positions[0].max(positions[1]));
+                self.get_clearing_pair_slots().contains(&pair)
+            },
+            _ => false,
+        };
+        let is_valid = is_valid && new_fields.iter().all(|f| {
+            f.field_type == OutsideElement::Meadow || f.field_type == OutsideElement::Field
+        });
+        if !is_valid {
+            return Err(GameError::IllegalPlacement(format!("Cannot clear forest for {:?}", new_fields)));
+        }
+
         self.fields.extend(new_fields);
+        Ok(())
+    }
+
+    pub fn remove_fields(&mut self, positions: &[u32]) -> Result<(), GameError> {
+        let fields: Vec<u32> = self.fields.iter().map(|f| f.position).collect();
+        _check_slots("field", positions, &fields)?;
+        self.fields.retain(|f| !positions.contains(&f.position));
+        Ok(())
+    }
+
+    pub fn build_pastures(&mut self, positions: Vec<u32>) -> Result<(), GameError> {
+        let is_valid = match positions.len() {
+            1 => self.get_small_pasture_slots().contains(&positions[0]),
+            2 => {
+                let pair = (positions[0].min(positions[1]), positions[0].max(positions[1]));
+                self.get_large_pasture_pair_slots().contains(&pair)
+            },
+            _ => false,
+        };
+        if !is_valid {
+            return Err(GameError::IllegalPlacement(format!("Cannot build pasture on {:?}", positions)));
+        }
+
+        let field_type = match positions.len() {
+            1 => OutsideElement::Fence,
+            _ => {
+                self.large_pastures.push((positions[0].min(positions[1]), positions[0].max(positions[1])));
+                OutsideElement::LargeFence
+            },
+        };
+        for field in self.fields.iter_mut().filter(|f| positions.contains(&f.position)) {
+            field.field_type = field_type.clone();
+        }
+        Ok(())
+    }
+
+    // Inverse of build_pastures, the fenced slots become meadows again
+    pub fn remove_pastures(&mut self, positions: &[u32]) -> Result<(), GameError> {
+        let fenced: Vec<u32> = self.fields
+            .iter()
+            .filter(|f| f.field_type == OutsideElement::Fence || f.field_type == OutsideElement::LargeFence)
+            .map(|f| f.position)
+            .collect();
+        _check_slots("pasture", positions, &fenced)?;
+        for field in self.fields.iter_mut().filter(|f| positions.contains(&f.position)) {
+            field.field_type = OutsideElement::Meadow;
+        }
+        self.large_pastures.retain(|p| !positions.contains(&p.0) && !positions.contains(&p.1));
+        Ok(())
+    }
+
+    pub fn sow(&mut self, grain: Vec<u32>, vegetables: Vec<u32>) -> Result<(), GameError> {
+        if grain.len() as u32 > MAX_SOWN_FIELDS_PER_CROP || vegetables.len() as u32 > MAX_SOWN_FIELDS_PER_CROP {
+            return Err(GameError::InvalidArgument(
+                format!("Cannot sow {:?} grain and {:?} vegetable fields", grain, vegetables)
+            ));
+        }
+        let mut sowable_slots = self.get_sowable_slots();
+        for position in grain.iter().chain(vegetables.iter()) {
+            if !sowable_slots.contains(position) {
+                return Err(GameError::IllegalPlacement(format!("Cannot sow on slot {}", position)));
+            }
+            sowable_slots.retain(|s| s != position);
+        }
+        self.spend_resources(hash_map! {
+            ResourceType::Wheat.str_key() => grain.len() as u32,
+            ResourceType::Pumpkin.str_key() => vegetables.len() as u32
+        })?;
+
+        let sowings = grain
+            .into_iter()
+            .map(|p| (p, ResourceType::Wheat, GRAIN_SOWING_COUNT))
+            .chain(vegetables.into_iter().map(|p| (p, ResourceType::Pumpkin, VEGETABLE_SOWING_COUNT)));
+        for (position, crop, count) in sowings {
+            self.sown_fields.push(PlayerSownField { position, crop, count });
+        }
+        Ok(())
+    }
+
+    // Inverse of sow, the seeds go back to the supply
+    pub fn remove_sown_fields(&mut self, positions: &[u32]) -> Result<(), GameError> {
+        let sown: Vec<u32> = self.sown_fields.iter().map(|f| f.position).collect();
+        _check_slots("sown field", positions, &sown)?;
+        let mut seeds: HashMap<String, u32> = HashMap::new();
+        for field in self.sown_fields.iter().filter(|f| positions.contains(&f.position)) {
+            *seeds.entry(field.crop.str_key()).or_insert(0) += 1;
+        }
+        self.sown_fields.retain(|f| !positions.contains(&f.position));
+        self.change_resources(seeds);
+        Ok(())
+    }
+
+    pub fn harvest_fields(&mut self) {
+        let mut harvest: HashMap<String, u32> = HashMap::new();
+        for field in self.sown_fields.iter_mut() {
+            *harvest.entry(field.crop.str_key()).or_insert(0) += 1;
+            field.count -= 1;
+        }
+        self.sown_fields.retain(|f| f.count > 0);
+        self.change_resources(harvest);
+    }
+
+    // Inverse of harvest_fields, every field given gets one crop back
+    pub fn return_harvest(&mut self, sown_fields: Vec<PlayerSownField>) -> Result<(), GameError> {
+        let mut harvest: HashMap<String, u32> = HashMap::new();
+        for field in sown_fields.iter() {
+            *harvest.entry(field.crop.str_key()).or_insert(0) += 1;
+        }
+        self.spend_resources(harvest)?;
+        self.sown_fields = sown_fields;
+        Ok(())
+    }
+
+    pub fn build_stables(&mut self, positions: Vec<u32>) -> Result<(), GameError> {
+        let mut stable_slots = self.get_stable_slots();
+        for (i, position) in positions.iter().enumerate() {
+            if !stable_slots.contains(position) || self.stables.len() as u32 + i as u32 >= MAX_STABLES {
+                return Err(GameError::IllegalPlacement(format!("Cannot build stable on {:?}", position)));
+            }
+            stable_slots.retain(|s| s != position);
+        }
+        self.stables.extend(positions);
+        Ok(())
+    }
+
+    pub fn remove_stables(&mut self, positions: &[u32]) -> Result<(), GameError> {
+        _check_slots("stable", positions, &self.stables)?;
+        self.stables.retain(|s| !positions.contains(s));
+        Ok(())
+    }
+
+    pub fn place_dogs(&mut self, positions: Vec<u32>) -> Result<(), GameError> {
+        if positions.len() as u32 > self.get_resource(ResourceType::Dog) {
+            return Err(GameError::NotEnoughResources(format!("Not enough dogs to place on {:?}", positions)));
+        }
+        if let Some(position) = positions.iter().find(|p| self.get_field_type(**p) != Some(OutsideElement::Meadow)) {
+            return Err(GameError::IllegalPlacement(format!("Cannot place dog on {:?}", position)));
+        }
+        self.dogs = positions;
+        Ok(())
+    }
+
+    // One dog per meadow gives the most sheep, the rest guard the first meadow
+    pub fn get_best_dog_slots(&self) -> Vec<u32> {
+        let mut meadows: Vec<u32> = self.fields
+            .iter()
+            .filter(|f| f.field_type == OutsideElement::Meadow)
+            .map(|f| f.position)
+            .collect();
+        meadows.sort();
+        if meadows.is_empty() {
+            return vec![];
+        }
+
+        let dogs = self.get_resource(ResourceType::Dog) as usize;
+        (0..dogs).map(|i| if i < meadows.len() { meadows[i] } else { meadows[0] }).collect()
     }
 
     pub fn get_rooms(&self) -> Vec<&Room> {
@@ -145,7 +589,7 @@ impl Player {
     }
 
     pub fn get_free_gnome_slots(&self) -> u32 {
-        self.get_gnomes_slots() - self.gnomes - self.child_gnomes
+        self.get_gnomes_slots().saturating_sub(self.get_all_gnomes_count())
     }
 
     pub fn get_yellow_rooms_count(&self) -> u32 {
@@ -163,19 +607,285 @@ impl Player {
     pub fn get_resource_clear_slots(&self, resource: &ResourceType) -> u32 {
         self.rooms
             .iter()
-            .map(|r| get_room(&r.room_type).unwrap().get_slots().get_clear_slots(resource))
+            .map(|r| get_room(&r.room_type).unwrap().get_player_slots(self).get_clear_slots(resource))
             .sum()
     }
 
     pub fn get_resource_max_slots(&self, resource: &ResourceType) -> u32 {
+        // Dogs don't need a place to stay
+        if *resource == ResourceType::Dog {
+            return self.get_resource(ResourceType::Dog);
+        }
+        self.get_rooms_max_slots(resource) + self.get_outdoor_max_slots(resource)
+    }
+
+    fn get_rooms_max_slots(&self, resource: &ResourceType) -> u32 {
         self.rooms
             .iter()
-            .map(|r| get_room(&r.room_type).unwrap().get_slots().get_max_slots(resource))
+            .map(|r| get_room(&r.room_type).unwrap().get_player_slots(self).get_max_slots(resource))
             .sum()
     }
+
+    // Every enclosure holds a single animal type. Animals not fitting into the rooms get the biggest enclosures first,
+    // the type missing the most room picks first, and the enclosures left empty can take any type
+    pub fn get_outdoor_max_slots(&self, resource: &ResourceType) -> u32 {
+        if !TRIBAL_ANIMALS.contains(resource) {
+            return 0;
+        }
+
+        let mut missing: Vec<u32> = TRIBAL_ANIMALS
+            .iter()
+            .map(|a| {
+                let indoor = self.get_rooms_max_slots(a) + self.get_guarded_sheep_slots(a);
+                self.get_resource(a.clone()).saturating_sub(indoor)
+            })
+            .collect();
+
+        let mut slots = self.get_guarded_sheep_slots(resource);
+        for (capacity, types) in self.get_enclosures().into_iter() {
+            let owner = (0..TRIBAL_ANIMALS.len())
+                .filter(|i| missing[*i] > 0 && types.contains(&TRIBAL_ANIMALS[*i]))
+                .max_by_key(|i| missing[*i]);
+            match owner {
+                Some(i) => {
+                    missing[i] = missing[i].saturating_sub(capacity);
+                    if TRIBAL_ANIMALS[i] == *resource {
+                        slots += capacity;
+                    }
+                },
+                None => if types.contains(resource) {
+                    slots += capacity;
+                },
+            }
+        }
+        slots
+    }
+
+    // Pastures and stables outside of them with the animal types they can hold, the biggest ones first
+    pub fn get_enclosures(&self) -> Vec<(u32, Vec<ResourceType>)> {
+        let mut result: Vec<(u32, Vec<ResourceType>)> = self.get_pastures()
+            .iter()
+            .map(|p| {
+                let size = if p.len() == 1 { SMALL_PASTURE_SIZE } else { LARGE_PASTURE_SIZE };
+                // Every stable inside a pasture doubles its capacity
+                let stables = p.iter().filter(|s| self.stables.contains(s)).count() as u32;
+                (size << stables, TRIBAL_ANIMALS.to_vec())
+            })
+            .collect();
+
+        for stable in self.stables.iter() {
+            let types: Vec<ResourceType> = match self.get_field_type(*stable) {
+                // Sheep on a guarded meadow are counted with the dogs
+                Some(OutsideElement::Meadow) => TRIBAL_ANIMALS
+                    .iter()
+                    .filter(|a| !(**a == ResourceType::Sheep && self.dogs.contains(stable)))
+                    .cloned()
+                    .collect(),
+                // Stable in the forest holds only a wild boar
+                None => vec![ResourceType::Hippo],
+                _ => vec![],
+            };
+            if !types.is_empty() {
+                result.push((1, types));
+            }
+        }
+        result.sort_by(|a, b| b.0.cmp(&a.0));
+        result
+    }
+
+    // Animals the player has no room for, the ones over the max slots are listed
+    pub fn get_animals_without_room(&self) -> Vec<(ResourceType, u32)> {
+        TRIBAL_ANIMALS
+            .iter()
+            .map(|a| (a.clone(), self.get_resource(a.clone()).saturating_sub(self.get_resource_max_slots(a))))
+            .filter(|&(_, count)| count > 0)
+            .collect()
+    }
+
+    // Meadow guarded by dogs holds one sheep more than there are dogs
+    fn get_guarded_sheep_slots(&self, resource: &ResourceType) -> u32 {
+        if *resource != ResourceType::Sheep {
+            return 0;
+        }
+        let mut meadows: Vec<u32> = self.dogs
+            .iter()
+            .filter(|d| self.get_field_type(**d) == Some(OutsideElement::Meadow))
+            .cloned()
+            .collect();
+        let dogs = meadows.len() as u32;
+        meadows.sort();
+        meadows.dedup();
+        dogs + meadows.len() as u32
+    }
+
+    pub fn can_afford(&self, price: &HashMap<String, u32>) -> bool {
+        price.iter().all(|(key, value)| *self.resources.get(key).unwrap_or(&0) >= *value)
+    }
+
+    pub fn get_excavated_slots(&self) -> Vec<u32> {
+        self.caverns.iter().map(|c| c.position).collect()
+    }
+
+    pub fn get_caverns_count(&self, cavern_type: InsideElement) -> u32 {
+        self.caverns.iter().filter(|c| c.cavern_type == cavern_type).count() as u32
+    }
+
+    pub fn get_excavation_slots(&self) -> HashSet<u32> {
+        get_available_slots(self.get_excavated_slots())
+    }
+
+    pub fn get_excavation_pair_slots(&self) -> HashSet<(u32, u32)> {
+        get_available_pair_slots(self.get_excavated_slots())
+    }
+
+    pub fn get_ore_mine_pair_slots(&self) -> HashSet<(u32, u32)> {
+        let tunnels: Vec<u32> = self.caverns
+            .iter()
+            .filter(|c| c.cavern_type == InsideElement::Hall)
+            .map(|c| c.position)
+            .collect();
+        let mut result: HashSet<(u32, u32)> = HashSet::new();
+        for tunnel in tunnels.iter() {
+            for neighbour in get_neighbour_slots(*tunnel) {
+                if neighbour > *tunnel && tunnels.contains(&neighbour) {
+                    result.insert((*tunnel, neighbour));
+                }
+            }
+        }
+        result
+    }
+
+    pub fn get_ruby_mine_slots(&self) -> Vec<u32> {
+        self.caverns
+            .iter()
+            .filter(|c| c.cavern_type == InsideElement::Hall || c.cavern_type == InsideElement::MineHall)
+            .map(|c| c.position)
+            .collect()
+    }
+
+    pub fn get_sowable_slots(&self) -> Vec<u32> {
+        let mut slots: Vec<u32> = self.fields
+            .iter()
+            .filter(|f| f.field_type == OutsideElement::Field)
+            .filter(|f| self.sown_fields.iter().all(|s| s.position != f.position))
+            .map(|f| f.position)
+            .collect();
+        slots.sort();
+        slots
+    }
+
+    // Crops both in the supply and on the fields
+    pub fn get_crops(&self, crop: ResourceType) -> u32 {
+        let sown: u32 = self.sown_fields.iter().filter(|f| f.crop == crop).map(|f| f.count).sum();
+        self.get_resource(crop) + sown
+    }
+
+    pub fn get_cleared_slots(&self) -> Vec<u32> {
+        self.fields.iter().map(|f| f.position).collect()
+    }
+
+    pub fn get_field_type(&self, position: u32) -> Option<OutsideElement> {
+        self.fields.iter().find(|f| f.position == position).map(|f| f.field_type.clone())
+    }
+
+    pub fn get_fields_count(&self, field_type: OutsideElement) -> u32 {
+        self.fields.iter().filter(|f| f.field_type == field_type).count() as u32
+    }
+
+    pub fn get_clearing_slots(&self) -> HashSet<u32> {
+        // The first twin can be placed anywhere in the forest
+        if self.fields.is_empty() {
+            return (0..12).collect();
+        }
+        get_available_slots(self.get_cleared_slots())
+    }
+
+    pub fn get_clearing_pair_slots(&self) -> HashSet<(u32, u32)> {
+        if self.fields.is_empty() {
+            let mut result: HashSet<(u32, u32)> = HashSet::new();
+            for slot in 0..12 {
+                for neighbour in get_neighbour_slots(slot) {
+                    if neighbour > slot {
+                        result.insert((slot, neighbour));
+                    }
+                }
+            }
+            return result;
+        }
+        get_available_pair_slots(self.get_cleared_slots())
+    }
+
+    pub fn get_small_pasture_slots(&self) -> Vec<u32> {
+        self.fields
+            .iter()
+            .filter(|f| f.field_type == OutsideElement::Meadow)
+            .map(|f| f.position)
+            .collect()
+    }
+
+    pub fn get_large_pasture_pair_slots(&self) -> HashSet<(u32, u32)> {
+        let meadows = self.get_small_pasture_slots();
+        let mut result: HashSet<(u32, u32)> = HashSet::new();
+        for meadow in meadows.iter() {
+            for neighbour in get_neighbour_slots(*meadow) {
+                if neighbour > *meadow && meadows.contains(&neighbour) {
+                    result.insert((*meadow, neighbour));
+                }
+            }
+        }
+        result
+    }
+
+    // Large pasture is listed once, on the field of its lower slot
+    pub fn get_pastures(&self) -> Vec<Vec<u32>> {
+        let mut result: Vec<Vec<u32>> = Vec::new();
+        for field in self.fields.iter() {
+            match field.field_type {
+                OutsideElement::Fence => result.push(vec![field.position]),
+                OutsideElement::LargeFence => {
+                    if let Some(&(first, second)) = self.large_pastures.iter().find(|p| p.0 == field.position) {
+                        result.push(vec![first, second]);
+                    }
+                },
+                _ => (),
+            }
+        }
+        result
+    }
+
+    pub fn get_stable_slots(&self) -> Vec<u32> {
+        if self.stables.len() as u32 >= MAX_STABLES {
+            return vec![];
+        }
+        (0..12)
+            .filter(|s| !self.stables.contains(s))
+            .filter(|s| self.get_field_type(*s) != Some(OutsideElement::Field))
+            .collect()
+    }
+
+    pub fn get_furnishable_slots(&self) -> Vec<u32> {
+        self.caverns
+            .iter()
+            .filter(|c| c.cavern_type == InsideElement::Room)
+            .filter(|c| self.rooms.iter().find(|r| r.position == c.position).is_none())
+            .map(|c| c.position)
+            .collect()
+    }
+
+    pub fn get_room_price(&self, room: &Room) -> HashMap<String, u32> {
+        let mut price = room.get_price();
+        for built_room in self.get_rooms() {
+            for (key, discount) in built_room.get_price_discount().into_iter() {
+                if let Some(value) = price.get_mut(&key) {
+                    *value = value.saturating_sub(discount);
+                }
+            }
+        }
+        price
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Game {
     pub turn: u64,
 
@@ -194,29 +904,21 @@ pub struct Game {
 }
 
 impl Game {
-    pub fn get_player(&self, player_name: &str) -> &Player {
+    pub fn find_player(&self, player_name: &str) -> Result<&Player, GameError> {
         self.players
             .iter()
-            .find(|p| p.name.eq(player_name))
-            .unwrap()
-    }
-
-    pub fn get_player_mut(&mut self, player_name: &String) -> &mut Player {
-        self.players
-            .iter_mut()
-            .find(|p| p.name == *player_name)
-            .unwrap()
+            .find(|p| p.name == player_name)
+            .ok_or_else(|| GameError::UnknownPlayer(String::from(player_name)))
     }
 
-    pub fn get_current_player_mut(&mut self) -> &mut Player {
-        let current_player_name = &self.next;
+    pub fn find_player_mut(&mut self, player_name: &str) -> Result<&mut Player, GameError> {
         self.players
             .iter_mut()
-            .find(|p| p.name == *current_player_name)
-            .unwrap()
+            .find(|p| p.name == player_name)
+            .ok_or_else(|| GameError::UnknownPlayer(String::from(player_name)))
     }
 
-    pub fn get_free_moves(&self) -> Vec<&Move> {
+    pub fn get_free_moves(&self) -> Result<Vec<&Move>, GameError> {
         self.available_moves
             .iter()
             .filter(|m| {
@@ -227,17 +929,60 @@ impl Game {
                 }
                 true
             })
-            .map(|m| get_move(&m).unwrap())
+            .map(|m| get_move(&m))
             .collect()
     }
 
-    pub fn get_all_moves(&self) -> Vec<&Move> {
+    pub fn get_all_moves(&self) -> Result<Vec<&Move>, GameError> {
         self.available_moves
             .iter()
-            .map(|m| get_move(&m).unwrap())
+            .map(|m| get_move(&m))
             .collect()
     }
 
+    pub fn is_room_available(&self, room_type: &str) -> bool {
+        if room_type == ENTRY_LEVEL_DWELLING {
+            return false;
+        }
+        match get_room(room_type) {
+            Ok(room) => !room.is_unique() || self.players
+                .iter()
+                .all(|p| p.rooms.iter().find(|r| r.room_type == room_type).is_none()),
+            Err(_) => false,
+        }
+    }
+
+    // Unknown rooms are never available, so every available one is found
+    pub fn get_available_rooms(&self) -> Vec<&Room> {
+        ALL_ROOMS
+            .iter()
+            .filter(|r| self.is_room_available(r))
+            .filter_map(|r| get_room(r).ok())
+            .collect()
+    }
+
+    pub fn get_buildable_rooms(&self, player_name: &str) -> Result<Vec<&Room>, GameError> {
+        let player = self.find_player(player_name)?;
+        Ok(self.get_available_rooms()
+            .into_iter()
+            .filter(|r| player.can_afford(&player.get_room_price(*r)))
+            .collect())
+    }
+
+    pub fn check_rooms_available(&self, rooms: &Vec<PlayerRoom>) -> Result<(), GameError> {
+        let mut requested: HashSet<&str> = HashSet::new();
+        for room in rooms.iter() {
+            if !self.is_room_available(&room.room_type) {
+                return Err(GameError::IllegalPlacement(format!("Room {} is not available", room.room_type)));
+            }
+            let is_unique = get_room(&room.room_type)?.is_unique();
+            if is_unique && !requested.insert(room.room_type.as_str()) {
+                return Err(GameError::IllegalPlacement(format!("Room {} can be built only once", room.room_type)));
+            }
+        }
+        Ok(())
+    }
+
     pub fn get_turn_moves_left(&self) -> u32 {
         self.players.iter().map(|p| p.get_free_gnomes()).sum()
     }
@@ -246,27 +991,107 @@ impl Game {
         self.get_turn_moves_left() <= 1
     }
 
-    pub fn get_next_user(&self) -> String {
-        let position = self.order.clone()
+    pub fn get_first_user(&self) -> Result<String, GameError> {
+        self.order
+            .first()
+            .cloned()
+            .ok_or_else(|| GameError::InvalidState(String::from("Game has no players in the order")))
+    }
+
+    pub fn get_next_user(&self) -> Result<String, GameError> {
+        let position = self.order
             .iter()
             .position(|p| *p == self.next)
-            .unwrap() + 1;
+            .ok_or_else(|| GameError::UnknownPlayer(self.next.clone()))? + 1;
         let before = self.order.clone().into_iter().take(position).collect::<Vec<_>>();
         let mut after = self.order.clone().into_iter().skip(position).collect::<Vec<_>>();
         after.extend(before);
-        after
-            .into_iter()
-            .map(|n| self.get_player(&n).clone())
-            .find(|p| p.get_free_gnomes() > 0)
-            .unwrap()
-            .name
+        for name in after.into_iter() {
+            if self.find_player(&name)?.get_free_gnomes() > 0 {
+                return Ok(name);
+            }
+        }
+        Err(GameError::InvalidState(String::from("No player has a gnome left to place")))
     }
 
-    pub fn get_last_game_file(config: &Config) -> (String, String) {
-        let paths = fs::read_dir(&config.folder).unwrap();
+    // Rules every game state has to follow, all broken ones are reported together
+    pub fn validate(&self) -> Result<(), GameError> {
+        let mut violations: Vec<String> = Vec::new();
+
+        if !self.order.contains(&self.next) {
+            violations.push(format!("Next player {} is not in the order", self.next));
+        }
+        for player in self.players.iter() {
+            if player.moved_gnomes > player.gnomes {
+                violations.push(format!(
+                    "Player {} moved {} of {} gnomes", player.name, player.moved_gnomes, player.gnomes
+                ));
+            }
+
+            let positions = vec![
+                ("caverns", player.caverns.iter().map(|c| c.position).collect::<Vec<u32>>()),
+                ("rooms", player.rooms.iter().map(|r| r.position).collect()),
+                ("fields", player.fields.iter().map(|f| f.position).collect()),
+                ("stables", player.stables.clone()),
+                ("sown fields", player.sown_fields.iter().map(|f| f.position).collect()),
+            ];
+            for (name, slots) in positions.into_iter() {
+                let mut unique: HashSet<u32> = HashSet::new();
+                if let Some(slot) = slots.iter().find(|s| !unique.insert(**s)) {
+                    violations.push(format!("Player {} has duplicated {} on slot {}", player.name, name, slot));
+                }
+            }
+
+            for &(first, second) in player.large_pastures.iter() {
+                let is_fenced = [first, second]
+                    .iter()
+                    .all(|s| player.get_field_type(*s) == Some(OutsideElement::LargeFence));
+                if !is_fenced {
+                    violations.push(format!(
+                        "Player {} has large pasture on slots {} and {} without large fences", player.name, first, second
+                    ));
+                }
+            }
+            for field in player.fields.iter().filter(|f| f.field_type == OutsideElement::LargeFence) {
+                if player.large_pastures.iter().all(|p| p.0 != field.position && p.1 != field.position) {
+                    violations.push(format!(
+                        "Player {} has large fence on slot {} outside of large pastures", player.name, field.position
+                    ));
+                }
+            }
+
+            for (animal, _) in player.get_animals_without_room().into_iter() {
+                violations.push(format!(
+                    "Player {} keeps {} {} with room for {}",
+                    player.name, player.get_resource(animal.clone()), animal.str_key(), player.get_resource_max_slots(&animal)
+                ));
+            }
+
+            for player_move in player.moves.iter() {
+                if !self.available_moves.contains(player_move) {
+                    violations.push(format!("Player {} blocks unavailable move {}", player.name, player_move));
+                }
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(GameError::InvalidState(violations.join("; ")))
+        }
+    }
+
+    pub fn get_last_game_file(config: &Config) -> Result<(String, String), GameError> {
+        let paths = fs::read_dir(&config.folder)
+            .map_err(|e| GameError::File(format!("Error reading games folder: {}", e)))?;
         let mut max_file_number = 0_i64;
         for path in paths {
-            let file_name = String::from(path.unwrap().file_name().clone().to_str().unwrap());
+            let path = path.map_err(|e| GameError::File(format!("Error reading games folder: {}", e)))?;
+            // Names that aren't valid unicode can't be game numbers
+            let file_name = match path.file_name().into_string() {
+                Ok(name) => name,
+                Err(_) => continue,
+            };
             let number: i64 = match file_name.trim_right_matches(".yml").parse() {
                    Ok(n) => {
                     n
@@ -279,41 +1104,20 @@ impl Game {
                     max_file_number = number;
             }
         }
-        (format!("{}.yml", max_file_number), format!("{}.yml", max_file_number + 1))
+        Ok((format!("{}.yml", max_file_number), format!("{}.yml", max_file_number + 1)))
     }
 
-    pub fn read_from_yaml(config: &Config, name: String) -> Game {
+    pub fn try_read_from_yaml(config: &Config, name: String) -> Result<Game, GameError> {
         let file = fs::File::open(path::Path::new(&config.folder).join(name))
-            .expect("Error reading game file");
-        serde_yaml::from_reader(file).unwrap()
+            .map_err(|e| GameError::File(format!("Error reading game file: {}", e)))?;
+        serde_yaml::from_reader(file)
+            .map_err(|e| GameError::File(format!("Error parsing game file: {}", e)))
     }
 
-    pub fn write_to_yaml(&self, config: &Config, name: String) {
+    pub fn write_to_yaml(&self, config: &Config, name: String) -> Result<(), GameError> {
         let file = fs::File::create(path::Path::new(&config.folder).join(name))
-            .expect("Error game file");
-        serde_yaml::to_writer(file, &self).unwrap()
+            .map_err(|e| GameError::File(format!("Error opening game file: {}", e)))?;
+        serde_yaml::to_writer(file, &self)
+            .map_err(|e| GameError::File(format!("Error writing game file: {}", e)))
     }
 }
-
-pub trait GetPlayer<T> {
-    fn get_player(&self, player_name: T) -> &Player;
-}
-
-impl<'a> GetPlayer<&'a str> for Game {
-    fn get_player(&self, player_name: &str) -> &Player {
-        self.players
-            .iter()
-            .find(|p| p.name.eq(player_name))
-            .unwrap()
-    }
-}
-
-impl<'a> GetPlayer<&'a String> for Game {
-    fn get_player(&self, player_name: &String) -> &Player {
-        self.players
-            .iter()
-            .find(|p| p.name.eq(player_name))
-            .unwrap()
-    }
-}
-
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write;
+use std::path;
+use serde_yaml;
+
+use config::Config;
+use errors::{GameError};
+
+pub const HISTORY_FILE: &str = "history.yml";
+pub const HEAD_FILE: &str = "head.yml";
+
+// Command which turned the parent snapshot into the next one
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct HistoryEntry {
+    pub parent: String,
+    pub snapshot: String,
+
+    pub player: String,
+    pub command: String,
+    pub args: HashMap<String, String>,
+
+    pub actions: Vec<String>,
+}
+
+impl HistoryEntry {
+    // Entries are appended as items of a single yaml list, written entries are never touched again
+    pub fn append_to_yaml(&self, config: &Config) -> Result<(), GameError> {
+        let item = serde_yaml::to_string(&vec![self])
+            .map_err(|e| GameError::File(format!("Error writing history: {}", e)))?;
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path::Path::new(&config.folder).join(HISTORY_FILE))
+            .map_err(|e| GameError::File(format!("Error opening history file: {}", e)))?;
+        writeln!(file, "{}", item.trim_start_matches("---").trim())
+            .map_err(|e| GameError::File(format!("Error writing history: {}", e)))
+    }
+
+    pub fn read_all_from_yaml(config: &Config) -> Result<Vec<HistoryEntry>, GameError> {
+        let history_path = path::Path::new(&config.folder).join(HISTORY_FILE);
+        if !history_path.exists() {
+            return Ok(vec![]);
+        }
+        let file = fs::File::open(history_path)
+            .map_err(|e| GameError::File(format!("Error reading history file: {}", e)))?;
+        let entries: Option<Vec<HistoryEntry>> = serde_yaml::from_reader(file)
+            .map_err(|e| GameError::File(format!("Error parsing history file: {}", e)))?;
+        Ok(entries.unwrap_or_default())
+    }
+
+    // Entries leading from the first snapshot to the given one, in the order they were played
+    pub fn get_chain(entries: &[HistoryEntry], snapshot: &str) -> Result<Vec<HistoryEntry>, GameError> {
+        let mut result: Vec<HistoryEntry> = Vec::new();
+        let mut current = String::from(snapshot);
+        while let Some(entry) = entries.iter().rev().find(|e| e.snapshot == current) {
+            if result.len() >= entries.len() {
+                return Err(GameError::File(format!("History of {} has a cycle", snapshot)));
+            }
+            current = entry.parent.clone();
+            result.push(entry.clone());
+        }
+        result.reverse();
+        Ok(result)
+    }
+}
+
+// Snapshot the game continues from, undone snapshots stay in the folder and can be redone
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Head {
+    pub snapshot: String,
+    // Last undone snapshot is redone first
+    pub redo: Vec<String>,
+}
+
+impl Head {
+    // Without a head file the game continues from the last snapshot
+    pub fn try_read_from_yaml(config: &Config, last_game_file: &str) -> Result<Head, GameError> {
+        let head_path = path::Path::new(&config.folder).join(HEAD_FILE);
+        if !head_path.exists() {
+            return Ok(Head { snapshot: String::from(last_game_file), redo: vec![] });
+        }
+        let file = fs::File::open(head_path)
+            .map_err(|e| GameError::File(format!("Error reading head file: {}", e)))?;
+        serde_yaml::from_reader(file)
+            .map_err(|e| GameError::File(format!("Error parsing head file: {}", e)))
+    }
+
+    pub fn write_to_yaml(&self, config: &Config) -> Result<(), GameError> {
+        let file = fs::File::create(path::Path::new(&config.folder).join(HEAD_FILE))
+            .map_err(|e| GameError::File(format!("Error opening head file: {}", e)))?;
+        serde_yaml::to_writer(file, &self)
+            .map_err(|e| GameError::File(format!("Error writing head file: {}", e)))
+    }
+
+    // New snapshot starts another branch, so nothing is left to redo
+    pub fn advance(&mut self, snapshot: &str) {
+        self.snapshot = String::from(snapshot);
+        self.redo.clear();
+    }
+
+    pub fn undo(&mut self, entries: &[HistoryEntry], steps: usize) -> Result<(), GameError> {
+        let chain = HistoryEntry::get_chain(entries, &self.snapshot)?;
+        if steps > chain.len() {
+            return Err(GameError::InvalidArgument(format!(
+                "Can't undo {} commands, only {} lead to {}", steps, chain.len(), self.snapshot
+            )));
+        }
+        for entry in chain.iter().rev().take(steps) {
+            self.redo.push(entry.snapshot.clone());
+            self.snapshot = entry.parent.clone();
+        }
+        Ok(())
+    }
+
+    pub fn redo(&mut self, steps: usize) -> Result<(), GameError> {
+        if steps > self.redo.len() {
+            return Err(GameError::InvalidArgument(format!(
+                "Can't redo {} commands, only {} are undone", steps, self.redo.len()
+            )));
+        }
+        for _ in 0..steps {
+            self.snapshot = self.redo.pop().unwrap();
+        }
+        Ok(())
+    }
+
+    // Going forward to an undone snapshot keeps the rest of the redo stack
+    pub fn goto(&mut self, snapshot: &str) {
+        match self.redo.iter().position(|s| s == snapshot) {
+            Some(i) => {
+                self.redo.truncate(i);
+                self.snapshot = String::from(snapshot);
+            },
+            None => self.advance(snapshot),
+        }
+    }
+}
@@ -1,2 +1,4 @@
 pub mod game;
-pub mod moves;
\ No newline at end of file
+pub mod history;
+pub mod moves;
+pub mod schedule;
\ No newline at end of file
@@ -1,4 +1,4 @@
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct MovesData {
     pub drift_mining: DriftMiningData,
     pub logging: LoggingData,
@@ -7,40 +7,99 @@ pub struct MovesData {
     pub clearing: ClearingData,
     pub starting_player: StartingPlayerData,
     pub ruby_mining: RubyMiningData,
+    #[serde(default)]
+    pub sheep_farming: SheepFarmingData,
+    #[serde(default)]
+    pub donkey_farming: DonkeyFarmingData,
+    #[serde(default)]
+    pub ore_delivery: OreDeliveryData,
+    #[serde(default)]
+    pub ruby_delivery: RubyDeliveryData,
+    #[serde(default)]
+    pub ore_mining: OreMiningData,
+    #[serde(default)]
+    pub sustenance: SustenanceData,
+    #[serde(default)]
+    pub depot: DepotData,
+    #[serde(default)]
+    pub large_depot: LargeDepotData,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct DriftMiningData {
     pub stone: u32,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct LoggingData {
     pub wood: u32,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct WoodGatheringData {
     pub wood: u32,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct ExcavationData {
     pub stone: u32,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct ClearingData {
     pub wood: u32,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct StartingPlayerData {
     pub food: u32,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct RubyMiningData {
     pub gems: u32,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct SheepFarmingData {
+    pub sheep: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct DonkeyFarmingData {
+    pub donkeys: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct OreDeliveryData {
+    pub stone: u32,
+    pub coal: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct RubyDeliveryData {
+    pub gems: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct OreMiningData {
+    pub coal: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct SustenanceData {
+    pub food: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct DepotData {
+    pub wood: u32,
+    pub coal: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct LargeDepotData {
+    pub wood: u32,
+    pub stone: u32,
+    pub coal: u32,
+}
@@ -0,0 +1,106 @@
+use std::fs;
+use std::path;
+use serde_yaml;
+use rand::{thread_rng, Rng};
+
+use config::Config;
+use errors::{GameError};
+use constants::{FeedingAndBreedingStatus};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ScheduledMove {
+    pub name: String,
+
+    // Keeps its round when the stage is shuffled
+    #[serde(default)]
+    pub fixed: bool,
+
+    #[serde(default)]
+    pub min_players: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ScheduledRound {
+    // No harvest at all when the marker is missing
+    #[serde(default)]
+    pub harvest: Option<FeedingAndBreedingStatus>,
+
+    #[serde(default)]
+    pub min_players: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Stage {
+    #[serde(default)]
+    pub shuffle: bool,
+
+    pub moves: Vec<ScheduledMove>,
+
+    pub rounds: Vec<ScheduledRound>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RoundSchedule {
+    pub stages: Vec<Stage>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Round {
+    pub new_move: String,
+    pub harvest: Option<FeedingAndBreedingStatus>,
+}
+
+impl RoundSchedule {
+    pub fn try_read_from_yaml(config: &Config, name: String) -> Result<RoundSchedule, GameError> {
+        let file = fs::File::open(path::Path::new(&config.folder).join(name))
+            .map_err(|e| GameError::File(format!("Error reading round schedule file: {}", e)))?;
+        serde_yaml::from_reader(file)
+            .map_err(|e| GameError::File(format!("Error parsing round schedule file: {}", e)))
+    }
+
+    // Rounds played by the given number of players, each opening a new move
+    pub fn get_rounds(&self, players_count: u32) -> Result<Vec<Round>, GameError> {
+        let mut result: Vec<Round> = Vec::new();
+        for (index, stage) in self.stages.iter().enumerate() {
+            let moves = stage.get_moves(players_count);
+            let rounds: Vec<&ScheduledRound> = stage.rounds
+                .iter()
+                .filter(|r| r.min_players <= players_count)
+                .collect();
+            if moves.len() != rounds.len() {
+                return Err(GameError::InvalidArgument(format!(
+                    "Stage {} has {} moves for {} rounds with {} players",
+                    index + 1, moves.len(), rounds.len(), players_count
+                )));
+            }
+            for (new_move, round) in moves.into_iter().zip(rounds.into_iter()) {
+                result.push(Round {
+                    new_move,
+                    harvest: round.harvest.clone(),
+                });
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl Stage {
+    pub fn get_moves(&self, players_count: u32) -> Vec<String> {
+        let moves: Vec<&ScheduledMove> = self.moves
+            .iter()
+            .filter(|m| m.min_players <= players_count)
+            .collect();
+        let mut names: Vec<String> = moves.iter().map(|m| m.name.clone()).collect();
+        if !self.shuffle {
+            return names;
+        }
+
+        let positions: Vec<usize> = (0..moves.len()).filter(|&i| !moves[i].fixed).collect();
+        let mut shuffled: Vec<String> = positions.iter().map(|&i| names[i].clone()).collect();
+        thread_rng().shuffle(&mut shuffled);
+        for (position, name) in positions.into_iter().zip(shuffled.into_iter()) {
+            names[position] = name;
+        }
+        names
+    }
+}
@@ -3,6 +3,7 @@ use std::path;
 use serde_yaml;
 
 use config::Config;
+use errors::{GameError};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct DriftMining {
@@ -54,6 +55,67 @@ pub struct RubyMining {
     pub gems: u32,
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SheepFarming {
+    pub sheep_incr: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct OreMineConstruction {
+    pub coal: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DonkeyFarming {
+    pub donkey_incr: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct OreDelivery {
+    pub stone_incr: u32,
+    pub coal_incr: u32,
+    pub coal_per_mine: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct OreTrading {
+    pub coal: u32,
+    pub gold: u32,
+    pub food: u32,
+    pub max_trades: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RubyDelivery {
+    pub gem_incr: u32,
+    pub mines_for_bonus: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct OreMining {
+    pub coal_incr: u32,
+    pub coal_per_mine: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Sustenance {
+    pub food_incr: u32,
+    pub wheat: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Depot {
+    pub wood_incr: u32,
+    pub coal_incr: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LargeDepot {
+    pub wood_incr: u32,
+    pub stone_incr: u32,
+    pub coal_incr: u32,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct MovesConfig {
     pub drift_mining: DriftMining,
@@ -64,13 +126,24 @@ pub struct MovesConfig {
     pub clearing: Clearing,
     pub starting_player: StartingPlayer,
     pub ruby_mining: RubyMining,
+    pub sheep_farming: SheepFarming,
+    pub ore_mine_construction: OreMineConstruction,
+    pub donkey_farming: DonkeyFarming,
+    pub ore_delivery: OreDelivery,
+    pub ore_trading: OreTrading,
+    pub ruby_delivery: RubyDelivery,
+    pub ore_mining: OreMining,
+    pub sustenance: Sustenance,
+    pub depot: Depot,
+    pub large_depot: LargeDepot,
 }
 
 impl MovesConfig {
-    pub fn read_from_yaml(config: &Config, name: String) -> MovesConfig {
+    pub fn try_read_from_yaml(config: &Config, name: String) -> Result<MovesConfig, GameError> {
         let file = fs::File::open(path::Path::new(&config.folder).join(name))
-            .expect("Error reading moves config file");
-        serde_yaml::from_reader(file).unwrap()
+            .map_err(|e| GameError::File(format!("Error reading moves config file: {}", e)))?;
+        serde_yaml::from_reader(file)
+            .map_err(|e| GameError::File(format!("Error parsing moves config file: {}", e)))
     }
 }
 
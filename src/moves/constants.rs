@@ -1,3 +1,5 @@
+use errors::{GameError};
+
 pub const DRIFT_MINING: &str = "drift_mining";
 pub const LOGGING: &str = "logging";
 pub const WOOD_GATHERING: &str = "wood_gathering";
@@ -20,6 +22,10 @@ pub const ORE_DELIVERY: &str = "ore_delivery";
 pub const ADVENTURE: &str = "adventure";
 pub const ORE_TRADING: &str = "ore_trading";
 pub const RUBY_DELIVERY: &str = "ruby_delivery";
+pub const ORE_MINING: &str = "ore_mining";
+pub const SUSTENANCE: &str = "sustenance";
+pub const DEPOT: &str = "depot";
+pub const LARGE_DEPOT: &str = "large_depot";
 
 
 pub const ALL_MOVES: &'static [&str] = &[
@@ -30,6 +36,13 @@ pub const ALL_MOVES: &'static [&str] = &[
     SUPPLIES,
     CLEARING,
     STARTING_PLAYER,
+    RUBY_MINING,
+    HOUSEWORK,
+    SLASH_AND_BURN,
+    ORE_MINING,
+    SUSTENANCE,
+    DEPOT,
+    LARGE_DEPOT,
 ];
 
 pub const TWO_PLAYERS_MOVES: &'static [&str] = &[
@@ -40,4 +53,63 @@ pub const TWO_PLAYERS_MOVES: &'static [&str] = &[
     SUPPLIES,
     CLEARING,
     STARTING_PLAYER,
-];
\ No newline at end of file
+];
+
+// Solo game has no one to take the first player from
+pub const SOLO_MOVES: &'static [&str] = &[
+    DRIFT_MINING,
+    LOGGING,
+    WOOD_GATHERING,
+    EXCAVATION,
+    SUPPLIES,
+    CLEARING,
+];
+
+pub const THREE_PLAYERS_EXTRA_MOVES: &'static [&str] = &[
+    RUBY_MINING,
+    HOUSEWORK,
+    SLASH_AND_BURN,
+];
+
+pub const FOUR_PLAYERS_EXTRA_MOVES: &'static [&str] = &[
+    ORE_MINING,
+    SUSTENANCE,
+];
+
+pub const FIVE_PLAYERS_EXTRA_MOVES: &'static [&str] = &[
+    DEPOT,
+];
+
+pub const SIX_PLAYERS_EXTRA_MOVES: &'static [&str] = &[
+    LARGE_DEPOT,
+];
+
+pub const MAX_PLAYERS: u32 = 7;
+
+// Food of the players by their seat, the first player gets the least
+pub const STARTING_FOOD: &'static [u32] = &[1, 1, 2, 3, 3, 3, 3];
+
+// Action spaces on the board, each player count adds spaces of the smaller boards
+pub fn get_board_moves(players_count: u32) -> Result<Vec<&'static str>, GameError> {
+    if players_count == 0 || players_count > MAX_PLAYERS {
+        return Err(GameError::InvalidArgument(format!("Game for {} players is not supported", players_count)));
+    }
+    if players_count == 1 {
+        return Ok(SOLO_MOVES.to_vec());
+    }
+
+    let mut moves = TWO_PLAYERS_MOVES.to_vec();
+    if players_count >= 3 {
+        moves.extend(THREE_PLAYERS_EXTRA_MOVES);
+    }
+    if players_count >= 4 {
+        moves.extend(FOUR_PLAYERS_EXTRA_MOVES);
+    }
+    if players_count >= 5 {
+        moves.extend(FIVE_PLAYERS_EXTRA_MOVES);
+    }
+    if players_count >= 6 {
+        moves.extend(SIX_PLAYERS_EXTRA_MOVES);
+    }
+    Ok(moves)
+}
@@ -3,17 +3,27 @@ use std::collections::{HashMap};
 use clap::{SubCommand, Arg, App, ArgMatches};
 
 use constants;
-use models::game::{Game};
-use actions::{MoveAction, Actions, UpdateResources, SetFirstPlayer};
+use errors::{GameError};
+use constants::{
+    InsideElement, OutsideElement, SMALL_PASTURE_WOOD, LARGE_PASTURE_WOOD, STABLE_STONE, MAX_FORGE_STRENGTH,
+    MAX_SOWN_FIELDS_PER_CROP,
+};
+use models::game::{Game, PlayerRoom, PlayerCavern, PlayerField};
+use actions::{
+    MoveAction, Actions, UpdateResources, PayResources, BuildRooms, Excavate, BuildMines, BuildFields, BuildPastures,
+    BuildStables, Sow, SpawnGnome, ForgeWeapon, SetFirstPlayer, TakeAccumulated,
+};
 use moves::config::{MovesConfig};
+use moves::expedition::{get_expedition_actions, get_expedition_picks, build_expedition_actions, get_upgraded_weapon};
 use moves::{constants as MovesConstants};
+use rooms::{constants as RoomConstants};
 
 pub struct ActionsFromMove {
     pub move_name: String,
     pub actions: Actions,
 }
 
-pub fn get_from_string(string: &str) -> Result<&Move, String> {
+pub fn get_from_string(string: &str) -> Result<&Move, GameError> {
     let moves_list: Vec<&Move> = vec![
         &DriftMining {},
         &Logging {},
@@ -36,27 +46,394 @@ pub fn get_from_string(string: &str) -> Result<&Move, String> {
         &Adventure {},
         &OreTrading {},
         &RubyDelivery {},
+        &Exploration {},
+        &OreMining {},
+        &Sustenance {},
+        &Depot {},
+        &LargeDepot {},
     ];
 
     match moves_list.into_iter().find(|m| m.get_name() == string) {
         Some(x) => Ok(x),
-        None => Err(format!("No move for {} found", string)),
+        None => Err(GameError::UnknownMove(String::from(string))),
     }
 }
 
-pub fn collect_actions(game: &Game, moves_config: &MovesConfig, moves: Vec<&Move>) -> Vec<ActionsFromMove> {
-    let actions = moves
-        .iter()
-        .flat_map(|&m| {
-            m.get_all_actions(game.clone(), moves_config).into_iter().map(|a| {
-                ActionsFromMove {
-                    move_name: String::from(m.get_name()),
-                    actions: a,
-                }
-            }).collect::<Vec<ActionsFromMove>>()
+pub fn collect_actions(game: &Game, moves_config: &MovesConfig, moves: Vec<&Move>
+) -> Result<Vec<ActionsFromMove>, GameError> {
+    let mut actions: Vec<ActionsFromMove> = Vec::new();
+    for m in moves.into_iter() {
+        for a in m.get_affordable_actions(game.clone(), moves_config)?.into_iter() {
+            actions.push(ActionsFromMove {
+                move_name: String::from(m.get_name()),
+                actions: a,
+            });
+        }
+    }
+    Ok(actions)
+}
+
+pub fn get_furnish_actions(game: &Game, player_name: &str, room_types: &[&str]) -> Result<Vec<Actions>, GameError> {
+    let player = game.find_player(player_name)?;
+    let slots = player.get_furnishable_slots();
+
+    let mut result: Vec<Actions> = Vec::new();
+    for room in game.get_buildable_rooms(player_name)? {
+        if !room_types.contains(&room.get_name()) {
+            continue;
+        }
+        for slot in slots.iter() {
+            let actions: Vec<Box<MoveAction>> = vec![
+                Box::new(PayResources {
+                    player: String::from(player_name),
+                    price: player.get_room_price(room),
+                }),
+                Box::new(BuildRooms {
+                    player: String::from(player_name),
+                    rooms: vec![PlayerRoom {
+                        room_type: String::from(room.get_name()),
+                        position: *slot,
+                    }],
+                }),
+            ];
+            result.push(Actions {
+                args: hash_map! {
+                    String::from("room") => String::from(room.get_name()),
+                    String::from("slot") => slot.to_string()
+                },
+                actions,
+            });
+        }
+    }
+    Ok(result)
+}
+
+pub fn get_excavation_actions(game: &Game, player_name: &str, two_rooms: bool) -> Result<Vec<Actions>, GameError> {
+    let player = game.find_player(player_name)?;
+
+    let mut twins: Vec<(u32, u32, InsideElement)> = Vec::new();
+    for &(first_slot, second_slot) in player.get_excavation_pair_slots().iter() {
+        twins.push((first_slot, second_slot, InsideElement::Hall));
+        twins.push((second_slot, first_slot, InsideElement::Hall));
+        if two_rooms {
+            twins.push((first_slot, second_slot, InsideElement::Room));
+        }
+    }
+
+    Ok(twins
+        .into_iter()
+        .map(|(room_slot, second_slot, second_type)| {
+            let args = hash_map! {
+                String::from("room_slot") => room_slot.to_string(),
+                String::from("second_slot") => second_slot.to_string(),
+                String::from("two_rooms") => (second_type == InsideElement::Room).to_string()
+            };
+            let actions: Vec<Box<MoveAction>> = vec![
+                Box::new(Excavate {
+                    player: String::from(player_name),
+                    caverns: vec![
+                        PlayerCavern { cavern_type: InsideElement::Room, position: room_slot },
+                        PlayerCavern { cavern_type: second_type, position: second_slot },
+                    ],
+                }),
+            ];
+            Actions { args, actions }
+        })
+        .collect())
+}
+
+pub fn get_clearing_actions(game: &Game, player_name: &str) -> Result<Vec<Actions>, GameError> {
+    let player = game.find_player(player_name)?;
+
+    let mut twins: Vec<(u32, u32)> = Vec::new();
+    for &(first_slot, second_slot) in player.get_clearing_pair_slots().iter() {
+        twins.push((first_slot, second_slot));
+        twins.push((second_slot, first_slot));
+    }
+
+    Ok(twins
+        .into_iter()
+        .map(|(meadow_slot, field_slot)| {
+            let args = hash_map! {
+                String::from("meadow_slot") => meadow_slot.to_string(),
+                String::from("field_slot") => field_slot.to_string()
+            };
+            let actions: Vec<Box<MoveAction>> = vec![
+                Box::new(BuildFields {
+                    player: String::from(player_name),
+                    fields: vec![
+                        PlayerField { field_type: OutsideElement::Meadow, position: meadow_slot },
+                        PlayerField { field_type: OutsideElement::Field, position: field_slot },
+                    ],
+                }),
+            ];
+            Actions { args, actions }
+        })
+        .collect())
+}
+
+fn _get_pasture_slots(game: &Game, player_name: &str) -> Result<Vec<Vec<u32>>, GameError> {
+    let player = game.find_player(player_name)?;
+    let wood = player.get_resource(constants::ResourceType::Wood);
+
+    let mut pastures: Vec<Vec<u32>> = Vec::new();
+    if wood >= SMALL_PASTURE_WOOD {
+        pastures.extend(player.get_small_pasture_slots().into_iter().map(|s| vec![s]));
+    }
+    if wood >= LARGE_PASTURE_WOOD {
+        pastures.extend(player.get_large_pasture_pair_slots().into_iter().map(|(f, s)| vec![f, s]));
+    }
+    Ok(pastures)
+}
+
+fn _get_stable_slots(game: &Game, player_name: &str) -> Result<Vec<u32>, GameError> {
+    let player = game.find_player(player_name)?;
+    if player.get_resource(constants::ResourceType::Stone) < STABLE_STONE {
+        return Ok(vec![]);
+    }
+    Ok(player.get_stable_slots())
+}
+
+fn _build_pasture(player_name: &str, pasture: &Vec<u32>) -> Vec<Box<MoveAction>> {
+    let price = if pasture.len() == 1 { SMALL_PASTURE_WOOD } else { LARGE_PASTURE_WOOD };
+    vec![
+        Box::new(PayResources {
+            player: String::from(player_name),
+            price: hash_map! {
+                constants::ResourceType::Wood.str_key() => price
+            },
+        }),
+        Box::new(BuildPastures {
+            player: String::from(player_name),
+            pastures: pasture.clone(),
+        }),
+    ]
+}
+
+fn _build_stable(player_name: &str, slot: u32) -> Vec<Box<MoveAction>> {
+    vec![
+        Box::new(PayResources {
+            player: String::from(player_name),
+            price: hash_map! {
+                constants::ResourceType::Stone.str_key() => STABLE_STONE
+            },
+        }),
+        Box::new(BuildStables {
+            player: String::from(player_name),
+            stables: vec![slot],
+        }),
+    ]
+}
+
+fn _pasture_arg(pasture: &Vec<u32>) -> String {
+    pasture.iter().map(|s| s.to_string()).collect::<Vec<String>>().join(",")
+}
+
+pub fn get_pasture_actions(game: &Game, player_name: &str) -> Result<Vec<Actions>, GameError> {
+    Ok(_get_pasture_slots(game, player_name)?
+        .into_iter()
+        .map(|pasture| Actions {
+            args: hash_map! {
+                String::from("pasture") => _pasture_arg(&pasture)
+            },
+            actions: _build_pasture(player_name, &pasture),
+        })
+        .collect())
+}
+
+pub fn get_stable_actions(game: &Game, player_name: &str) -> Result<Vec<Actions>, GameError> {
+    Ok(_get_stable_slots(game, player_name)?
+        .into_iter()
+        .map(|slot| Actions {
+            args: hash_map! {
+                String::from("stable") => slot.to_string()
+            },
+            actions: _build_stable(player_name, slot),
+        })
+        .collect())
+}
+
+pub fn get_farming_actions(game: &Game, player_name: &str) -> Result<Vec<Actions>, GameError> {
+    let stable_slots = _get_stable_slots(game, player_name)?;
+
+    let mut result: Vec<Actions> = Vec::new();
+    for pasture in _get_pasture_slots(game, player_name)? {
+        // Stable is built together with a new pasture only inside of it
+        for slot in pasture.iter().filter(|s| stable_slots.contains(s)) {
+            let mut actions = _build_pasture(player_name, &pasture);
+            actions.extend(_build_stable(player_name, *slot));
+            result.push(Actions {
+                args: hash_map! {
+                    String::from("pasture") => _pasture_arg(&pasture),
+                    String::from("stable") => slot.to_string()
+                },
+                actions,
+            });
+        }
+    }
+    result.extend(get_pasture_actions(game, player_name)?);
+    result.extend(get_stable_actions(game, player_name)?);
+    Ok(result)
+}
+
+// Fields are interchangeable, so grain goes on the lowest free fields and vegetables on the next ones
+pub fn get_sowing_actions(game: &Game, player_name: &str) -> Result<Vec<Actions>, GameError> {
+    let player = game.find_player(player_name)?;
+    let slots = player.get_sowable_slots();
+    let max_grain = player.get_resource(constants::ResourceType::Wheat).min(MAX_SOWN_FIELDS_PER_CROP);
+    let max_vegetables = player.get_resource(constants::ResourceType::Pumpkin).min(MAX_SOWN_FIELDS_PER_CROP);
+
+    let mut result: Vec<Actions> = Vec::new();
+    for grain in 0..max_grain.min(slots.len() as u32) + 1 {
+        let free_slots = slots.len() as u32 - grain;
+        for vegetables in 0..max_vegetables.min(free_slots) + 1 {
+            if grain + vegetables == 0 {
+                continue;
+            }
+            let actions: Vec<Box<MoveAction>> = vec![
+                Box::new(Sow {
+                    player: String::from(player_name),
+                    grain: slots[..grain as usize].to_vec(),
+                    vegetables: slots[grain as usize..(grain + vegetables) as usize].to_vec(),
+                }),
+            ];
+            result.push(Actions {
+                args: hash_map! {
+                    String::from("grain") => grain.to_string(),
+                    String::from("vegetables") => vegetables.to_string()
+                },
+                actions,
+            });
+        }
+    }
+    Ok(result)
+}
+
+// Every option can be followed by sowing, the sowing options depend on the fields the option brings
+fn _with_sowing(game: &Game, player_name: &str, options: Vec<Actions>) -> Result<Vec<Actions>, GameError> {
+    let mut result: Vec<Actions> = Vec::new();
+    // Each option is tried on the same state and reverted afterwards
+    let mut state = game.clone();
+    for option in options.into_iter() {
+        if let Ok(undo_log) = option.try_perform_part(&mut state) {
+            for sowing in get_sowing_actions(&state, player_name)?.into_iter() {
+                let mut sown = option.clone();
+                sown.args.extend(sowing.args);
+                sown.actions.extend(sowing.actions);
+                result.push(sown);
+            }
+            if undo_log.revert(&mut state).is_err() {
+                state = game.clone();
+            }
+        }
+        result.push(option);
+    }
+    Ok(result)
+}
+
+pub fn get_family_growth_actions(game: &Game, player_name: &str) -> Result<Vec<Actions>, GameError> {
+    if !game.find_player(player_name)?.can_spawn_gnome() {
+        return Ok(vec![]);
+    }
+    Ok(vec![Actions::from_vec(vec![
+        Box::new(SpawnGnome {
+            player: String::from(player_name),
+        }),
+    ])])
+}
+
+fn _get_forge_strengths(game: &Game, player_name: &str) -> Result<Vec<u32>, GameError> {
+    let player = game.find_player(player_name)?;
+    if player.get_next_gnome_weapon().is_some() || player.moved_gnomes >= player.gnomes {
+        return Ok(vec![]);
+    }
+
+    let coal = player.get_resource(constants::ResourceType::Coal);
+    Ok((1..coal.min(MAX_FORGE_STRENGTH) + 1).collect())
+}
+
+fn _forge_weapon(player_name: &str, strength: u32) -> Vec<Box<MoveAction>> {
+    vec![
+        Box::new(PayResources {
+            player: String::from(player_name),
+            price: hash_map! {
+                constants::ResourceType::Coal.str_key() => strength
+            },
+        }),
+        Box::new(ForgeWeapon {
+            player: String::from(player_name),
+            strength,
+        }),
+    ]
+}
+
+// Only the next placed gnome can get a weapon, so it has to be unarmed
+pub fn get_forge_actions(game: &Game, player_name: &str) -> Result<Vec<Actions>, GameError> {
+    Ok(_get_forge_strengths(game, player_name)?
+        .into_iter()
+        .map(|strength| {
+            let args = hash_map! {
+                String::from("strength") => strength.to_string()
+            };
+            Actions { args, actions: _forge_weapon(player_name, strength) }
         })
-        .collect();
-    actions
+        .collect())
+}
+
+// Game state after the next gnome of the player is placed on the move
+fn _with_placed_gnome(game: &Game, player_name: &str) -> Result<Game, GameError> {
+    let mut state = game.clone();
+    state.find_player_mut(player_name)?.place_next_gnome();
+    Ok(state)
+}
+
+// Expedition options done after the base actions, the base actions should be already performed on the game
+fn _get_expedition_options(game: &Game, player_name: &str, weapon: u32, level: u32,
+                           base_actions: &Fn() -> Vec<Box<MoveAction>>, base_args: &HashMap<String, String>
+) -> Result<Vec<Actions>, GameError> {
+    let mut result: Vec<Actions> = Vec::new();
+    for expedition in get_expedition_actions(game, player_name, weapon, level)?.into_iter() {
+        let mut actions = base_actions();
+        actions.extend(expedition.actions);
+        let mut args = base_args.clone();
+        args.extend(expedition.args);
+        result.push(Actions { args, actions });
+    }
+    Ok(result)
+}
+
+fn _with_base_actions(base_actions: &Fn() -> Vec<Box<MoveAction>>, options: Vec<Actions>, optional: bool) -> Vec<Actions> {
+    let mut result: Vec<Actions> = Vec::new();
+    if optional || options.is_empty() {
+        result.push(Actions { args: HashMap::new(), actions: base_actions() });
+    }
+    for option in options.into_iter() {
+        let mut actions = base_actions();
+        actions.extend(option.actions);
+        result.push(Actions { args: option.args, actions });
+    }
+    result
+}
+
+// Empty values are left by optional command line args that were not given
+pub fn _same_args(first: &HashMap<String, String>, second: &HashMap<String, String>) -> bool {
+    let given = |args: &HashMap<String, String>| -> HashMap<String, String> {
+        args.iter()
+            .filter(|&(_, value)| !value.is_empty())
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    };
+    given(first) == given(second)
+}
+
+fn _parse_optional_args(args: &ArgMatches, names: &[&str]) -> HashMap<String, String> {
+    let mut result: HashMap<String, String> = HashMap::new();
+    for name in names.iter() {
+        if let Some(value) = args.value_of(name) {
+            result.insert(String::from(*name), String::from(value));
+        }
+    }
+    result
 }
 
 pub trait Move {
@@ -67,8 +444,24 @@ pub trait Move {
     fn parse_args(&self, _args: &ArgMatches) -> HashMap<String, String> {
         HashMap::new()
     }
-    fn get_all_actions(&self, game: Game, moves_config: &MovesConfig) -> Vec<Actions>;
-    fn get_actions(&self, game: Game, moves_config: &MovesConfig, args: &HashMap<String, String>) -> Actions;
+    fn get_all_actions(&self, game: Game, moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError>;
+    // Options the next player can pay for with the resources gained before each payment
+    fn get_affordable_actions(&self, game: Game, moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        Ok(self.get_all_actions(game.clone(), moves_config)?
+            .into_iter()
+            .filter(|a| a.is_affordable(&game))
+            .collect())
+    }
+    // Option picked by the given args, an unknown combination of args is reported as an error
+    fn try_get_actions(&self, game: Game, moves_config: &MovesConfig, args: &HashMap<String, String>
+    ) -> Result<Actions, GameError> {
+        self.get_all_actions(game, moves_config)?
+            .into_iter()
+            .find(|a| _same_args(&a.args, args))
+            .ok_or_else(|| GameError::MoveNotPossible(
+                format!("Move {} is not possible with {:?}", self.get_name(), args)
+            ))
+    }
     fn on_next_turn(&self, _game: &mut Game, _moves_config: &MovesConfig) {}
 }
 
@@ -87,50 +480,45 @@ impl Move for DriftMining {
     }
 
     fn parse_args(&self, args: &ArgMatches) -> HashMap<String, String> {
-        hash_map! {
-            String::from("hall_slot") => String::from(args.value_of("hall_slot").unwrap()),
-            String::from("room_slot") => String::from(args.value_of("room_slot").unwrap())
-        }
+        _parse_optional_args(args, &["hall_slot", "room_slot"])
     }
 
-    fn get_all_actions(&self, game: Game, moves_config: &MovesConfig) -> Vec<Actions> {
-        let mut update_hash: HashMap<String, u32> = HashMap::new();
+    fn get_all_actions(&self, game: Game, _moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        let mut update_hash: HashMap<String, i32> = HashMap::new();
         update_hash.insert(
-            constants::ResourceType::Stone.str_key(), moves_config.drift_mining.stone_incr
+            constants::ResourceType::Stone.str_key(), game.moves.drift_mining.stone as i32
         );
 
         let mut actions: Vec<Box<MoveAction>> = Vec::new();
         actions.push(Box::new(UpdateResources {
-            player: game.next,
-            update_hash,
+            player: game.next.clone(),
+            update_hash: update_hash.clone(),
         }));
+        actions.push(Box::new(TakeAccumulated { player_move: String::from(MovesConstants::DRIFT_MINING) }));
 
         let mut result: Vec<Actions> = Vec::new();
         result.push(Actions {
-            args: hash_map! {
-                String::from("hall_slot") => String::new(),
-                String::from("room_slot") => String::new()
-            },
+            args: HashMap::new(),
             actions,
         });
-        result
-    }
-
-    fn get_actions(&self, game: Game, moves_config: &MovesConfig, _args: &HashMap<String, String>) -> Actions {
-        let mut update_hash: HashMap<String, u32> = HashMap::new();
-        update_hash.insert(
-            constants::ResourceType::Stone.str_key(), moves_config.drift_mining.stone_incr
-        );
 
-        let mut actions: Vec<Box<MoveAction>> = Vec::new();
-        actions.push(Box::new(UpdateResources {
-            player: game.next,
-            update_hash,
-        }));
-        Actions {
-            args: HashMap::new(),
-            actions,
+        for excavation in get_excavation_actions(&game, &game.next, false)? {
+            let mut actions: Vec<Box<MoveAction>> = Vec::new();
+            actions.push(Box::new(UpdateResources {
+                player: game.next.clone(),
+                update_hash: update_hash.clone(),
+            }));
+            actions.push(Box::new(TakeAccumulated { player_move: String::from(MovesConstants::DRIFT_MINING) }));
+            actions.extend(excavation.actions);
+            result.push(Actions {
+                args: hash_map! {
+                    String::from("hall_slot") => excavation.args.get("second_slot").unwrap().clone(),
+                    String::from("room_slot") => excavation.args.get("room_slot").unwrap().clone()
+                },
+                actions,
+            });
         }
+        Ok(result)
     }
 
     fn on_next_turn(&self, game: &mut Game, moves_config: &MovesConfig) {
@@ -151,10 +539,10 @@ impl Move for Logging {
             .arg(Arg::with_name("extraction"))
     }
 
-    fn get_all_actions(&self, game: Game, _moves_config: &MovesConfig) -> Vec<Actions> {
-        let mut update_hash: HashMap<String, u32> = HashMap::new();
+    fn get_all_actions(&self, game: Game, _moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        let mut update_hash: HashMap<String, i32> = HashMap::new();
         update_hash.insert(
-            constants::ResourceType::Wood.str_key(), game.moves.logging.wood
+            constants::ResourceType::Wood.str_key(), game.moves.logging.wood as i32
         );
 
         let mut actions: Vec<Box<MoveAction>> = Vec::new();
@@ -162,30 +550,14 @@ impl Move for Logging {
             player: game.next,
             update_hash,
         }));
+        actions.push(Box::new(TakeAccumulated { player_move: String::from(MovesConstants::LOGGING) }));
 
         let mut result: Vec<Actions> = Vec::new();
         result.push(Actions {
             args: HashMap::new(),
             actions,
         });
-        result
-    }
-
-    fn get_actions(&self, game: Game, _moves_config: &MovesConfig, _args: &HashMap<String, String>) -> Actions {
-        let mut update_hash: HashMap<String, u32> = HashMap::new();
-        update_hash.insert(
-            constants::ResourceType::Wood.str_key(), game.moves.logging.wood
-        );
-
-        let mut actions: Vec<Box<MoveAction>> = Vec::new();
-        actions.push(Box::new(UpdateResources {
-            player: game.next,
-            update_hash,
-        }));
-        Actions {
-            args: HashMap::new(),
-            actions,
-        }
+        Ok(result)
     }
 
     fn on_next_turn(&self, game: &mut Game, moves_config: &MovesConfig) {
@@ -208,10 +580,10 @@ impl Move for WoodGathering {
             .about("Wood Gathering")
     }
 
-    fn get_all_actions(&self, game: Game, _moves_config: &MovesConfig) -> Vec<Actions> {
-        let mut update_hash: HashMap<String, u32> = HashMap::new();
+    fn get_all_actions(&self, game: Game, _moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        let mut update_hash: HashMap<String, i32> = HashMap::new();
         update_hash.insert(
-            constants::ResourceType::Wood.str_key(), game.moves.wood_gathering.wood
+            constants::ResourceType::Wood.str_key(), game.moves.wood_gathering.wood as i32
         );
 
         let mut actions: Vec<Box<MoveAction>> = Vec::new();
@@ -219,30 +591,14 @@ impl Move for WoodGathering {
             player: game.next,
             update_hash,
         }));
+        actions.push(Box::new(TakeAccumulated { player_move: String::from(MovesConstants::WOOD_GATHERING) }));
 
         let mut result: Vec<Actions> = Vec::new();
         result.push(Actions {
             args: HashMap::new(),
             actions,
         });
-        result
-    }
-
-    fn get_actions(&self, game: Game, _moves_config: &MovesConfig, _args: &HashMap<String, String>) -> Actions {
-        let mut update_hash: HashMap<String, u32> = HashMap::new();
-        update_hash.insert(
-            constants::ResourceType::Wood.str_key(), game.moves.wood_gathering.wood
-        );
-
-        let mut actions: Vec<Box<MoveAction>> = Vec::new();
-        actions.push(Box::new(UpdateResources {
-            player: game.next,
-            update_hash,
-        }));
-        Actions {
-            args: HashMap::new(),
-            actions,
-        }
+        Ok(result)
     }
 
     fn on_next_turn(&self, game: &mut Game, moves_config: &MovesConfig) {
@@ -270,61 +626,69 @@ impl Move for Excavation {
         SubCommand::with_name(self.get_name())
             .about("Excavation")
             .arg(Arg::with_name("room_slot")
-                .required(true)
+                .requires("second_slot")
                 .help("Index of room slot")
                 .short("r")
                 .takes_value(true)
                 .validator(Excavation::_is_valid_slot))
             .arg(Arg::with_name("second_slot")
-                .required(true)
+                .requires("room_slot")
                 .help("Index of hall/room slot. Hall by default")
                 .short("h")
                 .takes_value(true)
                 .validator(Excavation::_is_valid_slot))
             .arg(Arg::with_name("two_rooms")
                 .short("t")
+                .requires("room_slot")
                 .help("Second slot will be room"))
     }
 
-    fn get_all_actions(&self, game: Game, _moves_config: &MovesConfig) -> Vec<Actions> {
-        let mut update_hash: HashMap<String, u32> = HashMap::new();
+    // Excavation is optional, the stone is taken without the slots
+    fn parse_args(&self, args: &ArgMatches) -> HashMap<String, String> {
+        let mut result = _parse_optional_args(args, &["room_slot", "second_slot"]);
+        if !result.is_empty() {
+            result.insert(String::from("two_rooms"), (args.occurrences_of("two_rooms") > 0).to_string());
+        }
+        result
+    }
+
+    fn get_all_actions(&self, game: Game, _moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        let mut update_hash: HashMap<String, i32> = HashMap::new();
         update_hash.insert(
-            constants::ResourceType::Stone.str_key(), game.moves.excavation.stone
+            constants::ResourceType::Stone.str_key(), game.moves.excavation.stone as i32
         );
 
         let mut actions: Vec<Box<MoveAction>> = Vec::new();
         actions.push(Box::new(UpdateResources {
-            player: game.next,
-            update_hash,
+            player: game.next.clone(),
+            update_hash: update_hash.clone(),
         }));
+        actions.push(Box::new(TakeAccumulated { player_move: String::from(MovesConstants::EXCAVATION) }));
 
         let mut result: Vec<Actions> = Vec::new();
         result.push(Actions {
             args: HashMap::new(),
             actions,
         });
-        result
-    }
-
-    fn get_actions(&self, game: Game, _moves_config: &MovesConfig, _args: &HashMap<String, String>) -> Actions {
-        let mut update_hash: HashMap<String, u32> = HashMap::new();
-        update_hash.insert(
-            constants::ResourceType::Stone.str_key(), game.moves.excavation.stone
-        );
 
-        let mut actions: Vec<Box<MoveAction>> = Vec::new();
-        actions.push(Box::new(UpdateResources {
-            player: game.next,
-            update_hash,
-        }));
-        Actions {
-            args: HashMap::new(),
-            actions,
+        for mut excavation in get_excavation_actions(&game, &game.next, true)? {
+            excavation.actions.insert(0, Box::new(TakeAccumulated {
+                player_move: String::from(MovesConstants::EXCAVATION),
+            }));
+            excavation.actions.insert(0, Box::new(UpdateResources {
+                player: game.next.clone(),
+                update_hash: update_hash.clone(),
+            }));
+            result.push(excavation);
         }
+        Ok(result)
     }
 
     fn on_next_turn(&self, game: &mut Game, moves_config: &MovesConfig) {
-        game.moves.excavation.stone += moves_config.excavation.stone_incr;
+        game.moves.excavation.stone += match game.moves.excavation.stone {
+            0 => moves_config.excavation.stone_incr,
+            _ => moves_config.excavation.secondary_stone_incr,
+        }
     }
 }
 
@@ -340,39 +704,20 @@ impl Move for Supplies {
             .about("Supplies")
     }
 
-    fn get_all_actions(&self, game: Game, moves_config: &MovesConfig) -> Vec<Actions> {
-        let mut update_hash: HashMap<String, u32> = HashMap::new();
-        update_hash.insert(constants::ResourceType::Stone.str_key(), moves_config.supplies.stone);
-        update_hash.insert(constants::ResourceType::Wood.str_key(), moves_config.supplies.wood);
-        update_hash.insert(constants::ResourceType::Coal.str_key(), moves_config.supplies.coal);
-        update_hash.insert(constants::ResourceType::Food.str_key(), moves_config.supplies.food);
-        update_hash.insert(constants::ResourceType::Gold.str_key(), moves_config.supplies.gold);
+    fn get_all_actions(&self, game: Game, moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        let mut update_hash: HashMap<String, i32> = HashMap::new();
+        update_hash.insert(constants::ResourceType::Stone.str_key(), moves_config.supplies.stone as i32);
+        update_hash.insert(constants::ResourceType::Wood.str_key(), moves_config.supplies.wood as i32);
+        update_hash.insert(constants::ResourceType::Coal.str_key(), moves_config.supplies.coal as i32);
+        update_hash.insert(constants::ResourceType::Food.str_key(), moves_config.supplies.food as i32);
+        update_hash.insert(constants::ResourceType::Gold.str_key(), moves_config.supplies.gold as i32);
 
         let mut actions: Vec<Box<MoveAction>> = Vec::new();
         actions.push(Box::new(UpdateResources { player: game.next, update_hash }));
 
         let mut result: Vec<Actions> = Vec::new();
         result.push(Actions { args: HashMap::new(), actions });
-        result
-    }
-
-    fn get_actions(&self, game: Game, moves_config: &MovesConfig, _args: &HashMap<String, String>) -> Actions {
-        let mut update_hash: HashMap<String, u32> = HashMap::new();
-        update_hash.insert(constants::ResourceType::Stone.str_key(), moves_config.supplies.stone);
-        update_hash.insert(constants::ResourceType::Wood.str_key(), moves_config.supplies.wood);
-        update_hash.insert(constants::ResourceType::Coal.str_key(), moves_config.supplies.coal);
-        update_hash.insert(constants::ResourceType::Food.str_key(), moves_config.supplies.food);
-        update_hash.insert(constants::ResourceType::Gold.str_key(), moves_config.supplies.gold);
-
-        let mut actions: Vec<Box<MoveAction>> = Vec::new();
-        actions.push(Box::new(UpdateResources {
-            player: game.next,
-            update_hash,
-        }));
-        Actions {
-            args: HashMap::new(),
-            actions,
-        }
+        Ok(result)
     }
 }
 
@@ -385,34 +730,52 @@ impl Move for Clearing {
 
     fn get_sub_command(&self) -> App<'static, 'static> {
         SubCommand::with_name(self.get_name())
-            .about("Wood Gathering")
+            .about("Clearing")
+            .arg(Arg::with_name("meadow_slot")
+                .help("Meadow slot of the twin")
+                .short("m")
+                .long("meadow_slot")
+                .takes_value(true)
+                .requires("field_slot")
+            ).arg(Arg::with_name("field_slot")
+                .help("Field slot of the twin")
+                .short("f")
+                .long("field_slot")
+                .takes_value(true)
+                .requires("meadow_slot")
+            )
+    }
+
+    fn parse_args(&self, args: &ArgMatches) -> HashMap<String, String> {
+        _parse_optional_args(args, &["meadow_slot", "field_slot"])
     }
 
-    fn get_all_actions(&self, game: Game, _moves_config: &MovesConfig) -> Vec<Actions> {
-        let mut update_hash: HashMap<String, u32> = HashMap::new();
-        update_hash.insert(constants::ResourceType::Wood.str_key(), game.moves.clearing.wood);
+    fn get_all_actions(&self, game: Game, _moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        let mut update_hash: HashMap<String, i32> = HashMap::new();
+        update_hash.insert(constants::ResourceType::Wood.str_key(), game.moves.clearing.wood as i32);
 
         let mut actions: Vec<Box<MoveAction>> = Vec::new();
-        actions.push(Box::new(UpdateResources { player: game.next, update_hash }));
+        actions.push(Box::new(UpdateResources { player: game.next.clone(), update_hash: update_hash.clone() }));
+        actions.push(Box::new(TakeAccumulated { player_move: String::from(MovesConstants::CLEARING) }));
 
         let mut result: Vec<Actions> = Vec::new();
         result.push(Actions { args: HashMap::new(), actions });
-        result
-    }
-
-    fn get_actions(&self, game: Game, _moves_config: &MovesConfig, _args: &HashMap<String, String>) -> Actions {
-        let mut update_hash: HashMap<String, u32> = HashMap::new();
-        update_hash.insert(constants::ResourceType::Wood.str_key(), game.moves.clearing.wood);
 
-        let mut actions: Vec<Box<MoveAction>> = Vec::new();
-        actions.push(Box::new(UpdateResources {
-            player: game.next,
-            update_hash,
-        }));
-        Actions {
-            args: HashMap::new(),
-            actions,
+        for mut clearing in get_clearing_actions(&game, &game.next)? {
+            clearing.actions.insert(0, Box::new(TakeAccumulated {
+                player_move: String::from(MovesConstants::CLEARING),
+            }));
+            clearing.actions.insert(0, Box::new(UpdateResources {
+                player: game.next.clone(),
+                update_hash: update_hash.clone(),
+            }));
+            result.push(clearing);
         }
+        Ok(result)
+    }
+
+    fn on_next_turn(&self, game: &mut Game, moves_config: &MovesConfig) {
+        game.moves.clearing.wood += moves_config.clearing.wood_incr;
     }
 }
 
@@ -428,37 +791,20 @@ impl Move for StartingPlayer {
             .about("Starting player")
     }
 
-    fn get_all_actions(&self, game: Game, moves_config: &MovesConfig) -> Vec<Actions> {
-        let mut update_hash: HashMap<String, u32> = HashMap::new();
-        update_hash.insert(constants::ResourceType::Gem.str_key(), moves_config.starting_player.gem);
-        update_hash.insert(constants::ResourceType::Coal.str_key(), moves_config.starting_player.coal);
-        update_hash.insert(constants::ResourceType::Food.str_key(), game.moves.starting_player.food);
+    fn get_all_actions(&self, game: Game, moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        let mut update_hash: HashMap<String, i32> = HashMap::new();
+        update_hash.insert(constants::ResourceType::Gem.str_key(), moves_config.starting_player.gem as i32);
+        update_hash.insert(constants::ResourceType::Coal.str_key(), moves_config.starting_player.coal as i32);
+        update_hash.insert(constants::ResourceType::Food.str_key(), game.moves.starting_player.food as i32);
 
         let mut actions: Vec<Box<MoveAction>> = Vec::new();
         actions.push(Box::new(UpdateResources { player: game.next.clone(), update_hash }));
+        actions.push(Box::new(TakeAccumulated { player_move: String::from(MovesConstants::STARTING_PLAYER) }));
         actions.push(Box::new(SetFirstPlayer { player: game.next.clone() }));
 
         let mut result: Vec<Actions> = Vec::new();
         result.push(Actions { args: HashMap::new(), actions });
-        result
-    }
-
-    fn get_actions(&self, game: Game, moves_config: &MovesConfig, _args: &HashMap<String, String>) -> Actions {
-        let mut update_hash: HashMap<String, u32> = HashMap::new();
-        update_hash.insert(constants::ResourceType::Gem.str_key(), moves_config.starting_player.gem);
-        update_hash.insert(constants::ResourceType::Coal.str_key(), moves_config.starting_player.coal);
-        update_hash.insert(constants::ResourceType::Food.str_key(), game.moves.starting_player.food);
-
-        let mut actions: Vec<Box<MoveAction>> = Vec::new();
-        actions.push(Box::new(UpdateResources {
-            player: game.next.clone(),
-            update_hash,
-        }));
-        actions.push(Box::new(SetFirstPlayer { player: game.next.clone() }));
-        Actions {
-            args: HashMap::new(),
-            actions,
-        }
+        Ok(result)
     }
 
     fn on_next_turn(&self, game: &mut Game, moves_config: &MovesConfig) {
@@ -473,37 +819,20 @@ impl Move for RubyMining {
         MovesConstants::RUBY_MINING
     }
 
-    fn get_all_actions(&self, game: Game, moves_config: &MovesConfig) -> Vec<Actions> {
-        let mut update_hash: HashMap<String, u32> = HashMap::new();
+    fn get_all_actions(&self, game: Game, moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        let mut update_hash: HashMap<String, i32> = HashMap::new();
         // TODO: add condition if player has gem mines
         let mut gems = moves_config.ruby_mining.gems;
         gems += game.moves.ruby_mining.gems;
-        update_hash.insert(constants::ResourceType::Gem.str_key(), gems);
+        update_hash.insert(constants::ResourceType::Gem.str_key(), gems as i32);
 
         let mut actions: Vec<Box<MoveAction>> = Vec::new();
         actions.push(Box::new(UpdateResources { player: game.next.clone(), update_hash }));
+        actions.push(Box::new(TakeAccumulated { player_move: String::from(MovesConstants::RUBY_MINING) }));
 
         let mut result: Vec<Actions> = Vec::new();
         result.push(Actions { args: HashMap::new(), actions });
-        result
-    }
-
-    fn get_actions(&self, game: Game, moves_config: &MovesConfig, _args: &HashMap<String, String>) -> Actions {
-        let mut update_hash: HashMap<String, u32> = HashMap::new();
-        // TODO: add condition if player has gem mines
-        let mut gems = moves_config.ruby_mining.gems;
-        gems += game.moves.ruby_mining.gems;
-        update_hash.insert(constants::ResourceType::Gem.str_key(), gems);
-
-        let mut actions: Vec<Box<MoveAction>> = Vec::new();
-        actions.push(Box::new(UpdateResources {
-            player: game.next.clone(),
-            update_hash,
-        }));
-        Actions {
-            args: HashMap::new(),
-            actions,
-        }
+        Ok(result)
     }
 
     fn on_next_turn(&self, game: &mut Game, moves_config: &MovesConfig) {
@@ -521,31 +850,41 @@ impl Move for Housework {
         MovesConstants::HOUSEWORK
     }
 
-    fn get_all_actions(&self, game: Game, _moves_config: &MovesConfig) -> Vec<Actions> {
-        let mut update_hash: HashMap<String, u32> = HashMap::new();
-        update_hash.insert(constants::ResourceType::Dog.str_key(), 1);
-
-        let mut actions: Vec<Box<MoveAction>> = Vec::new();
-        actions.push(Box::new(UpdateResources { player: game.next.clone(), update_hash }));
+    fn get_sub_command(&self) -> App<'static, 'static> {
+        SubCommand::with_name(self.get_name())
+            .about("Housework")
+            .arg(Arg::with_name("room")
+                .help("Room to furnish")
+                .short("r")
+                .long("room")
+                .takes_value(true)
+                .requires("slot")
+            ).arg(Arg::with_name("slot")
+                .help("Cavern slot for the room")
+                .short("s")
+                .long("slot")
+                .takes_value(true)
+                .requires("room")
+            )
+    }
 
-        let mut result: Vec<Actions> = Vec::new();
-        result.push(Actions { args: HashMap::new(), actions });
-        result
+    fn parse_args(&self, args: &ArgMatches) -> HashMap<String, String> {
+        _parse_optional_args(args, &["room", "slot"])
     }
 
-    fn get_actions(&self, game: Game, _moves_config: &MovesConfig, _args: &HashMap<String, String>) -> Actions {
-        let mut update_hash: HashMap<String, u32> = HashMap::new();
-        update_hash.insert(constants::ResourceType::Dog.str_key(), 1);
+    fn get_all_actions(&self, game: Game, _moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        let player = game.next.clone();
+        let base_actions = || -> Vec<Box<MoveAction>> {
+            vec![Box::new(UpdateResources {
+                player: player.clone(),
+                update_hash: hash_map! {
+                    constants::ResourceType::Dog.str_key() => 1
+                },
+            })]
+        };
 
-        let mut actions: Vec<Box<MoveAction>> = Vec::new();
-        actions.push(Box::new(UpdateResources {
-            player: game.next.clone(),
-            update_hash,
-        }));
-        Actions {
-            args: HashMap::new(),
-            actions,
-        }
+        let options = get_furnish_actions(&game, &game.next, RoomConstants::ALL_ROOMS)?;
+        Ok(_with_base_actions(&base_actions, options, true))
     }
 }
 
@@ -556,21 +895,41 @@ impl Move for SlashAndBurn {
         MovesConstants::SLASH_AND_BURN
     }
 
-    fn get_all_actions(&self, _game: Game, _moves_config: &MovesConfig) -> Vec<Actions> {
-        let actions: Vec<Box<MoveAction>> = Vec::new();
-
-        let mut result: Vec<Actions> = Vec::new();
-        result.push(Actions { args: HashMap::new(), actions });
-        result
+    fn get_sub_command(&self) -> App<'static, 'static> {
+        SubCommand::with_name(self.get_name())
+            .about("Slash-and-burn")
+            .arg(Arg::with_name("meadow_slot")
+                .help("Meadow slot of the twin")
+                .short("m")
+                .long("meadow_slot")
+                .takes_value(true)
+                .requires("field_slot")
+            ).arg(Arg::with_name("field_slot")
+                .help("Field slot of the twin")
+                .short("f")
+                .long("field_slot")
+                .takes_value(true)
+                .requires("meadow_slot")
+            ).arg(Arg::with_name("grain")
+                .help("Number of fields to sow with grain")
+                .long("grain")
+                .takes_value(true)
+                .requires("vegetables")
+            ).arg(Arg::with_name("vegetables")
+                .help("Number of fields to sow with vegetables")
+                .long("vegetables")
+                .takes_value(true)
+                .requires("grain")
+            )
     }
 
-    fn get_actions(&self, _game: Game, _moves_config: &MovesConfig, _args: &HashMap<String, String>) -> Actions {
-        let actions: Vec<Box<MoveAction>> = Vec::new();
+    fn parse_args(&self, args: &ArgMatches) -> HashMap<String, String> {
+        _parse_optional_args(args, &["meadow_slot", "field_slot", "grain", "vegetables"])
+    }
 
-        Actions {
-            args: HashMap::new(),
-            actions,
-        }
+    fn get_all_actions(&self, game: Game, _moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        let options = get_clearing_actions(&game, &game.next)?;
+        _with_sowing(&game, &game.next, _with_base_actions(&|| vec![], options, false))
     }
 }
 
@@ -581,21 +940,48 @@ impl Move for Blacksmithing {
         MovesConstants::BLACKSMITHING
     }
 
-    fn get_all_actions(&self, _game: Game, _moves_config: &MovesConfig) -> Vec<Actions> {
-        let actions: Vec<Box<MoveAction>> = Vec::new();
+    fn get_sub_command(&self) -> App<'static, 'static> {
+        SubCommand::with_name(self.get_name())
+            .about("Blacksmithing")
+            .arg(Arg::with_name("strength")
+                .help("Strength of the forged weapon, costs the same amount of ore")
+                .short("s")
+                .long("strength")
+                .takes_value(true)
+            ).arg(Arg::with_name("loot")
+                .help("Comma separated loot items in the loot table order")
+                .short("l")
+                .long("loot")
+                .takes_value(true)
+            ).arg(Arg::with_name("room")
+                .help("Room furnished from the loot")
+                .short("r")
+                .long("room")
+                .takes_value(true)
+                .requires("loot")
+            )
+    }
 
-        let mut result: Vec<Actions> = Vec::new();
-        result.push(Actions { args: HashMap::new(), actions });
-        result
+    fn parse_args(&self, args: &ArgMatches) -> HashMap<String, String> {
+        _parse_optional_args(args, &["strength", "loot", "room"])
     }
 
-    fn get_actions(&self, _game: Game, _moves_config: &MovesConfig, _args: &HashMap<String, String>) -> Actions {
-        let actions: Vec<Box<MoveAction>> = Vec::new();
+    fn get_all_actions(&self, game: Game, _moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        let player_name = game.next.clone();
 
-        Actions {
-            args: HashMap::new(),
-            actions,
+        let mut options: Vec<Actions> = Vec::new();
+        for forge in get_forge_actions(&game, &player_name)?.into_iter() {
+            let strength = forge.args["strength"].parse::<u32>().unwrap();
+            let mut state = _with_placed_gnome(&game, &player_name)?;
+            if forge.try_perform_part(&mut state).is_err() {
+                continue;
+            }
+
+            let base_actions = || _forge_weapon(&player_name, strength);
+            options.extend(_get_expedition_options(&state, &player_name, strength, 3, &base_actions, &forge.args)?);
+            options.push(forge);
         }
+        Ok(_with_base_actions(&|| vec![], options, true))
     }
 }
 
@@ -606,21 +992,47 @@ impl Move for SheepFarming {
         MovesConstants::SHEEP_FARMING
     }
 
-    fn get_all_actions(&self, _game: Game, _moves_config: &MovesConfig) -> Vec<Actions> {
-        let actions: Vec<Box<MoveAction>> = Vec::new();
+    fn get_sub_command(&self) -> App<'static, 'static> {
+        SubCommand::with_name(self.get_name())
+            .about("Sheep farming")
+            .arg(Arg::with_name("pasture")
+                .help("Meadow slots to fence, one for small pasture or two comma separated for large one")
+                .short("p")
+                .long("pasture")
+                .takes_value(true)
+            ).arg(Arg::with_name("stable")
+                .help("Slot for a new stable")
+                .short("s")
+                .long("stable")
+                .takes_value(true)
+            )
+    }
 
-        let mut result: Vec<Actions> = Vec::new();
-        result.push(Actions { args: HashMap::new(), actions });
-        result
+    fn parse_args(&self, args: &ArgMatches) -> HashMap<String, String> {
+        _parse_optional_args(args, &["pasture", "stable"])
     }
 
-    fn get_actions(&self, _game: Game, _moves_config: &MovesConfig, _args: &HashMap<String, String>) -> Actions {
-        let actions: Vec<Box<MoveAction>> = Vec::new();
+    fn get_all_actions(&self, game: Game, _moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        let player = game.next.clone();
+        let sheep = game.moves.sheep_farming.sheep;
+        let base_actions = || -> Vec<Box<MoveAction>> {
+            vec![
+                Box::new(UpdateResources {
+                    player: player.clone(),
+                    update_hash: hash_map! {
+                        constants::ResourceType::Sheep.str_key() => sheep as i32
+                    },
+                }),
+                Box::new(TakeAccumulated { player_move: String::from(MovesConstants::SHEEP_FARMING) }),
+            ]
+        };
 
-        Actions {
-            args: HashMap::new(),
-            actions,
-        }
+        let options = get_farming_actions(&game, &game.next)?;
+        Ok(_with_base_actions(&base_actions, options, true))
+    }
+
+    fn on_next_turn(&self, game: &mut Game, moves_config: &MovesConfig) {
+        game.moves.sheep_farming.sheep += moves_config.sheep_farming.sheep_incr;
     }
 }
 
@@ -631,21 +1043,84 @@ impl Move for OreMineConstruction {
         MovesConstants::ORE_MINE_CONSTRUCTION
     }
 
-    fn get_all_actions(&self, _game: Game, _moves_config: &MovesConfig) -> Vec<Actions> {
-        let actions: Vec<Box<MoveAction>> = Vec::new();
+    fn get_sub_command(&self) -> App<'static, 'static> {
+        SubCommand::with_name(self.get_name())
+            .about("Ore mine construction")
+            .arg(Arg::with_name("mine_slot")
+                .help("Tunnel slot for the ore mine")
+                .short("m")
+                .long("mine_slot")
+                .takes_value(true)
+                .requires("mine_hall_slot")
+            ).arg(Arg::with_name("mine_hall_slot")
+                .help("Tunnel slot for the deep tunnel")
+                .short("d")
+                .long("mine_hall_slot")
+                .takes_value(true)
+                .requires("mine_slot")
+            ).arg(Arg::with_name("loot")
+                .help("Comma separated loot items in the loot table order")
+                .short("l")
+                .long("loot")
+                .takes_value(true)
+            ).arg(Arg::with_name("room")
+                .help("Room furnished from the loot")
+                .short("r")
+                .long("room")
+                .takes_value(true)
+                .requires("loot")
+            )
+    }
 
-        let mut result: Vec<Actions> = Vec::new();
-        result.push(Actions { args: HashMap::new(), actions });
-        result
+    fn parse_args(&self, args: &ArgMatches) -> HashMap<String, String> {
+        _parse_optional_args(args, &["mine_slot", "mine_hall_slot", "loot", "room"])
     }
 
-    fn get_actions(&self, _game: Game, _moves_config: &MovesConfig, _args: &HashMap<String, String>) -> Actions {
-        let actions: Vec<Box<MoveAction>> = Vec::new();
+    fn get_all_actions(&self, game: Game, moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        let player = game.find_player(&game.next)?;
 
-        Actions {
-            args: HashMap::new(),
-            actions,
+        let mut twins: Vec<(u32, u32)> = Vec::new();
+        for &(first_slot, second_slot) in player.get_ore_mine_pair_slots().iter() {
+            twins.push((first_slot, second_slot));
+            twins.push((second_slot, first_slot));
+        }
+
+        let build_mines = |mine_slot: u32, mine_hall_slot: u32| -> Vec<Box<MoveAction>> {
+            vec![
+                Box::new(BuildMines {
+                    player: game.next.clone(),
+                    mines: vec![
+                        PlayerCavern { cavern_type: InsideElement::Mine, position: mine_slot },
+                        PlayerCavern { cavern_type: InsideElement::MineHall, position: mine_hall_slot },
+                    ],
+                }),
+                Box::new(UpdateResources {
+                    player: game.next.clone(),
+                    update_hash: hash_map! {
+                        constants::ResourceType::Coal.str_key() => moves_config.ore_mine_construction.coal as i32
+                    },
+                }),
+            ]
+        };
+        // Armed gnome goes on a level 2 expedition after the construction
+        let weapon = player.get_next_gnome_weapon();
+
+        let mut options: Vec<Actions> = Vec::new();
+        for (mine_slot, mine_hall_slot) in twins.into_iter() {
+            let args = hash_map! {
+                String::from("mine_slot") => mine_slot.to_string(),
+                String::from("mine_hall_slot") => mine_hall_slot.to_string()
+            };
+            if let Some(weapon) = weapon {
+                let mut state = _with_placed_gnome(&game, &game.next)?;
+                if Actions::from_vec(build_mines(mine_slot, mine_hall_slot)).try_perform_part(&mut state).is_ok() {
+                    let base_actions = || build_mines(mine_slot, mine_hall_slot);
+                    options.extend(_get_expedition_options(&state, &game.next, weapon, 2, &base_actions, &args)?);
+                }
+            }
+            options.push(Actions { args, actions: build_mines(mine_slot, mine_hall_slot) });
         }
+        Ok(_with_base_actions(&|| vec![], options, false))
     }
 }
 
@@ -656,21 +1131,32 @@ impl Move for WishForChildren {
         MovesConstants::WISH_FOR_CHILDREN
     }
 
-    fn get_all_actions(&self, _game: Game, _moves_config: &MovesConfig) -> Vec<Actions> {
-        let actions: Vec<Box<MoveAction>> = Vec::new();
-
-        let mut result: Vec<Actions> = Vec::new();
-        result.push(Actions { args: HashMap::new(), actions });
-        result
+    fn get_sub_command(&self) -> App<'static, 'static> {
+        SubCommand::with_name(self.get_name())
+            .about("Wish for children, family growth unless dwelling is furnished")
+            .arg(Arg::with_name("room")
+                .help("Dwelling to furnish instead of family growth")
+                .short("r")
+                .long("room")
+                .takes_value(true)
+                .requires("slot")
+            ).arg(Arg::with_name("slot")
+                .help("Cavern slot for the dwelling")
+                .short("s")
+                .long("slot")
+                .takes_value(true)
+                .requires("room")
+            )
     }
 
-    fn get_actions(&self, _game: Game, _moves_config: &MovesConfig, _args: &HashMap<String, String>) -> Actions {
-        let actions: Vec<Box<MoveAction>> = Vec::new();
+    fn parse_args(&self, args: &ArgMatches) -> HashMap<String, String> {
+        _parse_optional_args(args, &["room", "slot"])
+    }
 
-        Actions {
-            args: HashMap::new(),
-            actions,
-        }
+    fn get_all_actions(&self, game: Game, _moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        let mut result: Vec<Actions> = get_family_growth_actions(&game, &game.next)?;
+        result.extend(get_furnish_actions(&game, &game.next, RoomConstants::GINGER_ROOMS)?);
+        Ok(result)
     }
 }
 
@@ -681,21 +1167,47 @@ impl Move for DonkeyFarming {
         MovesConstants::DONKEY_FARMING
     }
 
-    fn get_all_actions(&self, _game: Game, _moves_config: &MovesConfig) -> Vec<Actions> {
-        let actions: Vec<Box<MoveAction>> = Vec::new();
+    fn get_sub_command(&self) -> App<'static, 'static> {
+        SubCommand::with_name(self.get_name())
+            .about("Donkey farming")
+            .arg(Arg::with_name("pasture")
+                .help("Meadow slots to fence, one for small pasture or two comma separated for large one")
+                .short("p")
+                .long("pasture")
+                .takes_value(true)
+            ).arg(Arg::with_name("stable")
+                .help("Slot for a new stable")
+                .short("s")
+                .long("stable")
+                .takes_value(true)
+            )
+    }
 
-        let mut result: Vec<Actions> = Vec::new();
-        result.push(Actions { args: HashMap::new(), actions });
-        result
+    fn parse_args(&self, args: &ArgMatches) -> HashMap<String, String> {
+        _parse_optional_args(args, &["pasture", "stable"])
     }
 
-    fn get_actions(&self, _game: Game, _moves_config: &MovesConfig, _args: &HashMap<String, String>) -> Actions {
-        let actions: Vec<Box<MoveAction>> = Vec::new();
+    fn get_all_actions(&self, game: Game, _moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        let player = game.next.clone();
+        let donkeys = game.moves.donkey_farming.donkeys;
+        let base_actions = || -> Vec<Box<MoveAction>> {
+            vec![
+                Box::new(UpdateResources {
+                    player: player.clone(),
+                    update_hash: hash_map! {
+                        constants::ResourceType::Donkey.str_key() => donkeys as i32
+                    },
+                }),
+                Box::new(TakeAccumulated { player_move: String::from(MovesConstants::DONKEY_FARMING) }),
+            ]
+        };
 
-        Actions {
-            args: HashMap::new(),
-            actions,
-        }
+        let options = get_farming_actions(&game, &game.next)?;
+        Ok(_with_base_actions(&base_actions, options, true))
+    }
+
+    fn on_next_turn(&self, game: &mut Game, moves_config: &MovesConfig) {
+        game.moves.donkey_farming.donkeys += moves_config.donkey_farming.donkey_incr;
     }
 }
 
@@ -706,21 +1218,54 @@ impl Move for RubyMineConstruction {
         MovesConstants::RUBY_MINE_CONSTRUCTION
     }
 
-    fn get_all_actions(&self, _game: Game, _moves_config: &MovesConfig) -> Vec<Actions> {
-        let actions: Vec<Box<MoveAction>> = Vec::new();
-
-        let mut result: Vec<Actions> = Vec::new();
-        result.push(Actions { args: HashMap::new(), actions });
-        result
+    fn get_sub_command(&self) -> App<'static, 'static> {
+        SubCommand::with_name(self.get_name())
+            .about("Ruby mine construction")
+            .arg(Arg::with_name("slot")
+                .help("Tunnel or deep tunnel slot for the ruby mine")
+                .short("s")
+                .long("slot")
+                .takes_value(true)
+            )
     }
 
-    fn get_actions(&self, _game: Game, _moves_config: &MovesConfig, _args: &HashMap<String, String>) -> Actions {
-        let actions: Vec<Box<MoveAction>> = Vec::new();
-
-        Actions {
-            args: HashMap::new(),
-            actions,
-        }
+    fn parse_args(&self, args: &ArgMatches) -> HashMap<String, String> {
+        _parse_optional_args(args, &["slot"])
+    }
+
+    fn get_all_actions(&self, game: Game, _moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        let player = game.find_player(&game.next)?;
+
+        let options: Vec<Actions> = player.get_ruby_mine_slots()
+            .into_iter()
+            .map(|slot| {
+                let mut actions: Vec<Box<MoveAction>> = vec![
+                    Box::new(BuildMines {
+                        player: game.next.clone(),
+                        mines: vec![PlayerCavern { cavern_type: InsideElement::GemMine, position: slot }],
+                    }),
+                ];
+                // Ruby mine in a deep tunnel brings a ruby right away
+                let is_deep = player.caverns
+                    .iter()
+                    .any(|c| c.position == slot && c.cavern_type == InsideElement::MineHall);
+                if is_deep {
+                    actions.push(Box::new(UpdateResources {
+                        player: game.next.clone(),
+                        update_hash: hash_map! {
+                            constants::ResourceType::Gem.str_key() => 1
+                        },
+                    }));
+                }
+                Actions {
+                    args: hash_map! {
+                        String::from("slot") => slot.to_string()
+                    },
+                    actions,
+                }
+            })
+            .collect();
+        Ok(_with_base_actions(&|| vec![], options, false))
     }
 }
 
@@ -731,21 +1276,46 @@ impl Move for FamilyLife {
         MovesConstants::FAMILY_LIFE
     }
 
-    fn get_all_actions(&self, _game: Game, _moves_config: &MovesConfig) -> Vec<Actions> {
-        let actions: Vec<Box<MoveAction>> = Vec::new();
-
-        let mut result: Vec<Actions> = Vec::new();
-        result.push(Actions { args: HashMap::new(), actions });
-        result
+    fn get_all_actions(&self, game: Game, _moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        let mut options: Vec<Actions> = Vec::new();
+        options.push(Actions { args: HashMap::new(), actions: vec![] });
+        for growth in get_family_growth_actions(&game, &game.next)?.into_iter() {
+            options.push(Actions {
+                args: hash_map! {
+                    String::from("family_growth") => String::from("true")
+                },
+                actions: growth.actions,
+            });
+        }
+        _with_sowing(&game, &game.next, options)
     }
 
-    fn get_actions(&self, _game: Game, _moves_config: &MovesConfig, _args: &HashMap<String, String>) -> Actions {
-        let actions: Vec<Box<MoveAction>> = Vec::new();
+    fn get_sub_command(&self) -> App<'static, 'static> {
+        SubCommand::with_name(self.get_name())
+            .about("Family life")
+            .arg(Arg::with_name("family_growth")
+                .help("Get a new gnome")
+                .short("g")
+                .long("family_growth")
+            ).arg(Arg::with_name("grain")
+                .help("Number of fields to sow with grain")
+                .long("grain")
+                .takes_value(true)
+                .requires("vegetables")
+            ).arg(Arg::with_name("vegetables")
+                .help("Number of fields to sow with vegetables")
+                .long("vegetables")
+                .takes_value(true)
+                .requires("grain")
+            )
+    }
 
-        Actions {
-            args: HashMap::new(),
-            actions,
+    fn parse_args(&self, args: &ArgMatches) -> HashMap<String, String> {
+        let mut result = _parse_optional_args(args, &["grain", "vegetables"]);
+        if args.occurrences_of("family_growth") > 0 {
+            result.insert(String::from("family_growth"), String::from("true"));
         }
+        result
     }
 }
 
@@ -756,21 +1326,36 @@ impl Move for OreDelivery {
         MovesConstants::ORE_DELIVERY
     }
 
-    fn get_all_actions(&self, _game: Game, _moves_config: &MovesConfig) -> Vec<Actions> {
-        let actions: Vec<Box<MoveAction>> = Vec::new();
-
-        let mut result: Vec<Actions> = Vec::new();
-        result.push(Actions { args: HashMap::new(), actions });
-        result
+    fn get_sub_command(&self) -> App<'static, 'static> {
+        SubCommand::with_name(self.get_name())
+            .about("Ore delivery")
     }
 
-    fn get_actions(&self, _game: Game, _moves_config: &MovesConfig, _args: &HashMap<String, String>) -> Actions {
-        let actions: Vec<Box<MoveAction>> = Vec::new();
+    fn get_all_actions(&self, game: Game, moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        let mines = game.find_player(&game.next)?.get_caverns_count(InsideElement::Mine);
+
+        let mut update_hash: HashMap<String, i32> = HashMap::new();
+        update_hash.insert(constants::ResourceType::Stone.str_key(), game.moves.ore_delivery.stone as i32);
+        update_hash.insert(
+            constants::ResourceType::Coal.str_key(),
+            (game.moves.ore_delivery.coal + mines * moves_config.ore_delivery.coal_per_mine) as i32
+        );
 
-        Actions {
+        let mut actions: Vec<Box<MoveAction>> = Vec::new();
+        actions.push(Box::new(UpdateResources {
+            player: game.next.clone(),
+            update_hash,
+        }));
+        actions.push(Box::new(TakeAccumulated { player_move: String::from(MovesConstants::ORE_DELIVERY) }));
+        Ok(vec![Actions {
             args: HashMap::new(),
             actions,
-        }
+        }])
+    }
+
+    fn on_next_turn(&self, game: &mut Game, moves_config: &MovesConfig) {
+        game.moves.ore_delivery.stone += moves_config.ore_delivery.stone_incr;
+        game.moves.ore_delivery.coal += moves_config.ore_delivery.coal_incr;
     }
 }
 
@@ -781,21 +1366,100 @@ impl Move for Adventure {
         MovesConstants::ADVENTURE
     }
 
-    fn get_all_actions(&self, _game: Game, _moves_config: &MovesConfig) -> Vec<Actions> {
-        let actions: Vec<Box<MoveAction>> = Vec::new();
-
-        let mut result: Vec<Actions> = Vec::new();
-        result.push(Actions { args: HashMap::new(), actions });
-        result
+    fn get_sub_command(&self) -> App<'static, 'static> {
+        SubCommand::with_name(self.get_name())
+            .about("Adventure")
+            .arg(Arg::with_name("strength")
+                .help("Strength of the forged weapon, costs the same amount of ore")
+                .short("s")
+                .long("strength")
+                .takes_value(true)
+            ).arg(Arg::with_name("loot")
+                .help("Comma separated loot items in the loot table order")
+                .short("l")
+                .long("loot")
+                .takes_value(true)
+            ).arg(Arg::with_name("room")
+                .help("Room furnished from the loot")
+                .short("r")
+                .long("room")
+                .takes_value(true)
+                .requires("loot")
+            )
+            .arg(Arg::with_name("second_loot")
+                .help("Loot items of the second expedition")
+                .long("second_loot")
+                .takes_value(true)
+                .requires("loot")
+            ).arg(Arg::with_name("second_room")
+                .help("Room furnished from the second loot")
+                .long("second_room")
+                .takes_value(true)
+                .requires("second_loot")
+            )
     }
 
-    fn get_actions(&self, _game: Game, _moves_config: &MovesConfig, _args: &HashMap<String, String>) -> Actions {
-        let actions: Vec<Box<MoveAction>> = Vec::new();
-
-        Actions {
-            args: HashMap::new(),
-            actions,
+    fn parse_args(&self, args: &ArgMatches) -> HashMap<String, String> {
+        _parse_optional_args(args, &["strength", "loot", "room", "second_loot", "second_room"])
+    }
+
+    fn get_all_actions(&self, game: Game, _moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        let player_name = game.next.clone();
+        let weapon = game.find_player(&player_name)?.get_next_gnome_weapon();
+
+        // Unarmed gnome has to forge a weapon first
+        let mut forges: Vec<Option<u32>> = vec![None];
+        forges.extend(_get_forge_strengths(&game, &player_name)?.into_iter().map(Some));
+
+        let mut options: Vec<Actions> = Vec::new();
+        for forged in forges.into_iter() {
+            let weapon = match forged.or(weapon) {
+                Some(weapon) => weapon,
+                None => continue,
+            };
+            let base_actions = || match forged {
+                Some(strength) => _forge_weapon(&player_name, strength),
+                None => vec![],
+            };
+            let base_args: HashMap<String, String> = match forged {
+                Some(strength) => hash_map! { String::from("strength") => strength.to_string() },
+                None => HashMap::new(),
+            };
+            let mut state = _with_placed_gnome(&game, &player_name)?;
+            if Actions::from_vec(base_actions()).try_perform_part(&mut state).is_err() {
+                continue;
+            }
+
+            for first in get_expedition_picks(&state, &player_name, weapon, 1)?.iter() {
+                let mut second_state = state.clone();
+                match build_expedition_actions(&state, &player_name, weapon, first)? {
+                    Some(ref expedition) if expedition.try_perform_part(&mut second_state).is_ok() => (),
+                    _ => continue,
+                }
+                let second_weapon = get_upgraded_weapon(weapon, first);
+
+                for second in get_expedition_picks(&second_state, &player_name, second_weapon, 1)?.iter() {
+                    let expeditions = (
+                        build_expedition_actions(&state, &player_name, weapon, first)?,
+                        build_expedition_actions(&second_state, &player_name, second_weapon, second)?,
+                    );
+                    let (first_expedition, second_expedition) = match expeditions {
+                        (Some(first_expedition), Some(second_expedition)) => (first_expedition, second_expedition),
+                        _ => continue,
+                    };
+
+                    let mut args = base_args.clone();
+                    args.extend(first_expedition.args);
+                    args.extend(second_expedition.args.into_iter().map(|(k, v)| (format!("second_{}", k), v)));
+
+                    let mut actions = base_actions();
+                    actions.extend(first_expedition.actions);
+                    actions.extend(second_expedition.actions);
+                    options.push(Actions { args, actions });
+                }
+            }
         }
+        Ok(_with_base_actions(&|| vec![], options, true))
     }
 }
 
@@ -806,45 +1470,260 @@ impl Move for OreTrading {
         MovesConstants::ORE_TRADING
     }
 
-    fn get_all_actions(&self, _game: Game, _moves_config: &MovesConfig) -> Vec<Actions> {
-        let actions: Vec<Box<MoveAction>> = Vec::new();
+    fn get_sub_command(&self) -> App<'static, 'static> {
+        SubCommand::with_name(self.get_name())
+            .about("Ore trading")
+            .arg(Arg::with_name("trades")
+                .help("How many times to trade ore for gold and food")
+                .short("t")
+                .long("trades")
+                .takes_value(true)
+            )
+    }
 
-        let mut result: Vec<Actions> = Vec::new();
-        result.push(Actions { args: HashMap::new(), actions });
-        result
+    fn parse_args(&self, args: &ArgMatches) -> HashMap<String, String> {
+        _parse_optional_args(args, &["trades"])
+    }
+
+    fn get_all_actions(&self, game: Game, moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        let config = &moves_config.ore_trading;
+        let coal = game.find_player(&game.next)?.get_resource(constants::ResourceType::Coal);
+        let max_trades = (coal / config.coal).min(config.max_trades);
+
+        let options: Vec<Actions> = (1..max_trades + 1)
+            .map(|trades| {
+                let actions: Vec<Box<MoveAction>> = vec![
+                    Box::new(UpdateResources {
+                        player: game.next.clone(),
+                        update_hash: hash_map! {
+                            constants::ResourceType::Coal.str_key() => -((trades * config.coal) as i32),
+                            constants::ResourceType::Gold.str_key() => (trades * config.gold) as i32,
+                            constants::ResourceType::Food.str_key() => (trades * config.food) as i32
+                        },
+                    }),
+                ];
+                Actions {
+                    args: hash_map! {
+                        String::from("trades") => trades.to_string()
+                    },
+                    actions,
+                }
+            })
+            .collect();
+        Ok(_with_base_actions(&|| vec![], options, false))
     }
+}
 
-    fn get_actions(&self, _game: Game, _moves_config: &MovesConfig, _args: &HashMap<String, String>) -> Actions {
-        let actions: Vec<Box<MoveAction>> = Vec::new();
+pub struct RubyDelivery {}
 
-        Actions {
+impl Move for RubyDelivery {
+    fn get_name(&self) -> &str {
+        MovesConstants::RUBY_DELIVERY
+    }
+
+    fn get_sub_command(&self) -> App<'static, 'static> {
+        SubCommand::with_name(self.get_name())
+            .about("Ruby delivery")
+    }
+
+    fn get_all_actions(&self, game: Game, moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        let mut gems = game.moves.ruby_delivery.gems;
+        if game.find_player(&game.next)?.get_caverns_count(InsideElement::GemMine) >= moves_config.ruby_delivery.mines_for_bonus {
+            gems += 1;
+        }
+
+        let mut actions: Vec<Box<MoveAction>> = Vec::new();
+        actions.push(Box::new(UpdateResources {
+            player: game.next.clone(),
+            update_hash: hash_map! {
+                constants::ResourceType::Gem.str_key() => gems as i32
+            },
+        }));
+        actions.push(Box::new(TakeAccumulated { player_move: String::from(MovesConstants::RUBY_DELIVERY) }));
+        Ok(vec![Actions {
             args: HashMap::new(),
             actions,
+        }])
+    }
+
+    fn on_next_turn(&self, game: &mut Game, moves_config: &MovesConfig) {
+        game.moves.ruby_delivery.gems += moves_config.ruby_delivery.gem_incr;
+    }
+}
+
+pub struct Exploration {}
+
+impl Move for Exploration {
+    fn get_name(&self) -> &str {
+        MovesConstants::EXPLORATION
+    }
+
+    fn get_sub_command(&self) -> App<'static, 'static> {
+        SubCommand::with_name(self.get_name())
+            .about("Exploration, level 4 expedition")
+            .arg(Arg::with_name("loot")
+                .help("Comma separated loot items in the loot table order")
+                .short("l")
+                .long("loot")
+                .takes_value(true)
+            ).arg(Arg::with_name("room")
+                .help("Room furnished from the loot")
+                .short("r")
+                .long("room")
+                .takes_value(true)
+                .requires("loot")
+            )
+    }
+
+    fn parse_args(&self, args: &ArgMatches) -> HashMap<String, String> {
+        _parse_optional_args(args, &["loot", "room"])
+    }
+
+    // Only an armed gnome can go exploring
+    fn get_all_actions(&self, game: Game, _moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        match game.find_player(&game.next)?.get_next_gnome_weapon() {
+            Some(weapon) => {
+                let state = _with_placed_gnome(&game, &game.next)?;
+                get_expedition_actions(&state, &game.next, weapon, 4)
+            },
+            None => Ok(vec![]),
         }
     }
 }
 
-pub struct RubyDelivery {}
+pub struct OreMining {}
 
-impl Move for RubyDelivery {
+impl Move for OreMining {
     fn get_name(&self) -> &str {
-        MovesConstants::RUBY_DELIVERY
+        MovesConstants::ORE_MINING
     }
 
-    fn get_all_actions(&self, _game: Game, _moves_config: &MovesConfig) -> Vec<Actions> {
-        let actions: Vec<Box<MoveAction>> = Vec::new();
+    fn get_sub_command(&self) -> App<'static, 'static> {
+        SubCommand::with_name(self.get_name())
+            .about("Ore mining")
+    }
 
-        let mut result: Vec<Actions> = Vec::new();
-        result.push(Actions { args: HashMap::new(), actions });
-        result
+    fn get_all_actions(&self, game: Game, moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        let mines = game.find_player(&game.next)?.get_caverns_count(InsideElement::Mine);
+
+        let mut actions: Vec<Box<MoveAction>> = Vec::new();
+        actions.push(Box::new(UpdateResources {
+            player: game.next.clone(),
+            update_hash: hash_map! {
+                constants::ResourceType::Coal.str_key() => (game.moves.ore_mining.coal + mines * moves_config.ore_mining.coal_per_mine) as i32
+            },
+        }));
+        actions.push(Box::new(TakeAccumulated { player_move: String::from(MovesConstants::ORE_MINING) }));
+        Ok(vec![Actions {
+            args: HashMap::new(),
+            actions,
+        }])
+    }
+
+    fn on_next_turn(&self, game: &mut Game, moves_config: &MovesConfig) {
+        game.moves.ore_mining.coal += moves_config.ore_mining.coal_incr;
+    }
+}
+
+pub struct Sustenance {}
+
+impl Move for Sustenance {
+    fn get_name(&self) -> &str {
+        MovesConstants::SUSTENANCE
     }
 
-    fn get_actions(&self, _game: Game, _moves_config: &MovesConfig, _args: &HashMap<String, String>) -> Actions {
-        let actions: Vec<Box<MoveAction>> = Vec::new();
+    fn get_sub_command(&self) -> App<'static, 'static> {
+        SubCommand::with_name(self.get_name())
+            .about("Sustenance")
+    }
 
-        Actions {
+    fn get_all_actions(&self, game: Game, moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        let mut actions: Vec<Box<MoveAction>> = Vec::new();
+        actions.push(Box::new(UpdateResources {
+            player: game.next.clone(),
+            update_hash: hash_map! {
+                constants::ResourceType::Food.str_key() => game.moves.sustenance.food as i32,
+                constants::ResourceType::Wheat.str_key() => moves_config.sustenance.wheat as i32
+            },
+        }));
+        actions.push(Box::new(TakeAccumulated { player_move: String::from(MovesConstants::SUSTENANCE) }));
+        Ok(vec![Actions {
             args: HashMap::new(),
             actions,
-        }
+        }])
+    }
+
+    fn on_next_turn(&self, game: &mut Game, moves_config: &MovesConfig) {
+        game.moves.sustenance.food += moves_config.sustenance.food_incr;
+    }
+}
+
+pub struct Depot {}
+
+impl Move for Depot {
+    fn get_name(&self) -> &str {
+        MovesConstants::DEPOT
+    }
+
+    fn get_sub_command(&self) -> App<'static, 'static> {
+        SubCommand::with_name(self.get_name())
+            .about("Depot")
+    }
+
+    fn get_all_actions(&self, game: Game, _moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        let mut actions: Vec<Box<MoveAction>> = Vec::new();
+        actions.push(Box::new(UpdateResources {
+            player: game.next.clone(),
+            update_hash: hash_map! {
+                constants::ResourceType::Wood.str_key() => game.moves.depot.wood as i32,
+                constants::ResourceType::Coal.str_key() => game.moves.depot.coal as i32
+            },
+        }));
+        actions.push(Box::new(TakeAccumulated { player_move: String::from(MovesConstants::DEPOT) }));
+        Ok(vec![Actions {
+            args: HashMap::new(),
+            actions,
+        }])
+    }
+
+    fn on_next_turn(&self, game: &mut Game, moves_config: &MovesConfig) {
+        game.moves.depot.wood += moves_config.depot.wood_incr;
+        game.moves.depot.coal += moves_config.depot.coal_incr;
+    }
+}
+
+pub struct LargeDepot {}
+
+impl Move for LargeDepot {
+    fn get_name(&self) -> &str {
+        MovesConstants::LARGE_DEPOT
+    }
+
+    fn get_sub_command(&self) -> App<'static, 'static> {
+        SubCommand::with_name(self.get_name())
+            .about("Large depot")
+    }
+
+    fn get_all_actions(&self, game: Game, _moves_config: &MovesConfig) -> Result<Vec<Actions>, GameError> {
+        let mut actions: Vec<Box<MoveAction>> = Vec::new();
+        actions.push(Box::new(UpdateResources {
+            player: game.next.clone(),
+            update_hash: hash_map! {
+                constants::ResourceType::Wood.str_key() => game.moves.large_depot.wood as i32,
+                constants::ResourceType::Stone.str_key() => game.moves.large_depot.stone as i32,
+                constants::ResourceType::Coal.str_key() => game.moves.large_depot.coal as i32
+            },
+        }));
+        actions.push(Box::new(TakeAccumulated { player_move: String::from(MovesConstants::LARGE_DEPOT) }));
+        Ok(vec![Actions {
+            args: HashMap::new(),
+            actions,
+        }])
+    }
+
+    fn on_next_turn(&self, game: &mut Game, moves_config: &MovesConfig) {
+        game.moves.large_depot.wood += moves_config.large_depot.wood_incr;
+        game.moves.large_depot.stone += moves_config.large_depot.stone_incr;
+        game.moves.large_depot.coal += moves_config.large_depot.coal_incr;
     }
 }
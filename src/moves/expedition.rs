@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+
+use actions::{
+    MoveAction, Actions, UpdateResources, PayResources, BuildRooms, Excavate, BuildFields, BuildPastures,
+    BuildStables, UpgradeWeapon, UpgradeAllWeapons,
+};
+use constants::{ResourceType, InsideElement, OutsideElement, MAX_WEAPON_STRENGTH};
+use errors::{GameError};
+use models::game::{Game, Player, PlayerRoom, PlayerCavern, PlayerField};
+
+
+pub const UPGRADE_WEAPONS: &str = "upgrade_weapons";
+pub const FURNISH: &str = "furnish";
+pub const STABLE: &str = "stable";
+pub const TUNNEL: &str = "tunnel";
+pub const MEADOW: &str = "meadow";
+pub const SMALL_PASTURE: &str = "small_pasture";
+pub const FIELD: &str = "field";
+pub const LARGE_PASTURE: &str = "large_pasture";
+pub const CAVERN: &str = "cavern";
+
+pub struct LootItem {
+    pub name: &'static str,
+    pub strength: u32,
+    pub resources: &'static [(ResourceType, u32)],
+}
+
+// Every item can be taken once per expedition, weapon has to be at least as strong as the item
+pub static LOOT_TABLE: &'static [LootItem] = &[
+    LootItem { name: UPGRADE_WEAPONS, strength: 1, resources: &[] },
+    LootItem { name: "wood", strength: 1, resources: &[(ResourceType::Wood, 1)] },
+    LootItem { name: "dog", strength: 1, resources: &[(ResourceType::Dog, 1)] },
+    LootItem { name: "wheat", strength: 2, resources: &[(ResourceType::Wheat, 1)] },
+    LootItem { name: "sheep", strength: 2, resources: &[(ResourceType::Sheep, 1)] },
+    LootItem { name: "stone", strength: 3, resources: &[(ResourceType::Stone, 1)] },
+    LootItem { name: "donkey", strength: 3, resources: &[(ResourceType::Donkey, 1)] },
+    LootItem { name: "pumpkin", strength: 4, resources: &[(ResourceType::Pumpkin, 1)] },
+    LootItem { name: "coal", strength: 4, resources: &[(ResourceType::Coal, 2)] },
+    LootItem { name: "hippo", strength: 5, resources: &[(ResourceType::Hippo, 1)] },
+    LootItem { name: "gold", strength: 6, resources: &[(ResourceType::Gold, 2)] },
+    LootItem { name: FURNISH, strength: 7, resources: &[] },
+    LootItem { name: STABLE, strength: 8, resources: &[] },
+    LootItem { name: TUNNEL, strength: 8, resources: &[] },
+    LootItem { name: "cow", strength: 9, resources: &[(ResourceType::Cow, 1)] },
+    LootItem { name: MEADOW, strength: 10, resources: &[] },
+    LootItem { name: SMALL_PASTURE, strength: 10, resources: &[] },
+    LootItem { name: FIELD, strength: 11, resources: &[] },
+    LootItem { name: LARGE_PASTURE, strength: 11, resources: &[] },
+    LootItem { name: CAVERN, strength: 12, resources: &[] },
+];
+
+// Loot items picked in one expedition, furnished room is stored next to the item
+pub type LootPick = Vec<(&'static str, Option<String>)>;
+
+// Expedition of the gnome with given weapon, the gnome should be already placed in the game.
+// Structures from the loot are placed on the lowest free slot to keep the options count sane.
+pub fn get_expedition_actions(game: &Game, player_name: &str, weapon: u32, level: u32) -> Result<Vec<Actions>, GameError> {
+    let mut result: Vec<Actions> = Vec::new();
+    for pick in get_expedition_picks(game, player_name, weapon, level)?.iter() {
+        if let Some(actions) = build_expedition_actions(game, player_name, weapon, pick)? {
+            result.push(actions);
+        }
+    }
+    Ok(result)
+}
+
+pub fn get_expedition_picks(game: &Game, player_name: &str, weapon: u32, level: u32) -> Result<Vec<LootPick>, GameError> {
+    let items: Vec<&'static LootItem> = LOOT_TABLE.iter().filter(|i| i.strength <= weapon).collect();
+
+    let mut picks: Vec<LootPick> = Vec::new();
+    _collect_loot(game, player_name, &items, 0, level, &mut vec![], &mut picks)?;
+    Ok(picks)
+}
+
+// Pick is skipped with None when one of its items can't be taken after the previous ones
+pub fn build_expedition_actions(game: &Game, player_name: &str, weapon: u32, pick: &LootPick
+) -> Result<Option<Actions>, GameError> {
+    let mut state = game.clone();
+    let mut actions: Vec<Box<MoveAction>> = vec![
+        Box::new(UpgradeWeapon {
+            player: String::from(player_name),
+            weapon,
+        }),
+    ];
+    for &(name, ref room) in pick.iter() {
+        let loot = match _build_loot(&state, state.find_player(player_name)?, name, room) {
+            Some(loot) => loot,
+            None => return Ok(None),
+        };
+        for action in loot.iter() {
+            if action.try_perform(&mut state).is_err() {
+                return Ok(None);
+            }
+        }
+        actions.extend(loot);
+    }
+
+    let mut args = hash_map! {
+        String::from("loot") => pick.iter().map(|&(n, _)| n).collect::<Vec<&str>>().join(",")
+    };
+    if let Some(&(_, Some(ref room))) = pick.iter().find(|&&(n, _)| n == FURNISH) {
+        args.insert(String::from("room"), room.clone());
+    }
+    Ok(Some(Actions { args, actions }))
+}
+
+// Weapon of the gnome after the expedition
+pub fn get_upgraded_weapon(weapon: u32, pick: &LootPick) -> u32 {
+    let upgrades = if pick.iter().any(|&(n, _)| n == UPGRADE_WEAPONS) { 2 } else { 1 };
+    (weapon + upgrades).min(MAX_WEAPON_STRENGTH)
+}
+
+fn _collect_loot(game: &Game, player_name: &str, items: &Vec<&'static LootItem>, start: usize, picks_left: u32,
+                 pick: &mut LootPick, picks: &mut Vec<LootPick>) -> Result<(), GameError> {
+    if picks_left == 0 {
+        return Ok(());
+    }
+    let player = game.find_player(player_name)?;
+    for index in start..items.len() {
+        let name = items[index].name;
+        let variants: Vec<Option<String>> = match name {
+            FURNISH => _get_furnish_rooms(game, player)?.into_iter().map(Some).collect(),
+            _ => vec![None],
+        };
+        for room in variants.into_iter() {
+            let loot = match _build_loot(game, player, name, &room) {
+                Some(loot) => loot,
+                None => continue,
+            };
+            let mut state = game.clone();
+            if Actions::from_vec(loot).try_perform_part(&mut state).is_err() {
+                continue;
+            }
+
+            pick.push((name, room));
+            picks.push(pick.clone());
+            _collect_loot(&state, player_name, items, index + 1, picks_left - 1, pick, picks)?;
+            pick.pop();
+        }
+    }
+    Ok(())
+}
+
+fn _get_furnish_rooms(game: &Game, player: &Player) -> Result<Vec<String>, GameError> {
+    if player.get_furnishable_slots().is_empty() {
+        return Ok(vec![]);
+    }
+    Ok(game.get_buildable_rooms(&player.name)?
+        .into_iter()
+        .map(|r| String::from(r.get_name()))
+        .collect())
+}
+
+fn _build_loot(game: &Game, player: &Player, name: &str, room: &Option<String>) -> Option<Vec<Box<MoveAction>>> {
+    let player_name = player.name.clone();
+
+    let actions: Vec<Box<MoveAction>> = match name {
+        UPGRADE_WEAPONS => {
+            if player.warriors.is_empty() {
+                return None;
+            }
+            vec![Box::new(UpgradeAllWeapons { player: player_name })]
+        },
+        FURNISH => {
+            let room_type = room.clone()?;
+            let position = *player.get_furnishable_slots().iter().min()?;
+            // Room the player can't afford is dropped when its payment fails
+            let price = player.get_room_price(game.get_available_rooms()
+                .into_iter()
+                .find(|r| r.get_name() == room_type)?);
+            vec![
+                Box::new(PayResources { player: player_name.clone(), price }),
+                Box::new(BuildRooms {
+                    player: player_name,
+                    rooms: vec![PlayerRoom { room_type, position }],
+                }),
+            ]
+        },
+        STABLE => {
+            let position = *player.get_stable_slots().iter().min()?;
+            vec![Box::new(BuildStables { player: player_name, stables: vec![position] })]
+        },
+        TUNNEL | CAVERN => {
+            let position = *player.get_excavation_slots().iter().min()?;
+            let cavern_type = if name == TUNNEL { InsideElement::Hall } else { InsideElement::Room };
+            vec![Box::new(Excavate {
+                player: player_name,
+                caverns: vec![PlayerCavern { cavern_type, position }],
+            })]
+        },
+        MEADOW | FIELD => {
+            let position = *player.get_clearing_slots().iter().min()?;
+            let field_type = if name == MEADOW { OutsideElement::Meadow } else { OutsideElement::Field };
+            vec![Box::new(BuildFields {
+                player: player_name,
+                fields: vec![PlayerField { field_type, position }],
+            })]
+        },
+        SMALL_PASTURE => {
+            let position = *player.get_small_pasture_slots().iter().min()?;
+            vec![Box::new(BuildPastures { player: player_name, pastures: vec![position] })]
+        },
+        LARGE_PASTURE => {
+            let (first, second) = *player.get_large_pasture_pair_slots().iter().min()?;
+            vec![Box::new(BuildPastures { player: player_name, pastures: vec![first, second] })]
+        },
+        _ => {
+            let item = LOOT_TABLE.iter().find(|i| i.name == name)?;
+            let mut update_hash: HashMap<String, i32> = HashMap::new();
+            for &(ref resource, count) in item.resources.iter() {
+                update_hash.insert(resource.str_key(), count as i32);
+            }
+            vec![Box::new(UpdateResources { player: player_name, update_hash })]
+        },
+    };
+    Some(actions)
+}
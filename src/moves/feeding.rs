@@ -1,20 +1,301 @@
 use std::collections::HashMap;
 
-use actions::{MoveAction, Actions};
-use constants::{FeedingAndBreedingStatus};
-use models::game::{Player};
+use actions::{constants as ActionsConstants, MoveAction, Actions, ConvertResources, Feed, BreedAnimals, HarvestFields};
+use constants::{FeedingAndBreedingStatus, FieldPhase, ResourceType, TRIBAL_ANIMALS};
+use errors::{GameError};
+use models::game::{Game, Player};
+use moves::core::{ActionsFromMove};
 
 
-pub fn get_feeding_and_breeding_actions(_player: &Player, _feeding_and_breeding_status: FeedingAndBreedingStatus) -> Vec<Actions> {
-    let actions: Vec<Box<MoveAction>> = Vec::new();
+pub const GNOME_FOOD: u32 = 2;
+pub const REDUCED_GNOME_FOOD: u32 = 1;
+pub const CHILD_GNOME_FOOD: u32 = 1;
 
+// Args of a feeding option, conversions are written the same way as the ones given before a move
+pub const CONVERT_ARG: &str = "convert";
+pub const BREED_ARG: &str = "breed";
+pub const HARVEST_FIELDS_ARG: &str = "harvest_fields";
+
+// Food per converted resource, gold is converted last
+pub static FOOD_CONVERSIONS: &'static [(ResourceType, u32)] = &[
+    (ResourceType::Wheat, 1),
+    (ResourceType::Pumpkin, 2),
+    (ResourceType::Sheep, 1),
+    (ResourceType::Donkey, 1),
+    (ResourceType::Hippo, 2),
+    (ResourceType::Cow, 3),
+    (ResourceType::Gem, 2),
+    (ResourceType::Gold, 1),
+];
+
+// A pair of donkeys gives 3 food and n gold gives n - 1 food
+pub fn get_converted_food(resource: &ResourceType, count: u32) -> u32 {
+    let rate = match FOOD_CONVERSIONS.iter().find(|&&(ref r, _)| r == resource) {
+        Some(&(_, rate)) => rate,
+        None => return 0,
+    };
+    match *resource {
+        ResourceType::Donkey => count * rate + count / 2,
+        ResourceType::Gold => count.saturating_sub(1) * rate,
+        _ => count * rate,
+    }
+}
+
+pub fn get_conversions_food(conversions: &HashMap<String, u32>) -> Result<u32, GameError> {
+    let mut food = 0;
+    for (key, count) in conversions.iter() {
+        match FOOD_CONVERSIONS.iter().find(|&&(ref r, _)| r.str_key() == *key) {
+            Some(&(ref resource, _)) => food += get_converted_food(resource, *count),
+            None => return Err(GameError::InvalidArgument(format!("{} can't be converted into food", key))),
+        }
+    }
+    Ok(food)
+}
+
+// Parses "resource:count" pairs separated by commas
+pub fn parse_conversions(value: &str) -> Result<HashMap<String, u32>, GameError> {
+    let mut conversions: HashMap<String, u32> = HashMap::new();
+    for item in value.split(",").filter(|i| !i.is_empty()) {
+        let parts: Vec<&str> = item.split(":").collect();
+        if parts.len() != 2 {
+            return Err(GameError::InvalidArgument(format!("Wrong conversion {}", item)));
+        }
+        let count = parts[1]
+            .parse::<u32>()
+            .map_err(|_| GameError::InvalidArgument(format!("Wrong count in conversion {}", item)))?;
+        *conversions.entry(String::from(parts[0])).or_insert(0) += count;
+    }
+    get_conversions_food(&conversions)?;
+    Ok(conversions)
+}
+
+pub fn format_conversions(conversions: &HashMap<String, u32>) -> String {
+    let mut items: Vec<String> = conversions.iter().map(|(key, count)| format!("{}:{}", key, count)).collect();
+    items.sort();
+    items.join(",")
+}
+
+pub fn get_conversion_actions(player: &Player, conversions: &HashMap<String, u32>) -> Result<Actions, GameError> {
+    let food = get_conversions_food(conversions)?;
+    if !player.can_afford(conversions) {
+        return Err(GameError::NotEnoughResources(
+            format!("Player {} doesn't have {:?} to convert", player.name, conversions)
+        ));
+    }
+    Ok(Actions::from_vec(vec![Box::new(ConvertResources {
+        player: player.name.clone(),
+        conversions: conversions.clone(),
+        food,
+    })]))
+}
+
+#[derive(Debug, Clone)]
+pub struct FeedingPlan {
+    pub conversions: HashMap<String, u32>,
+    pub converted_food: u32,
+    pub food: u32,
+    pub fines: u32,
+}
+
+pub fn get_feeding_and_breeding_actions(player: &Player, feeding_and_breeding_status: FeedingAndBreedingStatus) -> Vec<Actions> {
+    if feeding_and_breeding_status.get_field_phase() == FieldPhase::InsteadOfBreeding {
+        return _get_field_phase_or_breeding_actions(player);
+    }
+
+    let feeding_plans: Vec<Option<FeedingPlan>> = match feeding_and_breeding_status {
+        FeedingAndBreedingStatus::NoFeeding => vec![None],
+        FeedingAndBreedingStatus::FeedByOne => get_feeding_plans(player, player.get_all_gnomes_count() * REDUCED_GNOME_FOOD)
+            .into_iter()
+            .map(Some)
+            .collect(),
+        _ => get_feeding_plans(player, player.gnomes * GNOME_FOOD + player.child_gnomes * CHILD_GNOME_FOOD)
+            .into_iter()
+            .map(Some)
+            .collect(),
+    };
+
+    let breeding_options: Vec<Vec<ResourceType>> = match feeding_and_breeding_status {
+        FeedingAndBreedingStatus::NoBreeding | FeedingAndBreedingStatus::FeedByOne => vec![vec![]],
+        _ => get_breeding_options(player),
+    };
+
+    let mut result: Vec<Actions> = Vec::new();
+    for plan in feeding_plans.iter() {
+        for animals in breeding_options.iter() {
+            result.push(_build_actions(player, plan, animals));
+        }
+    }
+    result
+}
+
+// Gnomes are always fed, the player either harvests the fields before feeding or breeds animals after it
+fn _get_field_phase_or_breeding_actions(player: &Player) -> Vec<Actions> {
+    let food_needed = player.gnomes * GNOME_FOOD + player.child_gnomes * CHILD_GNOME_FOOD;
     let mut result: Vec<Actions> = Vec::new();
-    result.push(Actions {
-        args: hash_map! {
-                String::from("hall_slot") => String::new(),
-                String::from("room_slot") => String::new()
-            },
-        actions,
-    });
+
+    if !player.sown_fields.is_empty() {
+        let mut harvested = player.clone();
+        harvested.harvest_fields();
+        for plan in get_feeding_plans(&harvested, food_needed).into_iter() {
+            let mut actions = _build_actions(player, &Some(plan), &vec![]);
+            actions.actions.insert(0, Box::new(HarvestFields {
+                player: player.name.clone(),
+            }));
+            actions.args.insert(String::from(HARVEST_FIELDS_ARG), true.to_string());
+            result.push(actions);
+        }
+    }
+
+    let breeding_options = get_breeding_options(player);
+    for plan in get_feeding_plans(player, food_needed).into_iter() {
+        let plan = Some(plan);
+        for animals in breeding_options.iter() {
+            result.push(_build_actions(player, &plan, animals));
+        }
+    }
     result
-}
\ No newline at end of file
+}
+
+// Feeding options of the next player, listed among the moves under the feed command
+pub fn collect_feeding_actions(game: &Game) -> Result<Vec<ActionsFromMove>, GameError> {
+    Ok(get_feeding_and_breeding_actions(game.find_player(&game.next)?, game.feeding_and_breeding_status.clone())
+        .into_iter()
+        .map(|a| ActionsFromMove {
+            move_name: String::from(ActionsConstants::FEED),
+            actions: a,
+        })
+        .collect())
+}
+
+// Conversions and animals can be given in any order, they are compared with the options args sorted
+pub fn get_feeding_args(args: &HashMap<String, String>) -> Result<HashMap<String, String>, GameError> {
+    let mut result: HashMap<String, String> = HashMap::new();
+    if let Some(value) = args.get(CONVERT_ARG) {
+        let conversions = parse_conversions(value)?;
+        if !conversions.is_empty() {
+            result.insert(String::from(CONVERT_ARG), format_conversions(&conversions));
+        }
+    }
+    if let Some(value) = args.get(BREED_ARG) {
+        let mut animals: Vec<&str> = value.split(",").filter(|a| !a.is_empty()).collect();
+        animals.sort();
+        if !animals.is_empty() {
+            result.insert(String::from(BREED_ARG), animals.join(","));
+        }
+    }
+    if let Some(value) = args.get(HARVEST_FIELDS_ARG) {
+        result.insert(String::from(HARVEST_FIELDS_ARG), value.clone());
+    }
+    Ok(result)
+}
+
+pub fn get_feeding_plans(player: &Player, food_needed: u32) -> Vec<FeedingPlan> {
+    let food = player.get_resource(ResourceType::Food);
+    if food >= food_needed {
+        return vec![FeedingPlan {
+            conversions: HashMap::new(),
+            converted_food: 0,
+            food: food_needed,
+            fines: 0,
+        }];
+    }
+
+    let mut plans: Vec<FeedingPlan> = Vec::new();
+    _collect_feeding_plans(player, food_needed, food_needed - food, 0, HashMap::new(), 0, &mut plans);
+    plans
+}
+
+fn _collect_feeding_plans(player: &Player, food_needed: u32, deficit: u32, index: usize,
+                          conversions: HashMap<String, u32>, converted_food: u32, plans: &mut Vec<FeedingPlan>) {
+    let missing = deficit.saturating_sub(converted_food);
+
+    if index == FOOD_CONVERSIONS.len() {
+        let fines = missing;
+        plans.push(FeedingPlan {
+            conversions,
+            converted_food,
+            food: food_needed - fines,
+            fines,
+        });
+        return;
+    }
+
+    let (ref resource, _) = FOOD_CONVERSIONS[index];
+    let available = player.get_resource(resource.clone());
+
+    for count in 0..available + 1 {
+        let food = get_converted_food(resource, count);
+        // Converting more without getting more food is never useful
+        if count > 0 && food == get_converted_food(resource, count - 1) {
+            continue;
+        }
+        let mut next_conversions = conversions.clone();
+        if count > 0 {
+            next_conversions.insert(resource.str_key(), count);
+        }
+        _collect_feeding_plans(
+            player, food_needed, deficit, index + 1, next_conversions, converted_food + food, plans
+        );
+        if food >= missing {
+            break;
+        }
+    }
+}
+
+pub fn get_breeding_options(player: &Player) -> Vec<Vec<ResourceType>> {
+    let breedable: Vec<ResourceType> = TRIBAL_ANIMALS
+        .iter()
+        .filter(|a| {
+            let count = player.get_resource((*a).clone());
+            count >= 2 && count < player.get_resource_max_slots(a)
+        })
+        .cloned()
+        .collect();
+
+    let mut result: Vec<Vec<ResourceType>> = vec![vec![]];
+    for animal in breedable.into_iter() {
+        let with_animal: Vec<Vec<ResourceType>> = result
+            .iter()
+            .map(|o| {
+                let mut option = o.clone();
+                option.push(animal.clone());
+                option
+            })
+            .collect();
+        result.extend(with_animal);
+    }
+    result
+}
+
+fn _build_actions(player: &Player, plan: &Option<FeedingPlan>, animals: &Vec<ResourceType>) -> Actions {
+    let mut actions: Vec<Box<MoveAction>> = Vec::new();
+    let mut args: HashMap<String, String> = HashMap::new();
+
+    if let Some(ref p) = *plan {
+        if !p.conversions.is_empty() {
+            args.insert(String::from(CONVERT_ARG), format_conversions(&p.conversions));
+            actions.push(Box::new(ConvertResources {
+                player: player.name.clone(),
+                conversions: p.conversions.clone(),
+                food: p.converted_food,
+            }));
+        }
+        actions.push(Box::new(Feed {
+            player: player.name.clone(),
+            food: p.food,
+            fines: p.fines,
+        }));
+    }
+
+    if !animals.is_empty() {
+        let mut names: Vec<String> = animals.iter().map(|a| a.str_key()).collect();
+        names.sort();
+        args.insert(String::from(BREED_ARG), names.join(","));
+        actions.push(Box::new(BreedAnimals {
+            player: player.name.clone(),
+            animals: animals.clone(),
+        }));
+    }
+
+    Actions { args, actions }
+}
@@ -1,4 +1,6 @@
 pub mod config;
 pub mod constants;
 pub mod core;
+pub mod expedition;
 pub mod feeding;
+pub mod ruby;
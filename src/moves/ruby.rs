@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+
+use actions::{MoveAction, Actions, UpdateResources, PayResources, Excavate, BuildFields};
+use constants::{ResourceType, InsideElement, OutsideElement, TRIBAL_ANIMALS};
+use errors::{GameError};
+use models::game::{Game, PlayerCavern, PlayerField};
+use moves::config::{MovesConfig};
+use moves::core::{Move, ActionsFromMove, collect_actions, _same_args};
+use moves::expedition::{TUNNEL, MEADOW, FIELD, CAVERN};
+
+
+pub const RUBY_TRADE: &str = "ruby_trade";
+
+// Ruby paid to place a gnome on an action space taken by another player
+pub const OUT_OF_ORDER_RUBIES: u32 = 1;
+
+// Every resource costs one ruby, cattle also costs a food
+pub static RESOURCE_PURCHASES: &'static [(ResourceType, u32)] = &[
+    (ResourceType::Wood, 0),
+    (ResourceType::Stone, 0),
+    (ResourceType::Coal, 0),
+    (ResourceType::Wheat, 0),
+    (ResourceType::Pumpkin, 0),
+    (ResourceType::Sheep, 0),
+    (ResourceType::Donkey, 0),
+    (ResourceType::Hippo, 0),
+    (ResourceType::Cow, 1),
+];
+
+// Tile and its price in rubies
+pub static TILE_PURCHASES: &'static [(&str, u32)] = &[
+    (MEADOW, 1),
+    (FIELD, 1),
+    (TUNNEL, 1),
+    (CAVERN, 2),
+];
+
+pub fn get_ruby_trade_actions(game: &Game, player_name: &str) -> Result<Vec<Actions>, GameError> {
+    let player = game.find_player(player_name)?;
+    let rubies = player.get_resource(ResourceType::Gem);
+    let food = player.get_resource(ResourceType::Food);
+
+    let mut result: Vec<Actions> = Vec::new();
+    for &(ref resource, food_price) in RESOURCE_PURCHASES.iter() {
+        if rubies == 0 || food < food_price {
+            continue;
+        }
+        // Animals without a place to keep them would run away at once
+        if TRIBAL_ANIMALS.contains(resource)
+            && player.get_resource(resource.clone()) >= player.get_resource_max_slots(resource) {
+            continue;
+        }
+        let mut price = hash_map! { ResourceType::Gem.str_key() => 1 };
+        if food_price > 0 {
+            price.insert(ResourceType::Food.str_key(), food_price);
+        }
+        let actions: Vec<Box<MoveAction>> = vec![
+            Box::new(PayResources { player: String::from(player_name), price }),
+            Box::new(UpdateResources {
+                player: String::from(player_name),
+                update_hash: hash_map! { resource.str_key() => 1 },
+            }),
+        ];
+        result.push(Actions {
+            args: hash_map! { String::from("buy") => resource.str_key() },
+            actions,
+        });
+    }
+
+    for &(tile, tile_price) in TILE_PURCHASES.iter() {
+        if rubies < tile_price {
+            continue;
+        }
+        let mut slots: Vec<u32> = match tile {
+            MEADOW | FIELD => player.get_clearing_slots().into_iter().collect(),
+            _ => player.get_excavation_slots().into_iter().collect(),
+        };
+        slots.sort();
+        for slot in slots.into_iter() {
+            let actions: Vec<Box<MoveAction>> = vec![
+                Box::new(PayResources {
+                    player: String::from(player_name),
+                    price: hash_map! { ResourceType::Gem.str_key() => tile_price },
+                }),
+                _build_tile(player_name, tile, slot),
+            ];
+            result.push(Actions {
+                args: hash_map! {
+                    String::from("buy") => String::from(tile),
+                    String::from("slot") => slot.to_string()
+                },
+                actions,
+            });
+        }
+    }
+    Ok(result)
+}
+
+pub fn get_ruby_trade(game: &Game, player_name: &str, args: &HashMap<String, String>) -> Result<Actions, GameError> {
+    get_ruby_trade_actions(game, player_name)?
+        .into_iter()
+        .find(|a| _same_args(&a.args, args))
+        .ok_or_else(|| GameError::MoveNotPossible(format!("Ruby trade is not possible with {:?}", args)))
+}
+
+// Action spaces the next player can still use by paying a ruby
+pub fn get_out_of_order_moves(game: &Game) -> Result<Vec<&Move>, GameError> {
+    let player = game.find_player(&game.next)?;
+    if player.get_resource(ResourceType::Gem) < OUT_OF_ORDER_RUBIES {
+        return Ok(vec![]);
+    }
+    let free_moves: Vec<&str> = game.get_free_moves()?.iter().map(|m| m.get_name()).collect();
+    Ok(game.get_all_moves()?
+        .into_iter()
+        .filter(|m| !free_moves.contains(&m.get_name()) && !player.moves.contains(&String::from(m.get_name())))
+        .collect())
+}
+
+pub fn get_out_of_order_payment(player_name: &str) -> Box<MoveAction> {
+    Box::new(PayResources {
+        player: String::from(player_name),
+        price: hash_map! { ResourceType::Gem.str_key() => OUT_OF_ORDER_RUBIES },
+    })
+}
+
+pub fn collect_out_of_order_actions(game: &Game, moves_config: &MovesConfig) -> Result<Vec<ActionsFromMove>, GameError> {
+    Ok(collect_actions(game, moves_config, get_out_of_order_moves(game)?)?
+        .into_iter()
+        .map(|mut a| {
+            a.actions.actions.insert(0, get_out_of_order_payment(&game.next));
+            a
+        })
+        .filter(|a| a.actions.is_affordable(game))
+        .collect())
+}
+
+// Trades don't take a gnome, they are listed among the moves under their own name
+pub fn collect_ruby_trade_actions(game: &Game) -> Result<Vec<ActionsFromMove>, GameError> {
+    Ok(get_ruby_trade_actions(game, &game.next)?
+        .into_iter()
+        .map(|a| ActionsFromMove {
+            move_name: String::from(RUBY_TRADE),
+            actions: a,
+        })
+        .collect())
+}
+
+fn _build_tile(player_name: &str, tile: &str, slot: u32) -> Box<MoveAction> {
+    match tile {
+        MEADOW | FIELD => Box::new(BuildFields {
+            player: String::from(player_name),
+            fields: vec![PlayerField {
+                field_type: if tile == MEADOW { OutsideElement::Meadow } else { OutsideElement::Field },
+                position: slot,
+            }],
+        }),
+        _ => Box::new(Excavate {
+            player: String::from(player_name),
+            caverns: vec![PlayerCavern {
+                cavern_type: if tile == TUNNEL { InsideElement::Hall } else { InsideElement::Room },
+                position: slot,
+            }],
+        }),
+    }
+}
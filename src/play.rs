@@ -0,0 +1,51 @@
+use rustyline::{Context, Helper, Result as ReadlineResult};
+use rustyline::completion::{Completer};
+use rustyline::highlight::{Highlighter};
+use rustyline::hint::{Hinter};
+use rustyline::validate::{Validator};
+
+pub const PLAY: &str = "play";
+pub const HINT: &str = "hint";
+pub const QUIT: &str = "quit";
+
+// Completes the command name, the commands are rebuilt after every move since free moves change
+pub struct CommandCompleter {
+    pub commands: Vec<String>,
+}
+
+impl CommandCompleter {
+    // Only the first word is a command, its args are left to the user
+    pub fn get_candidates(&self, line: &str, pos: usize) -> Vec<String> {
+        let typed = &line[..pos];
+        if typed.trim_start().contains(' ') {
+            return vec![];
+        }
+        let prefix = typed.trim_start();
+        let mut candidates: Vec<String> = self.commands
+            .iter()
+            .filter(|c| c.starts_with(prefix))
+            .cloned()
+            .collect();
+        candidates.sort();
+        candidates
+    }
+}
+
+impl Completer for CommandCompleter {
+    type Candidate = String;
+
+    fn complete(&self, line: &str, pos: usize, _ctx: &Context) -> ReadlineResult<(usize, Vec<String>)> {
+        let start = line.len() - line.trim_start().len();
+        Ok((start, self.get_candidates(line, pos)))
+    }
+}
+
+impl Hinter for CommandCompleter {
+    type Hint = String;
+}
+
+impl Highlighter for CommandCompleter {}
+
+impl Validator for CommandCompleter {}
+
+impl Helper for CommandCompleter {}
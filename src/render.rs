@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+use serde_yaml;
+
+use models::game::{Game, Player};
+
+const EMPTY_SLOT: &str = ".";
+
+pub fn render_game(game: &Game) -> String {
+    let mut lines: Vec<String> = vec![
+        format!("Turn {}, status {:?}, harvest {:?}", game.turn, game.status, game.feeding_and_breeding_status),
+        format!("Next player {}, first player {}, order {}", game.next, game.first_move, game.order.join(", ")),
+        String::new(),
+        String::from("Action board:"),
+    ];
+    lines.extend(render_board(game));
+    for player in game.players.iter() {
+        lines.push(String::new());
+        lines.extend(render_player(player));
+    }
+    lines.join("\n")
+}
+
+// Every available move with the goods accumulated on it and the players who occupy it
+pub fn render_board(game: &Game) -> Vec<String> {
+    let accumulated = _get_accumulated_goods(game);
+    let width = game.available_moves.iter().map(|m| m.len()).max().unwrap_or(0);
+    game.available_moves
+        .iter()
+        .map(|m| {
+            let goods = accumulated.get(m).cloned().unwrap_or_default();
+            let occupants: Vec<&str> = game.players
+                .iter()
+                .filter(|p| p.moves.contains(m))
+                .map(|p| p.name.as_str())
+                .collect();
+            let mut line = format!("  {:width$}", m, width = width);
+            if !goods.is_empty() {
+                line += &format!("  {}", goods.join(", "));
+            }
+            if !occupants.is_empty() {
+                line += &format!("  [{}]", occupants.join(", "));
+            }
+            String::from(line.trim_end())
+        })
+        .collect()
+}
+
+pub fn render_player(player: &Player) -> Vec<String> {
+    let mut resources: Vec<(&String, &u32)> = player.resources.iter().filter(|&(_, count)| *count > 0).collect();
+    resources.sort();
+
+    let mut lines: Vec<String> = vec![
+        format!("{}:", player.name),
+        format!(
+            "  Gnomes {} free of {}, children {}, fines {}",
+            player.get_free_gnomes(), player.gnomes, player.child_gnomes, player.fines
+        ),
+        format!(
+            "  Resources: {}",
+            resources.iter().map(|&(name, count)| format!("{}: {}", name, count)).collect::<Vec<String>>().join(", ")
+        ),
+    ];
+    if !player.warriors.is_empty() {
+        lines.push(format!("  Weapons: {:?}", player.warriors));
+    }
+    if !player.moves.is_empty() {
+        lines.push(format!("  Moves: {}", player.moves.join(", ")));
+    }
+    lines.push(String::from("  Forest:"));
+    lines.extend(render_grid(&_get_forest_slots(player)));
+    lines.push(String::from("  Cave:"));
+    lines.extend(render_grid(&_get_cave_slots(player)));
+    lines
+}
+
+// Slots are numbered the same way as in utils::get_available_slots, 3 columns and 4 rows
+pub fn render_grid(slots: &HashMap<u32, String>) -> Vec<String> {
+    let width = slots.values().map(|s| s.len()).max().unwrap_or(0).max(EMPTY_SLOT.len());
+    (0..4)
+        .map(|j| {
+            let row: Vec<String> = (0..3)
+                .map(|i| {
+                    let num = i + 3 * j;
+                    let content = slots.get(&num).map(|s| s.as_str()).unwrap_or(EMPTY_SLOT);
+                    format!("{:>2} {:width$}", num, content, width = width)
+                })
+                .collect();
+            format!("   {}", row.join(" | ").trim_end())
+        })
+        .collect()
+}
+
+fn _get_forest_slots(player: &Player) -> HashMap<u32, String> {
+    let mut slots: HashMap<u32, Vec<String>> = HashMap::new();
+    for field in player.fields.iter() {
+        slots.entry(field.position).or_insert_with(Vec::new).push(field.field_type.str_key());
+    }
+    for sown in player.sown_fields.iter() {
+        slots.entry(sown.position).or_insert_with(Vec::new).push(format!("{}:{}", sown.crop.str_key(), sown.count));
+    }
+    for stable in player.stables.iter() {
+        slots.entry(*stable).or_insert_with(Vec::new).push(String::from("stable"));
+    }
+    let mut dogs: HashMap<u32, u32> = HashMap::new();
+    for dog in player.dogs.iter() {
+        *dogs.entry(*dog).or_insert(0) += 1;
+    }
+    for (slot, count) in dogs.into_iter() {
+        slots.entry(slot).or_insert_with(Vec::new).push(format!("dogs:{}", count));
+    }
+    slots.into_iter().map(|(slot, parts)| (slot, parts.join(" "))).collect()
+}
+
+// Furnished rooms are shown instead of the caverns they are built in
+fn _get_cave_slots(player: &Player) -> HashMap<u32, String> {
+    let mut slots: HashMap<u32, String> = player.caverns
+        .iter()
+        .map(|c| (c.position, c.cavern_type.str_key()))
+        .collect();
+    for room in player.rooms.iter() {
+        slots.insert(room.position, room.room_type.clone());
+    }
+    slots
+}
+
+// Accumulated goods are stored per move under the move name, only the goods lying on the board are listed
+fn _get_accumulated_goods(game: &Game) -> HashMap<String, Vec<String>> {
+    let mut result: HashMap<String, Vec<String>> = HashMap::new();
+    let moves = match serde_yaml::to_value(&game.moves) {
+        Ok(serde_yaml::Value::Mapping(moves)) => moves,
+        _ => return result,
+    };
+    for (name, data) in moves.into_iter() {
+        let (name, data) = match (name, data) {
+            (serde_yaml::Value::String(name), serde_yaml::Value::Mapping(data)) => (name, data),
+            _ => continue,
+        };
+        let mut goods: Vec<String> = data
+            .into_iter()
+            .filter_map(|(resource, count)| match (resource.as_str(), count.as_u64()) {
+                (Some(resource), Some(count)) if count > 0 => Some(format!("{}: {}", resource, count)),
+                _ => None,
+            })
+            .collect();
+        goods.sort();
+        result.insert(name, goods);
+    }
+    result
+}
@@ -6,6 +6,50 @@ pub const MIXED_DWELLING: &str = "mixed_dwelling";
 pub const COUPLE_DWELLING: &str = "couple_dwelling";
 pub const ADDITIONAL_DWELLING: &str = "additional_dwelling";
 
+pub const CARPENTER: &str = "carpenter";
+pub const STONE_CARVER: &str = "stone_carver";
+pub const BLACKSMITH: &str = "blacksmith";
+pub const MINER: &str = "miner";
+pub const BUILDER: &str = "builder";
+pub const TRADER: &str = "trader";
+pub const WOOD_SUPPLIER: &str = "wood_supplier";
+pub const STONE_SUPPLIER: &str = "stone_supplier";
+pub const RUBY_SUPPLIER: &str = "ruby_supplier";
+pub const DOG_SCHOOL: &str = "dog_school";
+pub const QUARRY: &str = "quarry";
+pub const SEAM: &str = "seam";
+pub const SLAUGHTERING_CAVE: &str = "slaughtering_cave";
+pub const COOKING_CAVE: &str = "cooking_cave";
+pub const WORKING_CAVE: &str = "working_cave";
+pub const MINING_CAVE: &str = "mining_cave";
+pub const BREEDING_CAVE: &str = "breeding_cave";
+pub const PEACEFUL_CAVE: &str = "peaceful_cave";
+pub const HUNTING_PARLOR: &str = "hunting_parlor";
+pub const BEER_PARLOR: &str = "beer_parlor";
+pub const BLACKSMITHING_PARLOR: &str = "blacksmithing_parlor";
+pub const SPARE_PART_STORAGE: &str = "spare_part_storage";
+pub const OFFICE_ROOM: &str = "office_room";
+pub const GUEST_ROOM: &str = "guest_room";
+pub const CUDDLE_ROOM: &str = "cuddle_room";
+pub const BREAKFAST_ROOM: &str = "breakfast_room";
+pub const STUBBLE_ROOM: &str = "stubble_room";
+pub const WORK_ROOM: &str = "work_room";
+
+pub const WEAVING_PARLOR: &str = "weaving_parlor";
+pub const MILKING_PARLOR: &str = "milking_parlor";
+pub const STATE_PARLOR: &str = "state_parlor";
+pub const STONE_STORAGE: &str = "stone_storage";
+pub const ORE_STORAGE: &str = "ore_storage";
+pub const MAIN_STORAGE: &str = "main_storage";
+pub const WEAPON_STORAGE: &str = "weapon_storage";
+pub const SUPPLIES_STORAGE: &str = "supplies_storage";
+pub const BROOM_CHAMBER: &str = "broom_chamber";
+pub const TREASURE_CHAMBER: &str = "treasure_chamber";
+pub const FOOD_CHAMBER: &str = "food_chamber";
+pub const PRAYER_CHAMBER: &str = "prayer_chamber";
+pub const WRITING_CHAMBER: &str = "writing_chamber";
+pub const FODDER_CHAMBER: &str = "fodder_chamber";
+
 pub const ALL_ROOMS: &'static [&str] = &[
     ENTRY_LEVEL_DWELLING,
     DWELLING,
@@ -14,12 +58,96 @@ pub const ALL_ROOMS: &'static [&str] = &[
     MIXED_DWELLING,
     COUPLE_DWELLING,
     ADDITIONAL_DWELLING,
+    CARPENTER,
+    STONE_CARVER,
+    BLACKSMITH,
+    MINER,
+    BUILDER,
+    TRADER,
+    WOOD_SUPPLIER,
+    STONE_SUPPLIER,
+    RUBY_SUPPLIER,
+    DOG_SCHOOL,
+    QUARRY,
+    SEAM,
+    SLAUGHTERING_CAVE,
+    COOKING_CAVE,
+    WORKING_CAVE,
+    MINING_CAVE,
+    BREEDING_CAVE,
+    PEACEFUL_CAVE,
+    HUNTING_PARLOR,
+    BEER_PARLOR,
+    BLACKSMITHING_PARLOR,
+    SPARE_PART_STORAGE,
+    OFFICE_ROOM,
+    GUEST_ROOM,
+    CUDDLE_ROOM,
+    BREAKFAST_ROOM,
+    STUBBLE_ROOM,
+    WORK_ROOM,
+    WEAVING_PARLOR,
+    MILKING_PARLOR,
+    STATE_PARLOR,
+    STONE_STORAGE,
+    ORE_STORAGE,
+    MAIN_STORAGE,
+    WEAPON_STORAGE,
+    SUPPLIES_STORAGE,
+    BROOM_CHAMBER,
+    TREASURE_CHAMBER,
+    FOOD_CHAMBER,
+    PRAYER_CHAMBER,
+    WRITING_CHAMBER,
+    FODDER_CHAMBER,
 ];
 
 pub const GREEN_ROOMS: &'static [&str] = &[
+    CARPENTER,
+    STONE_CARVER,
+    BLACKSMITH,
+    MINER,
+    BUILDER,
+    TRADER,
+    WOOD_SUPPLIER,
+    STONE_SUPPLIER,
+    RUBY_SUPPLIER,
+    DOG_SCHOOL,
+    QUARRY,
+    SEAM,
+    SLAUGHTERING_CAVE,
+    COOKING_CAVE,
+    WORKING_CAVE,
+    MINING_CAVE,
+    BREEDING_CAVE,
+    PEACEFUL_CAVE,
+    HUNTING_PARLOR,
+    BEER_PARLOR,
+    BLACKSMITHING_PARLOR,
+    SPARE_PART_STORAGE,
+    OFFICE_ROOM,
+    GUEST_ROOM,
+    CUDDLE_ROOM,
+    BREAKFAST_ROOM,
+    STUBBLE_ROOM,
+    WORK_ROOM,
 ];
 
 pub const YELLOW_ROOMS: &'static [&str] = &[
+    WEAVING_PARLOR,
+    MILKING_PARLOR,
+    STATE_PARLOR,
+    STONE_STORAGE,
+    ORE_STORAGE,
+    MAIN_STORAGE,
+    WEAPON_STORAGE,
+    SUPPLIES_STORAGE,
+    BROOM_CHAMBER,
+    TREASURE_CHAMBER,
+    FOOD_CHAMBER,
+    PRAYER_CHAMBER,
+    WRITING_CHAMBER,
+    FODDER_CHAMBER,
 ];
 
 pub const GINGER_ROOMS: &'static [&str] = &[
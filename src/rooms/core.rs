@@ -1,7 +1,14 @@
 use std::collections::HashMap;
 
 use constants;
+use errors::{GameError};
+use models::game::{Player};
 use rooms::{constants as RoomConstants};
+use score::calculator::{get_unused_slots};
+use utils::{get_neighbour_slots};
+
+static SHEEP: &'static [constants::ResourceType] = &[constants::ResourceType::Sheep];
+static COWS: &'static [constants::ResourceType] = &[constants::ResourceType::Cow];
 
 
 pub struct ResourceSlots {
@@ -48,22 +55,77 @@ pub trait Room {
         }
     }
 
+    fn get_player_slots(&self, _player: &Player) -> ResourceSlots {
+        self.get_slots()
+    }
+
+    fn get_bonus_points(&self, _player: &Player) -> u32 {
+        0
+    }
+
+    fn get_price_discount(&self) -> HashMap<String, u32> {
+        HashMap::new()
+    }
+
     fn get_price(&self) -> HashMap<String, u32>;
 }
 
-pub fn get_from_string(string: &str) -> Result<&Room, String> {
-    let mut dwellings: HashMap<&str, &Room> = HashMap::new();
+pub fn get_from_string(string: &str) -> Result<&Room, GameError> {
+    let rooms_list: Vec<&Room> = vec![
+        &EntryLevelDwelling {},
+        &Dwelling {},
+        &SimpleDwelling1 {},
+        &SimpleDwelling2 {},
+        &MixedDwelling {},
+        &CoupleDwelling {},
+        &AdditionalDwelling {},
+        &Carpenter {},
+        &StoneCarver {},
+        &Blacksmith {},
+        &Miner {},
+        &Builder {},
+        &Trader {},
+        &WoodSupplier {},
+        &StoneSupplier {},
+        &RubySupplier {},
+        &DogSchool {},
+        &Quarry {},
+        &Seam {},
+        &SlaughteringCave {},
+        &CookingCave {},
+        &WorkingCave {},
+        &MiningCave {},
+        &BreedingCave {},
+        &PeacefulCave {},
+        &HuntingParlor {},
+        &BeerParlor {},
+        &BlacksmithingParlor {},
+        &SparePartStorage {},
+        &OfficeRoom {},
+        &GuestRoom {},
+        &CuddleRoom {},
+        &BreakfastRoom {},
+        &StubbleRoom {},
+        &WorkRoom {},
+        &WeavingParlor {},
+        &MilkingParlor {},
+        &StateParlor {},
+        &StoneStorage {},
+        &OreStorage {},
+        &MainStorage {},
+        &WeaponStorage {},
+        &SuppliesStorage {},
+        &BroomChamber {},
+        &TreasureChamber {},
+        &FoodChamber {},
+        &PrayerChamber {},
+        &WritingChamber {},
+        &FodderChamber {},
+    ];
 
-    dwellings.insert(EntryLevelDwelling {}.get_name(), &EntryLevelDwelling {});
-    dwellings.insert(Dwelling {}.get_name(), &Dwelling {});
-    dwellings.insert(SimpleDwelling1 {}.get_name(), &SimpleDwelling1 {});
-    dwellings.insert(SimpleDwelling2 {}.get_name(), &SimpleDwelling2 {});
-    dwellings.insert(CoupleDwelling {}.get_name(), &CoupleDwelling {});
-    dwellings.insert(AdditionalDwelling {}.get_name(), &AdditionalDwelling {});
-
-    match dwellings.get(string) {
-        Some(x) => Ok(*x),
-        None => Err(format!("No room for {} found", string)),
+    match rooms_list.into_iter().find(|r| r.get_name() == string) {
+        Some(x) => Ok(x),
+        None => Err(GameError::UnknownRoom(String::from(string))),
     }
 }
 
@@ -100,6 +162,10 @@ impl Room for Dwelling {
         RoomConstants::DWELLING
     }
 
+    fn get_score_points(&self) -> u32 {
+        3
+    }
+
     fn get_gnome_slots(&self) -> u32 {
         1
     }
@@ -161,6 +227,10 @@ impl Room for MixedDwelling {
         RoomConstants::MIXED_DWELLING
     }
 
+    fn get_score_points(&self) -> u32 {
+        4
+    }
+
     fn get_gnome_slots(&self) -> u32 {
         1
     }
@@ -174,8 +244,8 @@ impl Room for MixedDwelling {
 
     fn get_price(&self) -> HashMap<String, u32> {
         hash_map! {
-            constants::ResourceType::Wood.str_key() => 3,
-            constants::ResourceType::Stone.str_key() => 3
+            constants::ResourceType::Wood.str_key() => 5,
+            constants::ResourceType::Stone.str_key() => 4
         }
     }
 }
@@ -187,6 +257,10 @@ impl Room for CoupleDwelling {
         RoomConstants::COUPLE_DWELLING
     }
 
+    fn get_score_points(&self) -> u32 {
+        5
+    }
+
     fn get_gnome_slots(&self) -> u32 {
         2
     }
@@ -206,14 +280,837 @@ impl Room for AdditionalDwelling {
         RoomConstants::ADDITIONAL_DWELLING
     }
 
+    fn get_score_points(&self) -> u32 {
+        5
+    }
+
     fn get_gnome_slots(&self) -> u32 {
         1
     }
 
     fn get_price(&self) -> HashMap<String, u32> {
         hash_map! {
-            constants::ResourceType::Wood.str_key() => 8,
-            constants::ResourceType::Stone.str_key() => 6
+            constants::ResourceType::Wood.str_key() => 4,
+            constants::ResourceType::Stone.str_key() => 3
+        }
+    }
+}
+
+pub struct Carpenter {}
+
+impl Room for Carpenter {
+    fn get_name(&self) -> &str {
+        RoomConstants::CARPENTER
+    }
+
+    fn get_price_discount(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 1
+        }
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Stone.str_key() => 1
+        }
+    }
+}
+
+pub struct StoneCarver {}
+
+impl Room for StoneCarver {
+    fn get_name(&self) -> &str {
+        RoomConstants::STONE_CARVER
+    }
+
+    fn get_score_points(&self) -> u32 {
+        1
+    }
+
+    fn get_price_discount(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Stone.str_key() => 1
+        }
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 1
+        }
+    }
+}
+
+pub struct Blacksmith {}
+
+impl Room for Blacksmith {
+    fn get_name(&self) -> &str {
+        RoomConstants::BLACKSMITH
+    }
+
+    fn get_score_points(&self) -> u32 {
+        3
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 1,
+            constants::ResourceType::Stone.str_key() => 2
+        }
+    }
+}
+
+pub struct Miner {}
+
+impl Room for Miner {
+    fn get_name(&self) -> &str {
+        RoomConstants::MINER
+    }
+
+    fn get_score_points(&self) -> u32 {
+        3
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 1,
+            constants::ResourceType::Stone.str_key() => 1
+        }
+    }
+}
+
+pub struct Builder {}
+
+impl Room for Builder {
+    fn get_name(&self) -> &str {
+        RoomConstants::BUILDER
+    }
+
+    fn get_score_points(&self) -> u32 {
+        2
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Stone.str_key() => 1
+        }
+    }
+}
+
+pub struct Trader {}
+
+impl Room for Trader {
+    fn get_name(&self) -> &str {
+        RoomConstants::TRADER
+    }
+
+    fn get_score_points(&self) -> u32 {
+        2
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 1
+        }
+    }
+}
+
+pub struct WoodSupplier {}
+
+impl Room for WoodSupplier {
+    fn get_name(&self) -> &str {
+        RoomConstants::WOOD_SUPPLIER
+    }
+
+    fn get_score_points(&self) -> u32 {
+        2
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Stone.str_key() => 1
         }
     }
-}
\ No newline at end of file
+}
+
+pub struct StoneSupplier {}
+
+impl Room for StoneSupplier {
+    fn get_name(&self) -> &str {
+        RoomConstants::STONE_SUPPLIER
+    }
+
+    fn get_score_points(&self) -> u32 {
+        1
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 1
+        }
+    }
+}
+
+pub struct RubySupplier {}
+
+impl Room for RubySupplier {
+    fn get_name(&self) -> &str {
+        RoomConstants::RUBY_SUPPLIER
+    }
+
+    fn get_score_points(&self) -> u32 {
+        2
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 2,
+            constants::ResourceType::Stone.str_key() => 2
+        }
+    }
+}
+
+pub struct DogSchool {}
+
+impl Room for DogSchool {
+    fn get_name(&self) -> &str {
+        RoomConstants::DOG_SCHOOL
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        HashMap::new()
+    }
+}
+
+pub struct Quarry {}
+
+impl Room for Quarry {
+    fn get_name(&self) -> &str {
+        RoomConstants::QUARRY
+    }
+
+    fn get_score_points(&self) -> u32 {
+        2
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 1
+        }
+    }
+}
+
+pub struct Seam {}
+
+impl Room for Seam {
+    fn get_name(&self) -> &str {
+        RoomConstants::SEAM
+    }
+
+    fn get_score_points(&self) -> u32 {
+        1
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 2
+        }
+    }
+}
+
+pub struct SlaughteringCave {}
+
+impl Room for SlaughteringCave {
+    fn get_name(&self) -> &str {
+        RoomConstants::SLAUGHTERING_CAVE
+    }
+
+    fn get_score_points(&self) -> u32 {
+        2
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 2,
+            constants::ResourceType::Stone.str_key() => 2
+        }
+    }
+}
+
+pub struct CookingCave {}
+
+impl Room for CookingCave {
+    fn get_name(&self) -> &str {
+        RoomConstants::COOKING_CAVE
+    }
+
+    fn get_score_points(&self) -> u32 {
+        2
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Stone.str_key() => 2
+        }
+    }
+}
+
+pub struct WorkingCave {}
+
+impl Room for WorkingCave {
+    fn get_name(&self) -> &str {
+        RoomConstants::WORKING_CAVE
+    }
+
+    fn get_score_points(&self) -> u32 {
+        2
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 1,
+            constants::ResourceType::Stone.str_key() => 1
+        }
+    }
+}
+
+pub struct MiningCave {}
+
+impl Room for MiningCave {
+    fn get_name(&self) -> &str {
+        RoomConstants::MINING_CAVE
+    }
+
+    fn get_score_points(&self) -> u32 {
+        2
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 3,
+            constants::ResourceType::Stone.str_key() => 2
+        }
+    }
+}
+
+pub struct BreedingCave {}
+
+impl Room for BreedingCave {
+    fn get_name(&self) -> &str {
+        RoomConstants::BREEDING_CAVE
+    }
+
+    fn get_score_points(&self) -> u32 {
+        2
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wheat.str_key() => 1,
+            constants::ResourceType::Stone.str_key() => 1
+        }
+    }
+}
+
+pub struct PeacefulCave {}
+
+impl Room for PeacefulCave {
+    fn get_name(&self) -> &str {
+        RoomConstants::PEACEFUL_CAVE
+    }
+
+    fn get_score_points(&self) -> u32 {
+        2
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 2,
+            constants::ResourceType::Stone.str_key() => 2
+        }
+    }
+}
+
+pub struct HuntingParlor {}
+
+impl Room for HuntingParlor {
+    fn get_name(&self) -> &str {
+        RoomConstants::HUNTING_PARLOR
+    }
+
+    fn get_score_points(&self) -> u32 {
+        1
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 2
+        }
+    }
+}
+
+pub struct BeerParlor {}
+
+impl Room for BeerParlor {
+    fn get_name(&self) -> &str {
+        RoomConstants::BEER_PARLOR
+    }
+
+    fn get_score_points(&self) -> u32 {
+        3
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 2
+        }
+    }
+}
+
+pub struct BlacksmithingParlor {}
+
+impl Room for BlacksmithingParlor {
+    fn get_name(&self) -> &str {
+        RoomConstants::BLACKSMITHING_PARLOR
+    }
+
+    fn get_score_points(&self) -> u32 {
+        2
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Coal.str_key() => 3
+        }
+    }
+}
+
+pub struct SparePartStorage {}
+
+impl Room for SparePartStorage {
+    fn get_name(&self) -> &str {
+        RoomConstants::SPARE_PART_STORAGE
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 2
+        }
+    }
+}
+
+pub struct OfficeRoom {}
+
+impl Room for OfficeRoom {
+    fn get_name(&self) -> &str {
+        RoomConstants::OFFICE_ROOM
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Stone.str_key() => 1
+        }
+    }
+}
+
+pub struct GuestRoom {}
+
+impl Room for GuestRoom {
+    fn get_name(&self) -> &str {
+        RoomConstants::GUEST_ROOM
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 1,
+            constants::ResourceType::Stone.str_key() => 1
+        }
+    }
+}
+
+pub struct CuddleRoom {}
+
+impl Room for CuddleRoom {
+    fn get_name(&self) -> &str {
+        RoomConstants::CUDDLE_ROOM
+    }
+
+    fn get_score_points(&self) -> u32 {
+        2
+    }
+
+    fn get_player_slots(&self, player: &Player) -> ResourceSlots {
+        ResourceSlots {
+            types: SHEEP,
+            size: player.get_all_gnomes_count(),
+        }
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 1
+        }
+    }
+}
+
+pub struct BreakfastRoom {}
+
+impl Room for BreakfastRoom {
+    fn get_name(&self) -> &str {
+        RoomConstants::BREAKFAST_ROOM
+    }
+
+    fn get_slots(&self) -> ResourceSlots {
+        ResourceSlots {
+            types: COWS,
+            size: 3,
+        }
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 1
+        }
+    }
+}
+
+pub struct StubbleRoom {}
+
+impl Room for StubbleRoom {
+    fn get_name(&self) -> &str {
+        RoomConstants::STUBBLE_ROOM
+    }
+
+    fn get_score_points(&self) -> u32 {
+        1
+    }
+
+    fn get_player_slots(&self, player: &Player) -> ResourceSlots {
+        ResourceSlots {
+            types: constants::TRIBAL_ANIMALS,
+            size: player.fields.iter().filter(|f| f.field_type == constants::OutsideElement::Field).count() as u32,
+        }
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 1,
+            constants::ResourceType::Coal.str_key() => 1
+        }
+    }
+}
+
+pub struct WorkRoom {}
+
+impl Room for WorkRoom {
+    fn get_name(&self) -> &str {
+        RoomConstants::WORK_ROOM
+    }
+
+    fn get_score_points(&self) -> u32 {
+        2
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Stone.str_key() => 1
+        }
+    }
+}
+
+pub struct WeavingParlor {}
+
+impl Room for WeavingParlor {
+    fn get_name(&self) -> &str {
+        RoomConstants::WEAVING_PARLOR
+    }
+
+    fn get_bonus_points(&self, player: &Player) -> u32 {
+        player.get_resource(constants::ResourceType::Sheep) / 2
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 2,
+            constants::ResourceType::Stone.str_key() => 1
+        }
+    }
+}
+
+pub struct MilkingParlor {}
+
+impl Room for MilkingParlor {
+    fn get_name(&self) -> &str {
+        RoomConstants::MILKING_PARLOR
+    }
+
+    fn get_bonus_points(&self, player: &Player) -> u32 {
+        player.get_resource(constants::ResourceType::Cow)
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 2,
+            constants::ResourceType::Stone.str_key() => 2
+        }
+    }
+}
+
+pub struct StateParlor {}
+
+impl Room for StateParlor {
+    fn get_name(&self) -> &str {
+        RoomConstants::STATE_PARLOR
+    }
+
+    fn get_bonus_points(&self, player: &Player) -> u32 {
+        let dwellings: Vec<u32> = player.rooms
+            .iter()
+            .filter(|r| RoomConstants::GINGER_ROOMS.contains(&r.room_type.as_str()))
+            .map(|r| r.position)
+            .collect();
+        player.rooms
+            .iter()
+            .filter(|r| r.room_type == self.get_name())
+            .map(|r| {
+                get_neighbour_slots(r.position)
+                    .iter()
+                    .filter(|s| dwellings.contains(s))
+                    .count() as u32 * 4
+            })
+            .sum()
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Gold.str_key() => 5,
+            constants::ResourceType::Stone.str_key() => 3
+        }
+    }
+}
+
+pub struct StoneStorage {}
+
+impl Room for StoneStorage {
+    fn get_name(&self) -> &str {
+        RoomConstants::STONE_STORAGE
+    }
+
+    fn get_bonus_points(&self, player: &Player) -> u32 {
+        player.get_resource(constants::ResourceType::Stone)
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 3,
+            constants::ResourceType::Coal.str_key() => 1
+        }
+    }
+}
+
+pub struct OreStorage {}
+
+impl Room for OreStorage {
+    fn get_name(&self) -> &str {
+        RoomConstants::ORE_STORAGE
+    }
+
+    fn get_bonus_points(&self, player: &Player) -> u32 {
+        player.get_resource(constants::ResourceType::Coal) / 2
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 1,
+            constants::ResourceType::Stone.str_key() => 2
+        }
+    }
+}
+
+pub struct MainStorage {}
+
+impl Room for MainStorage {
+    fn get_name(&self) -> &str {
+        RoomConstants::MAIN_STORAGE
+    }
+
+    fn get_bonus_points(&self, player: &Player) -> u32 {
+        player.get_yellow_rooms_count() * 2
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 2,
+            constants::ResourceType::Stone.str_key() => 1
+        }
+    }
+}
+
+pub struct WeaponStorage {}
+
+impl Room for WeaponStorage {
+    fn get_name(&self) -> &str {
+        RoomConstants::WEAPON_STORAGE
+    }
+
+    fn get_bonus_points(&self, player: &Player) -> u32 {
+        player.warriors.len() as u32 * 3
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 3,
+            constants::ResourceType::Stone.str_key() => 2
+        }
+    }
+}
+
+pub struct SuppliesStorage {}
+
+impl Room for SuppliesStorage {
+    fn get_name(&self) -> &str {
+        RoomConstants::SUPPLIES_STORAGE
+    }
+
+    fn get_bonus_points(&self, player: &Player) -> u32 {
+        let gnomes = player.get_all_gnomes_count();
+        if gnomes > 0 && player.warriors.len() as u32 >= gnomes {
+            return 8;
+        }
+        0
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Food.str_key() => 3,
+            constants::ResourceType::Wood.str_key() => 1
+        }
+    }
+}
+
+pub struct BroomChamber {}
+
+impl Room for BroomChamber {
+    fn get_name(&self) -> &str {
+        RoomConstants::BROOM_CHAMBER
+    }
+
+    fn get_bonus_points(&self, player: &Player) -> u32 {
+        match player.get_all_gnomes_count() {
+            0..=4 => 0,
+            5 => 5,
+            _ => 10,
+        }
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 1
+        }
+    }
+}
+
+pub struct TreasureChamber {}
+
+impl Room for TreasureChamber {
+    fn get_name(&self) -> &str {
+        RoomConstants::TREASURE_CHAMBER
+    }
+
+    fn get_bonus_points(&self, player: &Player) -> u32 {
+        player.get_resource(constants::ResourceType::Gem)
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 1,
+            constants::ResourceType::Stone.str_key() => 1
+        }
+    }
+}
+
+pub struct FoodChamber {}
+
+impl Room for FoodChamber {
+    fn get_name(&self) -> &str {
+        RoomConstants::FOOD_CHAMBER
+    }
+
+    fn get_bonus_points(&self, player: &Player) -> u32 {
+        let wheat = player.get_resource(constants::ResourceType::Wheat);
+        let pumpkin = player.get_resource(constants::ResourceType::Pumpkin);
+        wheat.min(pumpkin) * 2
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 2,
+            constants::ResourceType::Pumpkin.str_key() => 2
+        }
+    }
+}
+
+pub struct PrayerChamber {}
+
+impl Room for PrayerChamber {
+    fn get_name(&self) -> &str {
+        RoomConstants::PRAYER_CHAMBER
+    }
+
+    fn get_bonus_points(&self, player: &Player) -> u32 {
+        if player.warriors.is_empty() {
+            return 8;
+        }
+        0
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wood.str_key() => 2
+        }
+    }
+}
+
+pub struct WritingChamber {}
+
+impl Room for WritingChamber {
+    fn get_name(&self) -> &str {
+        RoomConstants::WRITING_CHAMBER
+    }
+
+    fn get_bonus_points(&self, player: &Player) -> u32 {
+        let missing_animals = constants::TRIBAL_ANIMALS
+            .iter()
+            .filter(|a| player.get_resource((*a).clone()) == 0)
+            .count() as u32;
+        (player.fines * 3 + missing_animals * 2 + get_unused_slots(player)).min(7)
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Stone.str_key() => 2
+        }
+    }
+}
+
+pub struct FodderChamber {}
+
+impl Room for FodderChamber {
+    fn get_name(&self) -> &str {
+        RoomConstants::FODDER_CHAMBER
+    }
+
+    fn get_bonus_points(&self, player: &Player) -> u32 {
+        let animals: u32 = constants::TRIBAL_ANIMALS
+            .iter()
+            .map(|a| player.get_resource(a.clone()))
+            .sum();
+        animals / 3
+    }
+
+    fn get_price(&self) -> HashMap<String, u32> {
+        hash_map! {
+            constants::ResourceType::Wheat.str_key() => 2,
+            constants::ResourceType::Stone.str_key() => 1
+        }
+    }
+}
@@ -1,5 +1,8 @@
-use constants::{ResourceType};
-use models::game::{Game};
+use std::collections::HashSet;
+
+use constants::{ResourceType, InsideElement};
+use errors::{GameError};
+use models::game::{Game, Player};
 
 pub fn get_score(resource: ResourceType, count: u32) -> u32 {
     match resource {
@@ -23,36 +26,108 @@ pub static ANIMALS: &'static [ResourceType] = &[
     ResourceType::Cow,
 ];
 
-pub fn get_final_score(game: Game, player_name: &str) -> i32 {
-    let mut score: i32 = 0;
+pub const MISSING_ANIMAL_PENALTY: i32 = -2;
+pub const UNUSED_SPACE_PENALTY: i32 = -1;
+pub const BEGGING_PENALTY: i32 = -3;
+pub const SMALL_PASTURE_POINTS: i32 = 2;
+pub const LARGE_PASTURE_POINTS: i32 = 4;
+pub const MINE_POINTS: i32 = 3;
+pub const GEM_MINE_POINTS: i32 = 4;
+
+// Each home board has 12 spaces
+const BOARD_SLOTS: u32 = 12;
 
-    let player = game.get_player(&String::from(player_name));
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct ScoreBreakdown {
+    // Farm animals and dogs
+    pub animals: i32,
+    pub missing_animals: i32,
+    pub grain: i32,
+    pub vegetables: i32,
+    pub rubies: i32,
+    pub gnomes: i32,
+    // Both forest and mountain boards
+    pub unused_spaces: i32,
+    pub rooms: i32,
+    pub bonus: i32,
+    pub pastures: i32,
+    pub mines: i32,
+    pub gold: i32,
+    pub begging: i32,
+}
+
+impl ScoreBreakdown {
+    pub fn total(&self) -> i32 {
+        self.animals
+            + self.missing_animals
+            + self.grain
+            + self.vegetables
+            + self.rubies
+            + self.gnomes
+            + self.unused_spaces
+            + self.rooms
+            + self.bonus
+            + self.pastures
+            + self.mines
+            + self.gold
+            + self.begging
+    }
+}
+
+pub fn get_unused_slots(player: &Player) -> u32 {
+    // Stables alone don't make forest space used
+    let used_forest_slots = player.get_cleared_slots().len() as u32;
+    let used_mountain_slots = player.caverns
+        .iter()
+        .map(|c| c.position)
+        .chain(player.rooms.iter().map(|r| r.position))
+        .collect::<HashSet<u32>>()
+        .len() as u32;
+    BOARD_SLOTS.saturating_sub(used_forest_slots) + BOARD_SLOTS.saturating_sub(used_mountain_slots)
+}
+
+pub fn get_final_score(game: Game, player_name: &str) -> Result<ScoreBreakdown, GameError> {
+    let player = game.find_player(player_name)?;
+
+    let mut score = ScoreBreakdown::default();
 
     for animal in ANIMALS {
-        if *player.resources.get(&animal.clone().str_key()).unwrap_or(&0) == 0 {
-            score -= 2;
-        } else {
-            score += 1
+        let count = player.get_resource(animal.clone());
+        if count == 0 {
+            score.missing_animals += MISSING_ANIMAL_PENALTY;
         }
+        score.animals += get_score(animal.clone(), count) as i32;
     }
-    score += player.get_resource(ResourceType::Dog) as i32;
-    score += player.get_resource(ResourceType::Pumpkin) as i32;
-    score += (0.5 * player.get_resource(ResourceType::Wheat) as f32).ceil() as i32;
-    score += player.get_resource(ResourceType::Gem) as i32;
-    score += player.get_resource(ResourceType::Gold) as i32;
-    score += player.gnomes as i32 + player.child_gnomes as i32;
+    score.animals += get_score(ResourceType::Dog, player.get_resource(ResourceType::Dog)) as i32;
 
-    score += player.fines as i32 * -3;
+    score.grain = get_score(ResourceType::Wheat, player.get_crops(ResourceType::Wheat)) as i32;
+    score.vegetables = get_score(ResourceType::Pumpkin, player.get_crops(ResourceType::Pumpkin)) as i32;
+    score.rubies = get_score(ResourceType::Gem, player.get_resource(ResourceType::Gem)) as i32;
+    score.gold = get_score(ResourceType::Gold, player.get_resource(ResourceType::Gold)) as i32;
+    score.gnomes = player.get_all_gnomes_count() as i32;
 
-    let rooms_score: u32 = player
+    score.unused_spaces = get_unused_slots(player) as i32 * UNUSED_SPACE_PENALTY;
+
+    score.rooms = player
         .get_rooms()
         .iter()
         .map(|r| r.get_score_points())
-        .sum();
+        .sum::<u32>() as i32;
+    score.bonus = player
+        .get_rooms()
+        .iter()
+        .map(|r| r.get_bonus_points(player))
+        .sum::<u32>() as i32;
 
-    score += rooms_score as i32;
+    score.pastures = player
+        .get_pastures()
+        .iter()
+        .map(|p| if p.len() == 1 { SMALL_PASTURE_POINTS } else { LARGE_PASTURE_POINTS })
+        .sum();
+    score.mines = player.get_caverns_count(InsideElement::Mine) as i32 * MINE_POINTS
+        + player.get_caverns_count(InsideElement::GemMine) as i32 * GEM_MINE_POINTS;
 
-    // TODO: add score from rooms, fields and caverns
+    score.begging = player.fines as i32 * BEGGING_PENALTY;
 
-    score
+    Ok(score)
 }
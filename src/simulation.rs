@@ -1,32 +1,38 @@
 use std::collections::HashMap;
 use rand::{random};
 
-use balance::utils::{BalanceConfig, get_balance_weight, generate_balance_config};
+use actions::{constants as ActionsConstants, Actions, ChangeStatus, MoveAction, OpenNewMove, ReleaseAnimals};
+use balance::utils::{BalanceConfig, generate_balance_config};
+use commands::{get_ranked_actions, perform_command, perform_harvest, perform_next_round};
 use constants;
-use models::game::{Game, Player, PlayerRoom};
+use constants::{GameStatus};
+use errors::GameError;
+use models::game::{Game, Player, PlayerRoom, PlayerCavern};
+use models::schedule::{RoundSchedule};
 use models::moves;
 use moves::config::{MovesConfig};
-use moves::core::{collect_actions};
-use moves::{constants as MovesConstants};
-use moves::feeding::get_feeding_and_breeding_actions;
+use moves::constants::{get_board_moves, STARTING_FOOD};
+use moves::ruby::{RUBY_TRADE};
 use rooms::constants::ENTRY_LEVEL_DWELLING;
 use score::calculator::get_final_score;
-use utils::{get_player_move_actions, get_game_turn_actions, get_start_feeding_and_breeding_actions};
+use utils::{get_player_move_actions};
 
 
-pub fn run_multiple_generations(moves_config: &MovesConfig, generations: i32) -> BalanceConfig {
+pub fn run_multiple_generations(moves_config: &MovesConfig, schedule: &RoundSchedule, generations: i32
+) -> Result<BalanceConfig, GameError> {
     let mut winner: BalanceConfig = mutate_config(&generate_balance_config());
 
     let mut top_scorer: BalanceConfig = mutate_config(&generate_balance_config());
     for _ in 0..generations {
-        let (w, t) = run_one_generation(moves_config, &winner, &top_scorer);
+        let (w, t) = run_one_generation(moves_config, schedule, &winner, &top_scorer)?;
         winner = w;
         top_scorer= t;
     }
-    winner
+    Ok(winner)
 }
 
-pub fn run_one_generation(moves_config: &MovesConfig, winner: &BalanceConfig, top_scorer: &BalanceConfig) -> (BalanceConfig, BalanceConfig) {
+pub fn run_one_generation(moves_config: &MovesConfig, schedule: &RoundSchedule, winner: &BalanceConfig, top_scorer: &BalanceConfig
+) -> Result<(BalanceConfig, BalanceConfig), GameError> {
     let mut configs: Vec<BalanceConfig> = vec![
         winner.clone(),
         top_scorer.clone(),
@@ -39,9 +45,9 @@ pub fn run_one_generation(moves_config: &MovesConfig, winner: &BalanceConfig, to
         configs.push(mutate_config(top_scorer));
     }
 
-    let (winner_index, top_scorer_index) = simulate_tournament(moves_config, &configs);
+    let (winner_index, top_scorer_index) = simulate_tournament(moves_config, schedule, &configs)?;
 
-    (configs[winner_index as usize].clone(), configs[top_scorer_index as usize].clone())
+    Ok((configs[winner_index as usize].clone(), configs[top_scorer_index as usize].clone()))
 }
 
 pub fn mutate_config(base: &BalanceConfig) -> BalanceConfig {
@@ -72,13 +78,14 @@ pub fn _mutate_hash(cfg: &mut HashMap<String, HashMap<String, f32>>) {
     *inner_hash.get_mut(&some_inner_key).unwrap() += delta;
 }
 
-pub fn simulate_tournament(moves_config: &MovesConfig, configs: &Vec<BalanceConfig>) -> (i32, i32) {
+pub fn simulate_tournament(moves_config: &MovesConfig, schedule: &RoundSchedule, configs: &Vec<BalanceConfig>
+) -> Result<(i32, i32), GameError> {
     let mut score_table: Vec<i32> = vec![0; configs.len()];
     let mut sum_score_table: Vec<i32> = vec![0; configs.len()];
 
     for i in 0..configs.len()-1 {
         for j in i+1..configs.len() {
-            let (first_score, second_score) = simulate_2_players_game(moves_config, &configs[i], &configs[j]);
+            let (first_score, second_score) = simulate_2_players_game(moves_config, schedule, &configs[i], &configs[j])?;
             sum_score_table[i] += first_score;
             sum_score_table[j] += second_score;
             if first_score == second_score {
@@ -102,170 +109,156 @@ pub fn simulate_tournament(moves_config: &MovesConfig, configs: &Vec<BalanceConf
         .enumerate()
         .max_by_key(|&(_, item)| item)
         .unwrap();
-    (max as i32, max_by_score as i32)
+    Ok((max as i32, max_by_score as i32))
 }
 
-pub fn simulate_2_players_game(moves_config: &MovesConfig, config1: &BalanceConfig, config2: &BalanceConfig) -> (i32, i32) {
-    let mut game = _instantiate_game();
-    let balances = hash_map! {
-        String::from("p1") => config1,
-        String::from("p2") => config2
-    };
-
-    // Round #1
-    _run_one_round(&mut game, moves_config, &balances);
-    _run_finish_round(&mut game, moves_config, Option::from(MovesConstants::SHEEP_FARMING));
-
-    // Round #2
-    _run_one_round(&mut game, moves_config, &balances);
-    _run_finish_round(&mut game, moves_config, Option::from(MovesConstants::BLACKSMITHING));
-
-    // Round #3
-    _run_one_round(&mut game, moves_config, &balances);
-    _run_feed_and_breed_round(&mut game, constants::FeedingAndBreedingStatus::Normal, &balances);
-    _run_finish_round(&mut game, moves_config, Option::from(MovesConstants::ORE_MINE_CONSTRUCTION));
-
-    // Round #4
-    _run_one_round(&mut game, moves_config, &balances);
-    _run_feed_and_breed_round(&mut game, constants::FeedingAndBreedingStatus::FeedByOne, &balances);
-    _run_finish_round(&mut game, moves_config, Option::from(MovesConstants::WISH_FOR_CHILDREN));
-
-    // Round #5
-    _run_one_round(&mut game, moves_config, &balances);
-    _run_feed_and_breed_round(&mut game, constants::FeedingAndBreedingStatus::Normal, &balances);
-    _run_finish_round(&mut game, moves_config, Option::from(MovesConstants::DONKEY_FARMING));
-
-    // Round #6
-    _run_one_round(&mut game, moves_config, &balances);
-    _run_feed_and_breed_round(&mut game, constants::FeedingAndBreedingStatus::Normal, &balances);
-    _run_finish_round(&mut game, moves_config, Option::from(MovesConstants::RUBY_MINE_CONSTRUCTION));
-
-    // Round #7
-    _run_one_round(&mut game, moves_config, &balances);
-    _run_feed_and_breed_round(&mut game, constants::FeedingAndBreedingStatus::NoBreeding, &balances);
-    _run_finish_round(&mut game, moves_config, Option::from(MovesConstants::ORE_DELIVERY));
-
-    // Round #8
-    _run_one_round(&mut game, moves_config, &balances);
-    _run_feed_and_breed_round(&mut game, constants::FeedingAndBreedingStatus::FeedByOne, &balances);
-    _run_finish_round(&mut game, moves_config, Option::from(MovesConstants::FAMILY_LIFE));
+pub fn simulate_2_players_game(moves_config: &MovesConfig, schedule: &RoundSchedule,
+                               config1: &BalanceConfig, config2: &BalanceConfig) -> Result<(i32, i32), GameError> {
+    let scores = simulate_n_players_game(moves_config, schedule, &vec![config1, config2])?;
+    Ok((scores[0], scores[1]))
+}
 
-    // Round #9 is skipped for 2 players
+// Scores are returned in the initial seat order
+pub fn simulate_n_players_game(moves_config: &MovesConfig, schedule: &RoundSchedule, configs: &Vec<&BalanceConfig>
+) -> Result<Vec<i32>, GameError> {
+    let mut game = instantiate_game(configs.len() as u32)?;
+    let names = game.order.clone();
+    let balances: HashMap<String, &BalanceConfig> = names.iter().cloned().zip(configs.iter().cloned()).collect();
 
-    // Round #10
-    _run_one_round(&mut game, moves_config, &balances);
-    _run_feed_and_breed_round(&mut game, constants::FeedingAndBreedingStatus::Normal, &balances);
-    _run_finish_round(&mut game, moves_config, Option::from(MovesConstants::ADVENTURE));
+    run_game(&mut game, moves_config, schedule, &balances)?;
 
-    // Round #11
-    _run_one_round(&mut game, moves_config, &balances);
-    _run_feed_and_breed_round(&mut game, constants::FeedingAndBreedingStatus::FeedingOrBreeding, &balances);
-    _run_finish_round(&mut game, moves_config, Option::from(MovesConstants::RUBY_DELIVERY));
+    names
+        .iter()
+        .map(|name| get_final_score(game.clone(), name).map(|score| score.total()))
+        .collect()
+}
 
-    // Round #12
-    _run_one_round(&mut game, moves_config, &balances);
-    _run_feed_and_breed_round(&mut game, constants::FeedingAndBreedingStatus::Normal, &balances);
-    _run_finish_round(&mut game, moves_config, Option::from(MovesConstants::ORE_TRADING));
+// Plays every round of the schedule, the first round move is opened on the initial game
+pub fn run_game(game: &mut Game, moves_config: &MovesConfig, schedule: &RoundSchedule,
+                configs: &HashMap<String, &BalanceConfig>) -> Result<(), GameError> {
+    let rounds = schedule.get_rounds(game.players.len() as u32)?;
+    for (index, round) in rounds.into_iter().enumerate() {
+        if index == 0 {
+            OpenNewMove { new_move: round.new_move.clone() }.try_perform(game)?;
+        } else {
+            perform_next_round(game, moves_config, schedule, Some(round.new_move.as_str()))?;
+        }
 
-    (get_final_score(game.clone(), &String::from("p1")), get_final_score(game.clone(), &String::from("p2")))
+        run_one_round(game, moves_config, configs)?;
+        run_feed_and_breed_round(game, moves_config, schedule, configs)?;
+    }
+    Ok(())
 }
 
-fn _run_one_round(game: &mut Game, moves_config: &MovesConfig, configs: &HashMap<String, &BalanceConfig>) {
+// Ranked options are tried from the best one, an option failing on the way is rolled back by try_perform
+pub fn run_one_round(game: &mut Game, moves_config: &MovesConfig, configs: &HashMap<String, &BalanceConfig>
+) -> Result<(), GameError> {
     while game.get_turn_moves_left() != 0 {
-        let game_cloned = game.clone();
-        let player = game_cloned.get_next_user();
-        let balance_config = configs.get(&player).unwrap();
-        let moves = game_cloned.get_free_moves();
-        let actions = collect_actions(game, moves_config, moves);
-
-        let max_actions = actions
-            .iter()
-            .max_by_key(|a| get_balance_weight(&game_cloned, player.as_str(), balance_config, &a.actions))
-            .unwrap();
-        max_actions.actions.perform(game);
-
-        let move_actions = get_player_move_actions(max_actions.move_name.clone(), game);
-        move_actions.perform(game);
+        let ranked = get_ranked_actions(game, moves_config, _get_balance_config(configs, &game.next)?)?;
+        let release = ReleaseAnimals { player: game.next.clone() };
+
+        let mut performed = false;
+        for (_, option) in ranked.into_iter() {
+            let mut actions = if option.move_name == RUBY_TRADE {
+                // Trading rubies doesn't use a gnome, the player moves again afterwards
+                Actions::from_vec(vec![])
+            } else {
+                // Gnome is placed before the move effects, forged weapon is given to it
+                get_player_move_actions(option.move_name.clone(), game)?
+            };
+            actions.actions.extend(option.actions.actions);
+            actions.actions.push(Box::new(release.clone()));
+            if actions.try_perform(game).is_ok() {
+                performed = true;
+                break;
+            }
+        }
+        // Big games can run out of action spaces before all gnomes are placed, the gnomes left skip the round
+        if !performed {
+            Actions::from_vec(vec![Box::new(ChangeStatus { status: GameStatus::NextTurnPending })]).try_perform(game)?;
+            break;
+        }
     }
+    Ok(())
 }
 
-fn _run_feed_and_breed_round(game: &mut Game, status: constants::FeedingAndBreedingStatus, configs: &HashMap<String, &BalanceConfig>) {
-    let actions = get_start_feeding_and_breeding_actions(game, status.clone());
-    actions.perform(game);
-
-    let players = game.players.clone();
-
-    players.into_iter().for_each(|player| {
-        let game_cloned = game.clone();
-
-        let balance_config = configs.get(&player.name).unwrap();
-
-        let feeding_actions = get_feeding_and_breeding_actions(&player, status.clone());
-
-        let max_actions = feeding_actions
-            .iter()
-            .max_by_key(|a| get_balance_weight(&game_cloned, player.name.as_str(), balance_config, &a))
-            .unwrap();
-        max_actions.perform(game);
-    })
-
+// Harvest marker of the round is read by the shared harvest step, players are fed in the order with the feed command
+pub fn run_feed_and_breed_round(game: &mut Game, moves_config: &MovesConfig, schedule: &RoundSchedule,
+                                configs: &HashMap<String, &BalanceConfig>) -> Result<(), GameError> {
+    perform_harvest(game, schedule)?;
+
+    while game.status == GameStatus::FeedingAndBreeding {
+        let ranked = get_ranked_actions(game, moves_config, _get_balance_config(configs, &game.next)?)?;
+        let mut fed = false;
+        for (_, option) in ranked.into_iter() {
+            if perform_command(game, moves_config, schedule, ActionsConstants::FEED, &option.actions.args).is_ok() {
+                fed = true;
+                break;
+            }
+        }
+        if !fed {
+            return Err(GameError::MoveNotPossible(format!("{} for {}", ActionsConstants::FEED, game.next)));
+        }
+    }
+    Ok(())
 }
 
-fn _run_finish_round(game: &mut Game, moves_config: &MovesConfig, new_move: Option<&str>) {
-    let actions = get_game_turn_actions(game, new_move);
-    actions.perform(game);
+fn _get_balance_config<'a>(configs: &HashMap<String, &'a BalanceConfig>, player: &str) -> Result<&'a BalanceConfig, GameError> {
+    configs.get(player).cloned().ok_or_else(|| GameError::MissingBalance(String::from(player)))
+}
 
-    for mov in game.clone().get_all_moves() {
-        mov.on_next_turn(game, &moves_config);
+fn _instantiate_player(name: String, food: u32) -> Player {
+    Player {
+        name,
+        gnomes: 2,
+        child_gnomes: 0,
+        moved_gnomes: 0,
+        caverns: vec![
+            PlayerCavern {
+                cavern_type: constants::InsideElement::Room,
+                position: constants::ENTRY_CAVERN_SLOT,
+            },
+            PlayerCavern {
+                cavern_type: constants::InsideElement::Room,
+                position: constants::INITIAL_CAVERN_SLOT,
+            },
+        ],
+        fields: vec![],
+        large_pastures: vec![],
+        stables: vec![],
+        dogs: vec![],
+        sown_fields: vec![],
+        rooms: vec![
+            PlayerRoom {
+                position: 0u32,
+                room_type: String::from(ENTRY_LEVEL_DWELLING),
+            }
+        ],
+        resources: hash_map! {
+            constants::ResourceType::Food.str_key() => food
+        },
+        moves: vec![],
+        fines: 0,
+        warriors: vec![],
+        moved_warriors: vec![],
     }
 }
 
-fn _instantiate_game() -> Game {
-    Game {
+pub fn instantiate_game(players_count: u32) -> Result<Game, GameError> {
+    let available_moves = get_board_moves(players_count)?;
+    let names: Vec<String> = (0..players_count).map(|i| format!("p{}", i + 1)).collect();
+    Ok(Game {
         turn: 1,
         status: constants::GameStatus::PlayerMove,
-        next: String::from("p1"),
-        first_move: String::from("p1"),
-        order: vec![String::from("p1"), String::from("p2")],
+        next: names[0].clone(),
+        first_move: names[0].clone(),
+        order: names.clone(),
         feeding_and_breeding_status: constants::FeedingAndBreedingStatus::Normal,
-        players: vec![
-            Player {
-                name: String::from("p1"),
-                gnomes: 2,
-                child_gnomes: 0,
-                moved_gnomes: 0,
-                caverns: vec![],
-                fields: vec![],
-                rooms: vec![
-                    PlayerRoom {
-                        position: 0u32,
-                        room_type: String::from(ENTRY_LEVEL_DWELLING),
-                    }
-                ],
-                resources: HashMap::new(),
-                moves: vec![],
-                fines: 0,
-                warriors: vec![],
-            },
-            Player {
-                name: String::from("p2"),
-                gnomes: 2,
-                child_gnomes: 0,
-                moved_gnomes: 0,
-                caverns: vec![],
-                rooms: vec![
-                    PlayerRoom {
-                        position: 0u32,
-                        room_type: String::from(ENTRY_LEVEL_DWELLING),
-                    }
-                ],
-                fields: vec![],
-                resources: HashMap::new(),
-                moves: vec![],
-                fines: 0,
-                warriors: vec![],
-            },
-        ],
+        players: names
+            .into_iter()
+            .enumerate()
+            .map(|(seat, name)| _instantiate_player(name, STARTING_FOOD[seat]))
+            .collect(),
         moves: moves::MovesData {
             drift_mining: moves::DriftMiningData {
                 stone: 1,
@@ -288,15 +281,38 @@ fn _instantiate_game() -> Game {
             ruby_mining: moves::RubyMiningData {
                 gems: 0,
             },
+            sheep_farming: moves::SheepFarmingData {
+                sheep: 0,
+            },
+            donkey_farming: moves::DonkeyFarmingData {
+                donkeys: 0,
+            },
+            ore_delivery: moves::OreDeliveryData {
+                stone: 0,
+                coal: 0,
+            },
+            ruby_delivery: moves::RubyDeliveryData {
+                gems: 0,
+            },
+            ore_mining: moves::OreMiningData {
+                coal: 1,
+            },
+            sustenance: moves::SustenanceData {
+                food: 1,
+            },
+            depot: moves::DepotData {
+                wood: 1,
+                coal: 1,
+            },
+            large_depot: moves::LargeDepotData {
+                wood: 2,
+                stone: 1,
+                coal: 1,
+            },
         },
-        available_moves: vec![
-            String::from("drift_mining"),
-            String::from("logging"),
-            String::from("wood_gathering"),
-            String::from("excavation"),
-            String::from("supplies"),
-            String::from("clearing"),
-            String::from("starting_player"),
-        ],
-    }
-}
\ No newline at end of file
+        available_moves: available_moves
+            .into_iter()
+            .map(String::from)
+            .collect(),
+    })
+}
@@ -1,7 +1,9 @@
 use std::collections::HashMap;
+use serde_yaml;
 use constants;
 use models::game::{Game, Player};
 use models::moves;
+use models::schedule::{RoundSchedule};
 use moves::{constants as MovesConstants};
 use moves::{config as MovesConfig};
 
@@ -28,6 +30,14 @@ pub fn get_game_with_2_players() -> Game {
 
                 fields: vec![],
 
+                large_pastures: vec![],
+
+                stables: vec![],
+
+                dogs: vec![],
+
+                sown_fields: vec![],
+
                 rooms: vec![],
 
                 resources: HashMap::new(),
@@ -37,6 +47,8 @@ pub fn get_game_with_2_players() -> Game {
                 fines: 0,
 
                 warriors: vec![],
+
+                moved_warriors: vec![],
             },
             Player {
                 name: String::from("p2"),
@@ -51,6 +63,14 @@ pub fn get_game_with_2_players() -> Game {
 
                 fields: vec![],
 
+                large_pastures: vec![],
+
+                stables: vec![],
+
+                dogs: vec![],
+
+                sown_fields: vec![],
+
                 resources: HashMap::new(),
 
                 moves: vec![],
@@ -58,6 +78,8 @@ pub fn get_game_with_2_players() -> Game {
                 fines: 0,
 
                 warriors: vec![],
+
+                moved_warriors: vec![],
             },
         ],
 
@@ -83,6 +105,34 @@ pub fn get_game_with_2_players() -> Game {
             ruby_mining: moves::RubyMiningData {
                 gems: 0,
             },
+            sheep_farming: moves::SheepFarmingData {
+                sheep: 0,
+            },
+            donkey_farming: moves::DonkeyFarmingData {
+                donkeys: 0,
+            },
+            ore_delivery: moves::OreDeliveryData {
+                stone: 0,
+                coal: 0,
+            },
+            ruby_delivery: moves::RubyDeliveryData {
+                gems: 0,
+            },
+            ore_mining: moves::OreMiningData {
+                coal: 0,
+            },
+            sustenance: moves::SustenanceData {
+                food: 0,
+            },
+            depot: moves::DepotData {
+                wood: 0,
+                coal: 0,
+            },
+            large_depot: moves::LargeDepotData {
+                wood: 0,
+                stone: 0,
+                coal: 0,
+            },
         },
         available_moves: vec![
             String::from(MovesConstants::DRIFT_MINING),
@@ -97,6 +147,21 @@ pub fn get_game_with_2_players() -> Game {
 }
 
 
+// Harvest comes after the third round only
+pub fn get_schedule() -> RoundSchedule {
+    serde_yaml::from_str("
+stages:
+  - moves:
+      - name: blacksmithing
+      - name: sheep_farming
+      - name: ore_mine_construction
+    rounds:
+      - harvest: ~
+      - harvest: ~
+      - harvest: Normal
+").unwrap()
+}
+
 pub fn get_moves_config() -> MovesConfig::MovesConfig {
     MovesConfig::MovesConfig {
         drift_mining: MovesConfig::DriftMining {
@@ -133,6 +198,47 @@ pub fn get_moves_config() -> MovesConfig::MovesConfig {
             gem_incr: 1,
             from_turn: 2,
         },
+        sheep_farming: MovesConfig::SheepFarming {
+            sheep_incr: 1,
+        },
+        ore_mine_construction: MovesConfig::OreMineConstruction {
+            coal: 3,
+        },
+        donkey_farming: MovesConfig::DonkeyFarming {
+            donkey_incr: 1,
+        },
+        ore_delivery: MovesConfig::OreDelivery {
+            stone_incr: 1,
+            coal_incr: 1,
+            coal_per_mine: 2,
+        },
+        ore_trading: MovesConfig::OreTrading {
+            coal: 2,
+            gold: 2,
+            food: 1,
+            max_trades: 3,
+        },
+        ruby_delivery: MovesConfig::RubyDelivery {
+            gem_incr: 1,
+            mines_for_bonus: 2,
+        },
+        ore_mining: MovesConfig::OreMining {
+            coal_incr: 1,
+            coal_per_mine: 2,
+        },
+        sustenance: MovesConfig::Sustenance {
+            food_incr: 1,
+            wheat: 1,
+        },
+        depot: MovesConfig::Depot {
+            wood_incr: 1,
+            coal_incr: 1,
+        },
+        large_depot: MovesConfig::LargeDepot {
+            wood_incr: 2,
+            stone_incr: 1,
+            coal_incr: 1,
+        },
     }
 }
 
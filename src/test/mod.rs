@@ -2,8 +2,20 @@ pub mod base;
 
 pub mod test_actions;
 
+pub mod test_commands;
+
+pub mod test_models;
+
 pub mod test_moves;
 
+pub mod test_play;
+
+pub mod test_render;
+
+pub mod test_rooms;
+
 pub mod test_score;
 
-pub mod test_utils;
\ No newline at end of file
+pub mod test_utils;
+
+pub mod test_simulation;
\ No newline at end of file
@@ -26,6 +26,123 @@ mod test {
 
             base::assert_player_has_resource(&game, String::from("p1"), constants::ResourceType::Gold, 30);
         }
+
+        #[test]
+        fn test_perform_negative_delta() {
+            let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p1").unwrap().resources.insert(constants::ResourceType::Coal.str_key(), 3);
+
+            let action = UpdateResources {
+                player: String::from("p1"),
+                update_hash: hash_map! {
+                    constants::ResourceType::Coal.str_key() => -2,
+                    constants::ResourceType::Gold.str_key() => 2
+                },
+            };
+            action.perform(&mut game);
+
+            base::assert_player_has_resource(&game, String::from("p1"), constants::ResourceType::Coal, 1);
+            base::assert_player_has_resource(&game, String::from("p1"), constants::ResourceType::Gold, 2);
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_perform_not_enough() {
+            let mut game = base::get_game_with_2_players();
+
+            let action = UpdateResources {
+                player: String::from("p1"),
+                update_hash: hash_map! {
+                    constants::ResourceType::Coal.str_key() => -1
+                },
+            };
+            action.perform(&mut game);
+        }
+    }
+
+    #[cfg(test)]
+    mod test_is_affordable {
+        use std::collections::HashMap;
+
+        use test::base;
+
+        use constants;
+        use actions::{Actions, MoveAction, UpdateResources, PayResources};
+
+        fn get_actions(gain_first: bool) -> Actions {
+            let gain: Box<MoveAction> = Box::new(UpdateResources {
+                player: String::from("p1"),
+                update_hash: hash_map! {
+                    constants::ResourceType::Wood.str_key() => 2
+                },
+            });
+            let payment: Box<MoveAction> = Box::new(PayResources {
+                player: String::from("p1"),
+                price: hash_map! {
+                    constants::ResourceType::Wood.str_key() => 3
+                },
+            });
+            if gain_first {
+                Actions::from_vec(vec![gain, payment])
+            } else {
+                Actions::from_vec(vec![payment, gain])
+            }
+        }
+
+        #[test]
+        fn test_gains_pay_later_costs() {
+            let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p1").unwrap().resources.insert(constants::ResourceType::Wood.str_key(), 1);
+
+            assert!(get_actions(true).is_affordable(&game));
+            assert!(!get_actions(false).is_affordable(&game));
+        }
+
+        #[test]
+        fn test_without_resources() {
+            let game = base::get_game_with_2_players();
+            assert!(!get_actions(true).is_affordable(&game));
+        }
+    }
+
+    #[cfg(test)]
+    mod test_pay_resources {
+        use std::collections::HashMap;
+
+        use test::base;
+
+        use constants;
+        use actions::{MoveAction, PayResources};
+
+        #[test]
+        fn test_perform() {
+            let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p1").unwrap().resources.insert(constants::ResourceType::Wood.str_key(), 5);
+
+            let action = PayResources {
+                player: String::from("p1"),
+                price: hash_map! {
+                    constants::ResourceType::Wood.str_key() => 3
+                },
+            };
+            action.perform(&mut game);
+
+            base::assert_player_has_resource(&game, String::from("p1"), constants::ResourceType::Wood, 2);
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_perform_not_enough() {
+            let mut game = base::get_game_with_2_players();
+
+            let action = PayResources {
+                player: String::from("p1"),
+                price: hash_map! {
+                    constants::ResourceType::Wood.str_key() => 3
+                },
+            };
+            action.perform(&mut game);
+        }
     }
 
     #[cfg(test)]
@@ -33,12 +150,17 @@ mod test {
         use test::base;
 
         use actions::{MoveAction, BuildRooms};
-        use models::game::{PlayerRoom};
+        use constants::{InsideElement};
+        use models::game::{PlayerRoom, PlayerCavern};
         use rooms::{constants as RoomConstants};
 
         #[test]
         fn test_perform() {
             let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p1").unwrap().caverns.push(PlayerCavern {
+                cavern_type: InsideElement::Room,
+                position: 2,
+            });
             let rooms = vec![
                 PlayerRoom {
                     room_type: String::from(RoomConstants::DWELLING),
@@ -52,12 +174,33 @@ mod test {
             };
             action.perform(&mut game);
 
-            let player = game.get_player("p1");
+            let player = game.find_player("p1").unwrap();
 
             assert_eq!(player.rooms.len(), 1);
             assert_eq!(player.rooms[0].position, 2);
             assert_eq!(player.rooms[0].room_type, String::from(RoomConstants::DWELLING));
         }
+
+        #[test]
+        #[should_panic(expected = "Room carpenter is not available")]
+        fn test_perform_taken_unique_room() {
+            let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p2").unwrap().rooms.push(PlayerRoom {
+                room_type: String::from(RoomConstants::CARPENTER),
+                position: 1,
+            });
+
+            let action = BuildRooms {
+                player: String::from("p1"),
+                rooms: vec![
+                    PlayerRoom {
+                        room_type: String::from(RoomConstants::CARPENTER),
+                        position: 2,
+                    }
+                ],
+            };
+            action.perform(&mut game);
+        }
     }
 
     #[cfg(test)]
@@ -84,7 +227,7 @@ mod test {
             };
             action.perform(&mut game);
 
-            let player = game.get_player("p1");
+            let player = game.find_player("p1").unwrap();
 
             assert_eq!(player.fields.len(), 1);
             assert_eq!(player.fields[0].position, 3);
@@ -93,22 +236,393 @@ mod test {
     }
 
     #[cfg(test)]
-    mod test_spawn_gnome {
+    mod test_excavate {
+        use test::base;
+
+        use actions::{MoveAction, Excavate};
+        use constants::{InsideElement};
+        use models::game::{PlayerCavern};
+
+        #[test]
+        fn test_perform() {
+            let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p1").unwrap().caverns.push(PlayerCavern {
+                cavern_type: InsideElement::Room,
+                position: 1,
+            });
+
+            let action = Excavate {
+                player: String::from("p1"),
+                caverns: vec![
+                    PlayerCavern { cavern_type: InsideElement::Room, position: 4 },
+                    PlayerCavern { cavern_type: InsideElement::Hall, position: 7 },
+                ],
+            };
+            action.perform(&mut game);
+
+            let player = game.find_player("p1").unwrap();
+            assert_eq!(player.get_caverns_count(InsideElement::Room), 2);
+            assert_eq!(player.get_caverns_count(InsideElement::Hall), 1);
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_perform_not_adjacent() {
+            let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p1").unwrap().caverns.push(PlayerCavern {
+                cavern_type: InsideElement::Room,
+                position: 1,
+            });
+
+            let action = Excavate {
+                player: String::from("p1"),
+                caverns: vec![
+                    PlayerCavern { cavern_type: InsideElement::Room, position: 10 },
+                ],
+            };
+            action.perform(&mut game);
+        }
+    }
+
+    #[cfg(test)]
+    mod test_build_mines {
+        use test::base;
+
+        use actions::{MoveAction, BuildMines};
+        use constants::{InsideElement};
+        use models::game::{PlayerCavern};
+
+        #[test]
+        fn test_perform() {
+            let mut game = base::get_game_with_2_players();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.caverns.push(PlayerCavern { cavern_type: InsideElement::Hall, position: 4 });
+                player.caverns.push(PlayerCavern { cavern_type: InsideElement::Hall, position: 7 });
+            }
+
+            let action = BuildMines {
+                player: String::from("p1"),
+                mines: vec![
+                    PlayerCavern { cavern_type: InsideElement::Mine, position: 4 },
+                    PlayerCavern { cavern_type: InsideElement::MineHall, position: 7 },
+                ],
+            };
+            action.perform(&mut game);
+
+            let player = game.find_player("p1").unwrap();
+            assert_eq!(player.get_caverns_count(InsideElement::Hall), 0);
+            assert_eq!(player.get_caverns_count(InsideElement::Mine), 1);
+            assert_eq!(player.get_caverns_count(InsideElement::MineHall), 1);
+        }
+    }
+
+    #[cfg(test)]
+    mod test_place_dogs {
+        use test::base;
+
+        use actions::{MoveAction, PlaceDogs};
+        use constants::{OutsideElement, ResourceType};
+        use models::game::{Game, PlayerField};
+
+        fn get_game(dogs: u32) -> Game {
+            let mut game = base::get_game_with_2_players();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.fields = vec![
+                    PlayerField { field_type: OutsideElement::Meadow, position: 0 },
+                    PlayerField { field_type: OutsideElement::Meadow, position: 1 },
+                    PlayerField { field_type: OutsideElement::Field, position: 2 },
+                ];
+                player.resources.insert(ResourceType::Dog.str_key(), dogs);
+            }
+            game
+        }
+
+        #[test]
+        fn test_dogs_guard_sheep() {
+            let mut game = get_game(3);
+            assert_eq!(game.find_player("p1").unwrap().get_resource_max_slots(&ResourceType::Sheep), 0);
+
+            PlaceDogs { player: String::from("p1"), dogs: vec![0, 0] }.perform(&mut game);
+            assert_eq!(game.find_player("p1").unwrap().get_resource_max_slots(&ResourceType::Sheep), 3);
+            assert_eq!(game.find_player("p1").unwrap().get_resource_max_slots(&ResourceType::Donkey), 0);
+
+            PlaceDogs { player: String::from("p1"), dogs: vec![0, 1, 1] }.perform(&mut game);
+            assert_eq!(game.find_player("p1").unwrap().get_resource_max_slots(&ResourceType::Sheep), 5);
+        }
+
+        #[test]
+        fn test_dogs_replace_meadow_stable() {
+            let mut game = get_game(1);
+            game.find_player_mut("p1").unwrap().stables.push(0);
+            assert_eq!(game.find_player("p1").unwrap().get_resource_max_slots(&ResourceType::Sheep), 1);
+
+            PlaceDogs { player: String::from("p1"), dogs: vec![0] }.perform(&mut game);
+            assert_eq!(game.find_player("p1").unwrap().get_resource_max_slots(&ResourceType::Sheep), 2);
+        }
+
+        #[test]
+        fn test_dogs_need_no_slots() {
+            let game = get_game(4);
+            assert_eq!(game.find_player("p1").unwrap().get_resource_max_slots(&ResourceType::Dog), 4);
+        }
+
+        #[test]
+        fn test_best_dog_slots() {
+            let game = get_game(3);
+            assert_eq!(game.find_player("p1").unwrap().get_best_dog_slots(), vec![0, 1, 0]);
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_perform_on_field() {
+            let mut game = get_game(1);
+            PlaceDogs { player: String::from("p1"), dogs: vec![2] }.perform(&mut game);
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_perform_not_enough_dogs() {
+            let mut game = get_game(1);
+            PlaceDogs { player: String::from("p1"), dogs: vec![0, 1] }.perform(&mut game);
+        }
+    }
+
+    #[cfg(test)]
+    mod test_build_pastures {
+        use test::base;
+
+        use actions::{MoveAction, BuildPastures, BuildStables};
+        use constants::{OutsideElement, ResourceType};
+        use models::game::{Game, PlayerField};
+
+        fn get_game() -> Game {
+            let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p1").unwrap().fields = vec![
+                PlayerField { field_type: OutsideElement::Meadow, position: 0 },
+                PlayerField { field_type: OutsideElement::Meadow, position: 1 },
+                PlayerField { field_type: OutsideElement::Meadow, position: 3 },
+            ];
+            game
+        }
+
+        #[test]
+        fn test_perform() {
+            let mut game = get_game();
+
+            BuildPastures { player: String::from("p1"), pastures: vec![0, 1] }.perform(&mut game);
+            BuildPastures { player: String::from("p1"), pastures: vec![3] }.perform(&mut game);
+
+            let player = game.find_player("p1").unwrap();
+            assert_eq!(player.get_fields_count(OutsideElement::LargeFence), 2);
+            assert_eq!(player.get_fields_count(OutsideElement::Fence), 1);
+            assert_eq!(player.get_pastures(), vec![vec![0, 1], vec![3]]);
+            assert_eq!(player.get_outdoor_max_slots(&ResourceType::Sheep), 6);
+            assert_eq!(player.get_outdoor_max_slots(&ResourceType::Wheat), 0);
+        }
+
+        #[test]
+        fn test_stables_capacity() {
+            let mut game = get_game();
+
+            BuildPastures { player: String::from("p1"), pastures: vec![0, 1] }.perform(&mut game);
+            BuildStables { player: String::from("p1"), stables: vec![1, 3, 11] }.perform(&mut game);
+
+            let player = game.find_player("p1").unwrap();
+            // Large pasture doubled, stable on meadow and stable in the forest
+            assert_eq!(player.get_outdoor_max_slots(&ResourceType::Cow), 9);
+            assert_eq!(player.get_outdoor_max_slots(&ResourceType::Hippo), 10);
+        }
+
+        #[test]
+        fn test_enclosures_hold_single_type() {
+            let mut game = get_game();
+
+            BuildPastures { player: String::from("p1"), pastures: vec![0, 1] }.perform(&mut game);
+            BuildPastures { player: String::from("p1"), pastures: vec![3] }.perform(&mut game);
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.resources.insert(ResourceType::Sheep.str_key(), 3);
+                player.resources.insert(ResourceType::Donkey.str_key(), 2);
+            }
+
+            let player = game.find_player("p1").unwrap();
+            assert_eq!(player.get_outdoor_max_slots(&ResourceType::Sheep), 4);
+            assert_eq!(player.get_outdoor_max_slots(&ResourceType::Donkey), 2);
+            assert_eq!(player.get_outdoor_max_slots(&ResourceType::Cow), 0);
+
+            game.find_player_mut("p1").unwrap().resources.insert(ResourceType::Cow.str_key(), 1);
+            let player = game.find_player("p1").unwrap();
+            assert_eq!(player.get_animals_without_room(), vec![(ResourceType::Cow, 1)]);
+        }
+
+        #[test]
+        fn test_large_pastures_are_paired_explicitly() {
+            let mut game = base::get_game_with_2_players();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.fields = vec![
+                    PlayerField { field_type: OutsideElement::LargeFence, position: 0 },
+                    PlayerField { field_type: OutsideElement::LargeFence, position: 3 },
+                    PlayerField { field_type: OutsideElement::LargeFence, position: 1 },
+                    PlayerField { field_type: OutsideElement::LargeFence, position: 4 },
+                ];
+                player.large_pastures = vec![(0, 1), (3, 4)];
+            }
+            assert_eq!(game.find_player("p1").unwrap().get_pastures(), vec![vec![0, 1], vec![3, 4]]);
+            assert_eq!(game.validate(), Ok(()));
+
+            game.find_player_mut("p1").unwrap().large_pastures.pop();
+            assert!(game.validate().is_err());
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_perform_not_adjacent() {
+            let mut game = get_game();
+
+            BuildPastures { player: String::from("p1"), pastures: vec![1, 3] }.perform(&mut game);
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_too_many_stables() {
+            let mut game = get_game();
+
+            BuildStables { player: String::from("p1"), stables: vec![0, 1, 3, 4] }.perform(&mut game);
+        }
+    }
+
+    #[cfg(test)]
+    mod test_sow {
         use test::base;
 
-        use actions::{MoveAction, SpawnGnome};
+        use actions::{MoveAction, Sow, HarvestFields};
+        use constants::{ResourceType, OutsideElement};
+        use models::game::{Game, PlayerField};
+
+        fn get_game() -> Game {
+            let mut game = base::get_game_with_2_players();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.fields = vec![
+                    PlayerField { field_type: OutsideElement::Field, position: 0 },
+                    PlayerField { field_type: OutsideElement::Field, position: 1 },
+                    PlayerField { field_type: OutsideElement::Meadow, position: 2 },
+                ];
+                player.resources.insert(ResourceType::Wheat.str_key(), 1);
+                player.resources.insert(ResourceType::Pumpkin.str_key(), 1);
+            }
+            game
+        }
 
         #[test]
         fn test_perform() {
+            let mut game = get_game();
+
+            Sow { player: String::from("p1"), grain: vec![0], vegetables: vec![1] }.perform(&mut game);
+
+            let player = game.find_player("p1").unwrap();
+            assert_eq!(player.get_resource(ResourceType::Wheat), 0);
+            assert_eq!(player.get_resource(ResourceType::Pumpkin), 0);
+            assert_eq!(player.get_crops(ResourceType::Wheat), 3);
+            assert_eq!(player.get_crops(ResourceType::Pumpkin), 2);
+            assert!(player.get_sowable_slots().is_empty());
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_perform_on_meadow() {
+            let mut game = get_game();
+
+            Sow { player: String::from("p1"), grain: vec![2], vegetables: vec![] }.perform(&mut game);
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_perform_without_crop() {
+            let mut game = get_game();
+
+            Sow { player: String::from("p1"), grain: vec![0, 1], vegetables: vec![] }.perform(&mut game);
+        }
+
+        #[test]
+        fn test_harvest_fields() {
+            let mut game = get_game();
+
+            Sow { player: String::from("p1"), grain: vec![0], vegetables: vec![1] }.perform(&mut game);
+            HarvestFields { player: String::from("p1") }.perform(&mut game);
+            HarvestFields { player: String::from("p1") }.perform(&mut game);
+
+            let player = game.find_player("p1").unwrap();
+            assert_eq!(player.get_resource(ResourceType::Wheat), 2);
+            assert_eq!(player.get_resource(ResourceType::Pumpkin), 2);
+            assert_eq!(player.sown_fields.len(), 1);
+            assert_eq!(player.get_sowable_slots(), vec![1]);
+        }
+    }
+
+    #[cfg(test)]
+    mod test_spawn_gnome {
+        use test::base;
+
+        use actions::{MoveAction, SpawnGnome, GrowUpChildren};
+        use models::game::{Game, PlayerRoom};
+        use rooms::constants as RoomConstants;
+
+        fn get_game() -> Game {
             let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p1").unwrap().rooms = vec![
+                PlayerRoom { room_type: String::from(RoomConstants::ENTRY_LEVEL_DWELLING), position: 0 },
+                PlayerRoom { room_type: String::from(RoomConstants::DWELLING), position: 1 },
+            ];
+            game
+        }
+
+        #[test]
+        fn test_perform() {
+            let mut game = get_game();
 
             let action = SpawnGnome {
                 player: String::from("p1"),
             };
             action.perform(&mut game);
 
-            let player = game.get_player("p1");
+            let player = game.find_player("p1").unwrap();
             assert_eq!(player.child_gnomes, 1);
+            assert_eq!(player.get_free_gnome_slots(), 0);
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_perform_without_free_dwelling() {
+            let mut game = base::get_game_with_2_players();
+
+            SpawnGnome { player: String::from("p1") }.perform(&mut game);
+        }
+
+        #[test]
+        fn test_perform_fills_dwellings() {
+            let mut game = get_game();
+
+            SpawnGnome { player: String::from("p1") }.perform(&mut game);
+
+            let player = game.find_player("p1").unwrap();
+            assert!(!player.can_spawn_gnome());
+            assert!(SpawnGnome { player: String::from("p1") }.try_perform(&mut game).is_err());
+        }
+
+        #[test]
+        fn test_grow_up_children() {
+            let mut game = get_game();
+
+            SpawnGnome { player: String::from("p1") }.perform(&mut game);
+            GrowUpChildren {}.perform(&mut game);
+
+            let player = game.find_player("p1").unwrap();
+            assert_eq!(player.gnomes, 3);
+            assert_eq!(player.child_gnomes, 0);
         }
     }
 
@@ -198,7 +712,7 @@ mod test {
             };
             action.perform(&mut game);
 
-            let player = game.get_player("p1");
+            let player = game.find_player("p1").unwrap();
             assert_eq!(player.moved_gnomes, 1);
         }
     }
@@ -220,7 +734,7 @@ mod test {
             };
             action.perform(&mut game);
 
-            let player = game.get_player("p1");
+            let player = game.find_player("p1").unwrap();
             assert_eq!(player.moves, vec![String::from(MovesConstants::DONKEY_FARMING)]);
         }
     }
@@ -278,19 +792,19 @@ mod test {
             let mut game = base::get_game_with_2_players();
 
             {
-                let p1 = game.get_player_mut(&String::from("p1"));
+                let p1 = game.find_player_mut("p1").unwrap();
                 p1.moves.push(String::from(MovesConstants::DONKEY_FARMING));
             }
             {
-                let p2 = game.get_player_mut(&String::from("p2"));
+                let p2 = game.find_player_mut("p2").unwrap();
                 p2.moves.push(String::from(MovesConstants::ORE_DELIVERY));
             }
 
             let action = ReleaseMoves {};
             action.perform(&mut game);
 
-            assert_eq!(game.get_player("p1").moves.len(), 0);
-            assert_eq!(game.get_player("p2").moves.len(), 0);
+            assert_eq!(game.find_player("p1").unwrap().moves.len(), 0);
+            assert_eq!(game.find_player("p2").unwrap().moves.len(), 0);
         }
     }
 
@@ -333,4 +847,305 @@ mod test {
             assert_eq!(game.feeding_and_breeding_status, FeedingAndBreedingStatus::NoBreeding);
         }
     }
+
+    #[cfg(test)]
+    mod test_take_accumulated {
+        use test::base;
+
+        use actions::{MoveAction, TakeAccumulated};
+        use moves::{constants as MovesConstants};
+
+        #[test]
+        fn test_perform() {
+            let mut game = base::get_game_with_2_players();
+
+            game.moves.logging.wood = 4;
+
+            let action = TakeAccumulated {
+                player_move: String::from(MovesConstants::LOGGING),
+            };
+            action.perform(&mut game);
+
+            assert_eq!(game.moves.logging.wood, 0);
+            assert_eq!(game.moves.excavation.stone, 1);
+        }
+    }
+
+    #[cfg(test)]
+    mod test_errors {
+        use std::collections::HashMap;
+
+        use test::base;
+
+        use constants::{ResourceType};
+        use errors::{GameError};
+        use actions::{MoveAction, PayResources, BuildRooms};
+        use models::game::{PlayerRoom};
+        use rooms::{constants as RoomConstants};
+
+        #[test]
+        fn test_not_enough_resources_keeps_stock() {
+            let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p1").unwrap().resources.insert(ResourceType::Coal.str_key(), 3);
+
+            let action = PayResources {
+                player: String::from("p1"),
+                price: hash_map! {
+                    ResourceType::Coal.str_key() => 1,
+                    ResourceType::Gold.str_key() => 5
+                },
+            };
+            assert_eq!(
+                action.try_perform(&mut game),
+                Err(GameError::NotEnoughResources(String::from("Not enough gold to spend 5")))
+            );
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Coal, 3);
+        }
+
+        #[test]
+        fn test_unknown_player() {
+            let mut game = base::get_game_with_2_players();
+
+            let action = PayResources {
+                player: String::from("p9"),
+                price: hash_map! { ResourceType::Coal.str_key() => 1 },
+            };
+            assert_eq!(action.try_perform(&mut game), Err(GameError::UnknownPlayer(String::from("p9"))));
+        }
+
+        #[test]
+        fn test_taken_unique_room() {
+            let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p2").unwrap().rooms.push(PlayerRoom {
+                room_type: String::from(RoomConstants::CARPENTER),
+                position: 1,
+            });
+
+            let action = BuildRooms {
+                player: String::from("p1"),
+                rooms: vec![
+                    PlayerRoom {
+                        room_type: String::from(RoomConstants::CARPENTER),
+                        position: 2,
+                    }
+                ],
+            };
+            assert_eq!(
+                action.try_perform(&mut game),
+                Err(GameError::IllegalPlacement(String::from("Room carpenter is not available")))
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod test_undo {
+        use std::collections::HashMap;
+
+        use test::base;
+
+        use actions::{
+            MoveAction, Actions, UpdateResources, PayResources, ReserveGnome, BlockMove, NextUser, TakeAccumulated,
+            ReleaseMoves, OpenNewMove, ReorderPlayers, Excavate, BuildRooms, BuildFields, BuildPastures, BuildStables,
+            PlaceDogs, Sow, SpawnGnome, ForgeWeapon, HarvestFields, UpgradeWeapon, UpgradeAllWeapons, Feed,
+            GrowUpChildren, RemoveRooms,
+        };
+        use constants::{ResourceType, InsideElement, OutsideElement};
+        use models::game::{PlayerCavern, PlayerRoom, PlayerField, PlayerSownField};
+        use moves::{constants as MovesConstants};
+        use rooms::{constants as RoomConstants};
+
+        #[test]
+        fn test_failed_actions_are_not_applied() {
+            let mut game = base::get_game_with_2_players();
+
+            let actions = Actions::from_vec(vec![
+                Box::new(UpdateResources {
+                    player: String::from("p1"),
+                    update_hash: hash_map! { ResourceType::Gold.str_key() => 2 },
+                }),
+                Box::new(ReserveGnome { player: String::from("p1") }),
+                Box::new(PayResources {
+                    player: String::from("p1"),
+                    price: hash_map! { ResourceType::Coal.str_key() => 5 },
+                }),
+            ]);
+            assert!(actions.try_perform(&mut game).is_err());
+
+            assert_eq!(game.find_player("p1").unwrap().get_resource(ResourceType::Gold), 0);
+            assert_eq!(game.find_player("p1").unwrap().moved_gnomes, 0);
+        }
+
+        #[test]
+        fn test_revert() {
+            let mut game = base::get_game_with_2_players();
+            game.moves.logging.wood = 3;
+
+            let actions = Actions::from_vec(vec![
+                Box::new(ReserveGnome { player: String::from("p1") }),
+                Box::new(BlockMove { player: String::from("p1"), player_move: String::from(MovesConstants::LOGGING) }),
+                Box::new(UpdateResources {
+                    player: String::from("p1"),
+                    update_hash: hash_map! { ResourceType::Wood.str_key() => 3 },
+                }),
+                Box::new(TakeAccumulated { player_move: String::from(MovesConstants::LOGGING) }),
+                Box::new(NextUser { player: String::from("p2") }),
+            ]);
+            let undo_log = actions.try_perform(&mut game).unwrap();
+            assert_eq!(undo_log.actions.len(), 5);
+            assert_eq!(game.next, String::from("p2"));
+
+            undo_log.revert(&mut game).unwrap();
+            assert_eq!(game.next, String::from("p1"));
+            assert_eq!(game.moves.logging.wood, 3);
+            let player = game.find_player("p1").unwrap();
+            assert_eq!(player.moved_gnomes, 0);
+            assert!(player.moves.is_empty());
+            assert_eq!(player.get_resource(ResourceType::Wood), 0);
+        }
+
+        #[test]
+        fn test_revert_game_actions() {
+            let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p2").unwrap().moves.push(String::from(MovesConstants::LOGGING));
+            let available_moves = game.available_moves.len();
+
+            let actions = Actions::from_vec(vec![
+                Box::new(ReleaseMoves {}),
+                Box::new(OpenNewMove { new_move: String::from(MovesConstants::SHEEP_FARMING) }),
+                Box::new(ReorderPlayers { player: String::from("p2") }),
+            ]);
+            let undo_log = actions.try_perform(&mut game).unwrap();
+            assert_eq!(game.order, vec![String::from("p2"), String::from("p1")]);
+
+            undo_log.revert(&mut game).unwrap();
+            assert_eq!(game.order, vec![String::from("p1"), String::from("p2")]);
+            assert_eq!(game.available_moves.len(), available_moves);
+            assert_eq!(game.find_player("p2").unwrap().moves, vec![String::from(MovesConstants::LOGGING)]);
+        }
+
+        #[test]
+        fn test_undo_of_undo() {
+            let mut game = base::get_game_with_2_players();
+            let action = NextUser { player: String::from("p2") };
+
+            let undo = action.get_undo(&game).unwrap();
+            action.perform(&mut game);
+            let redo = undo.get_undo(&game).unwrap();
+            undo.perform(&mut game);
+            assert_eq!(game.next, String::from("p1"));
+
+            redo.perform(&mut game);
+            assert_eq!(game.next, String::from("p2"));
+        }
+
+        #[test]
+        fn test_placements_are_undone_by_inverse_actions() {
+            let mut game = base::get_game_with_2_players();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.caverns = vec![PlayerCavern { cavern_type: InsideElement::Room, position: 0 }];
+                player.rooms = vec![PlayerRoom { room_type: String::from(RoomConstants::ENTRY_LEVEL_DWELLING), position: 0 }];
+                player.resources.insert(ResourceType::Wood.str_key(), 2);
+                player.resources.insert(ResourceType::Wheat.str_key(), 1);
+                player.resources.insert(ResourceType::Dog.str_key(), 1);
+            }
+            let player = game.find_player("p1").unwrap().clone();
+
+            let actions = Actions::from_vec(vec![
+                Box::new(ReserveGnome { player: String::from("p1") }),
+                Box::new(ForgeWeapon { player: String::from("p1"), strength: 1 }),
+                Box::new(PayResources {
+                    player: String::from("p1"),
+                    price: hash_map! { ResourceType::Wood.str_key() => 1 },
+                }),
+                Box::new(Excavate {
+                    player: String::from("p1"),
+                    caverns: vec![
+                        PlayerCavern { cavern_type: InsideElement::Room, position: 1 },
+                        PlayerCavern { cavern_type: InsideElement::Hall, position: 2 },
+                    ],
+                }),
+                Box::new(BuildRooms {
+                    player: String::from("p1"),
+                    rooms: vec![PlayerRoom { room_type: String::from(RoomConstants::DWELLING), position: 1 }],
+                }),
+                Box::new(SpawnGnome { player: String::from("p1") }),
+                Box::new(BuildFields {
+                    player: String::from("p1"),
+                    fields: vec![
+                        PlayerField { field_type: OutsideElement::Meadow, position: 0 },
+                        PlayerField { field_type: OutsideElement::Field, position: 1 },
+                    ],
+                }),
+                Box::new(Sow { player: String::from("p1"), grain: vec![1], vegetables: vec![] }),
+                Box::new(PlaceDogs { player: String::from("p1"), dogs: vec![0] }),
+                Box::new(BuildPastures { player: String::from("p1"), pastures: vec![0] }),
+                Box::new(BuildStables { player: String::from("p1"), stables: vec![0] }),
+            ]);
+            let undo_log = actions.try_perform(&mut game).unwrap();
+
+            undo_log.revert(&mut game).unwrap();
+            let reverted = game.find_player("p1").unwrap();
+            assert_eq!(reverted.caverns, player.caverns);
+            assert_eq!(reverted.rooms, player.rooms);
+            assert_eq!(reverted.fields, player.fields);
+            assert_eq!(reverted.stables, player.stables);
+            assert_eq!(reverted.dogs, player.dogs);
+            assert_eq!(reverted.sown_fields, player.sown_fields);
+            assert_eq!((reverted.moved_gnomes, reverted.child_gnomes), (player.moved_gnomes, player.child_gnomes));
+            assert_eq!(reverted.warriors, player.warriors);
+            assert_eq!(reverted.moved_warriors, player.moved_warriors);
+            for resource in [ResourceType::Wood, ResourceType::Wheat, ResourceType::Dog].iter() {
+                assert_eq!(reverted.get_resource(resource.clone()), player.get_resource(resource.clone()));
+            }
+        }
+
+        #[test]
+        fn test_harvest_feeding_and_weapons_are_undone_by_inverse_actions() {
+            let mut game = base::get_game_with_2_players();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.gnomes = 3;
+                player.child_gnomes = 1;
+                player.moved_gnomes = 2;
+                player.warriors = vec![3, 14];
+                player.moved_warriors = vec![3, 14];
+                player.sown_fields = vec![PlayerSownField { position: 1, crop: ResourceType::Wheat, count: 1 }];
+                player.resources.insert(ResourceType::Food.str_key(), 1);
+                player.resources.insert(ResourceType::Wheat.str_key(), 0);
+            }
+            let player = game.find_player("p1").unwrap().clone();
+
+            let actions = Actions::from_vec(vec![
+                Box::new(HarvestFields { player: String::from("p1") }),
+                Box::new(UpgradeWeapon { player: String::from("p1"), weapon: 3 }),
+                Box::new(UpgradeAllWeapons { player: String::from("p1") }),
+                Box::new(Feed { player: String::from("p1"), food: 1, fines: 2 }),
+                Box::new(GrowUpChildren {}),
+            ]);
+            let undo_log = actions.try_perform(&mut game).unwrap();
+            assert_eq!(game.find_player("p1").unwrap().warriors, vec![5, 14]);
+
+            undo_log.revert(&mut game).unwrap();
+            assert_eq!(*game.find_player("p1").unwrap(), player);
+        }
+
+        #[test]
+        fn test_undo_of_inverse_action() {
+            let mut game = base::get_game_with_2_players();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.caverns = vec![PlayerCavern { cavern_type: InsideElement::Room, position: 0 }];
+                player.rooms = vec![PlayerRoom { room_type: String::from(RoomConstants::DWELLING), position: 0 }];
+            }
+            let action = RemoveRooms { player: String::from("p1"), positions: vec![0] };
+
+            let undo = action.get_undo(&game).unwrap();
+            action.perform(&mut game);
+            assert!(game.find_player("p1").unwrap().rooms.is_empty());
+
+            undo.perform(&mut game);
+            assert_eq!(game.find_player("p1").unwrap().rooms.len(), 1);
+        }
+    }
 }
\ No newline at end of file
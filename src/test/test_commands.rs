@@ -0,0 +1,219 @@
+#[cfg(test)]
+mod test {
+    #[cfg(test)]
+    mod test_commands {
+        use std::collections::HashMap;
+        use test::base;
+
+        use actions::{constants as ActionsConstants};
+        use commands::{NEXT_ROUND, NEW_MOVE_ARG, perform_command};
+        use constants::{GameStatus, ResourceType};
+        use errors::{GameError};
+        use models::game::{PlayerRoom};
+        use moves::{constants as MovesConstants};
+        use rooms::constants::{ENTRY_LEVEL_DWELLING};
+
+        #[test]
+        fn test_move_passes_turn() {
+            let mut game = base::get_game_with_2_players();
+            let moves_config = base::get_moves_config();
+            let schedule = base::get_schedule();
+            perform_command(&mut game, &moves_config, &schedule, MovesConstants::LOGGING, &HashMap::new()).unwrap();
+
+            assert_eq!(game.next, "p2");
+            assert_eq!(game.find_player("p1").unwrap().moves, vec![String::from(MovesConstants::LOGGING)]);
+        }
+
+        #[test]
+        fn test_taken_move() {
+            let mut game = base::get_game_with_2_players();
+            let moves_config = base::get_moves_config();
+            let schedule = base::get_schedule();
+            perform_command(&mut game, &moves_config, &schedule, MovesConstants::LOGGING, &HashMap::new()).unwrap();
+
+            let before = game.clone();
+            assert_eq!(
+                perform_command(&mut game, &moves_config, &schedule, MovesConstants::LOGGING, &HashMap::new()),
+                Err(GameError::MoveNotPossible(String::from("Move logging is already taken")))
+            );
+            assert_eq!(game, before);
+        }
+
+        #[test]
+        fn test_next_round_before_all_moves() {
+            let mut game = base::get_game_with_2_players();
+            let moves_config = base::get_moves_config();
+            let schedule = base::get_schedule();
+            assert_eq!(
+                perform_command(&mut game, &moves_config, &schedule, NEXT_ROUND, &HashMap::new()),
+                Err(GameError::WrongStatus { expected: GameStatus::NextTurnPending, actual: GameStatus::PlayerMove })
+            );
+        }
+
+        #[test]
+        fn test_next_round_releases_gnomes() {
+            let mut game = base::get_game_with_2_players();
+            let moves_config = base::get_moves_config();
+            let schedule = base::get_schedule();
+            let moves = vec![MovesConstants::LOGGING, MovesConstants::WOOD_GATHERING, MovesConstants::SUPPLIES, MovesConstants::CLEARING];
+            for mov in moves {
+                perform_command(&mut game, &moves_config, &schedule, mov, &HashMap::new()).unwrap();
+            }
+            assert_eq!(game.status, GameStatus::NextTurnPending);
+            perform_command(&mut game, &moves_config, &schedule, NEXT_ROUND, &HashMap::new()).unwrap();
+
+            assert_eq!(game.status, GameStatus::PlayerMove);
+            assert_eq!(game.next, "p1");
+            assert_eq!(game.turn, 2);
+            assert!(game.players.iter().all(|p| p.moved_gnomes == 0 && p.moves.is_empty()));
+        }
+
+        #[test]
+        fn test_harvest_before_next_round() {
+            let mut game = base::get_game_with_2_players();
+            let moves_config = base::get_moves_config();
+            let schedule = base::get_schedule();
+            game.turn = 3;
+            let moves = vec![MovesConstants::LOGGING, MovesConstants::WOOD_GATHERING, MovesConstants::SUPPLIES, MovesConstants::CLEARING];
+            for mov in moves {
+                perform_command(&mut game, &moves_config, &schedule, mov, &HashMap::new()).unwrap();
+            }
+            let new_move = hash_map! { String::from(NEW_MOVE_ARG) => String::from(MovesConstants::ADVENTURE) };
+            perform_command(&mut game, &moves_config, &schedule, NEXT_ROUND, &new_move).unwrap();
+            assert_eq!(game.status, GameStatus::FeedingAndBreeding);
+            assert_eq!(game.next, "p1");
+            assert_eq!(
+                perform_command(&mut game, &moves_config, &schedule, NEXT_ROUND, &new_move),
+                Err(GameError::WrongStatus { expected: GameStatus::NextTurnPending, actual: GameStatus::FeedingAndBreeding })
+            );
+
+            perform_command(&mut game, &moves_config, &schedule, ActionsConstants::FEED, &HashMap::new()).unwrap();
+            assert_eq!(game.next, "p2");
+            perform_command(&mut game, &moves_config, &schedule, ActionsConstants::FEED, &HashMap::new()).unwrap();
+            assert_eq!(game.status, GameStatus::NextTurnCalculating);
+            assert!(game.players.iter().all(|p| p.fines > 0));
+
+            perform_command(&mut game, &moves_config, &schedule, NEXT_ROUND, &new_move).unwrap();
+            assert_eq!(game.status, GameStatus::PlayerMove);
+            assert_eq!(game.turn, 4);
+            assert!(game.available_moves.contains(&String::from(MovesConstants::ADVENTURE)));
+        }
+
+        #[test]
+        fn test_animals_without_room_become_food() {
+            let mut game = base::get_game_with_2_players();
+            let moves_config = base::get_moves_config();
+            let schedule = base::get_schedule();
+            game.available_moves.push(String::from(MovesConstants::ADVENTURE));
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.rooms.push(PlayerRoom { room_type: String::from(ENTRY_LEVEL_DWELLING), position: 0 });
+                player.resources.insert(ResourceType::Sheep.str_key(), 2);
+                player.warriors = vec![1, 1];
+            }
+            let args = hash_map! {
+                String::from("loot") => String::from("wood"),
+                String::from("second_loot") => String::from("sheep")
+            };
+            perform_command(&mut game, &moves_config, &schedule, MovesConstants::ADVENTURE, &args).unwrap();
+
+            let player = game.find_player("p1").unwrap();
+            assert_eq!(player.get_resource(ResourceType::Sheep), 2);
+            assert_eq!(player.get_resource(ResourceType::Food), 1);
+            assert_eq!(game.validate(), Ok(()));
+        }
+    }
+
+    #[cfg(test)]
+    mod test_replay {
+        use std::collections::HashMap;
+        use test::base;
+
+        use commands::{perform_command, replay};
+        use errors::{GameError};
+        use models::history::{HistoryEntry};
+        use moves::{constants as MovesConstants};
+
+        fn get_entry(player: &str, command: &str) -> HistoryEntry {
+            HistoryEntry {
+                parent: String::from(""),
+                snapshot: String::from(""),
+                player: String::from(player),
+                command: String::from(command),
+                args: HashMap::new(),
+                actions: vec![],
+            }
+        }
+
+        #[test]
+        fn test_replay_gives_same_game() {
+            let moves_config = base::get_moves_config();
+            let schedule = base::get_schedule();
+            let mut played = base::get_game_with_2_players();
+            perform_command(&mut played, &moves_config, &schedule, MovesConstants::LOGGING, &HashMap::new()).unwrap();
+            perform_command(&mut played, &moves_config, &schedule, MovesConstants::WOOD_GATHERING, &HashMap::new()).unwrap();
+
+            let mut replayed = base::get_game_with_2_players();
+            replay(&mut replayed, &moves_config, &schedule, &vec![
+                get_entry("p1", MovesConstants::LOGGING),
+                get_entry("p2", MovesConstants::WOOD_GATHERING),
+            ]).unwrap();
+            assert_eq!(replayed, played);
+        }
+
+        #[test]
+        fn test_replay_wrong_player() {
+            let mut game = base::get_game_with_2_players();
+            let result = replay(&mut game, &base::get_moves_config(), &base::get_schedule(), &vec![get_entry("p2", MovesConstants::LOGGING)]);
+            match result {
+                Err(GameError::InvalidState(_)) => (),
+                _ => panic!("Expected the replay to fail"),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod test_get_ranked_actions {
+        use std::collections::HashMap;
+        use test::base;
+
+        use balance::utils::{BalanceConfig, generate_balance_config};
+        use commands::{get_ranked_actions};
+        use constants::{GameStatus};
+        use errors::{GameError};
+
+        #[test]
+        fn test_best_option_first() {
+            let game = base::get_game_with_2_players();
+            let ranked = get_ranked_actions(&game, &base::get_moves_config(), &generate_balance_config()).unwrap();
+
+            assert!(!ranked.is_empty());
+            assert!(ranked.windows(2).all(|pair| pair[0].0 >= pair[1].0));
+            assert!(ranked.iter().all(|&(_, ref option)| game.available_moves.contains(&option.move_name)));
+        }
+
+        #[test]
+        fn test_missing_balance() {
+            let game = base::get_game_with_2_players();
+            let balance_config = BalanceConfig {
+                actions: HashMap::new(),
+                rooms: HashMap::new(),
+                resources: HashMap::new(),
+            };
+            match get_ranked_actions(&game, &base::get_moves_config(), &balance_config) {
+                Err(GameError::MissingBalance(_)) => (),
+                _ => panic!("Expected a missing balance"),
+            }
+        }
+
+        #[test]
+        fn test_not_players_move() {
+            let mut game = base::get_game_with_2_players();
+            game.status = GameStatus::NextTurnPending;
+            match get_ranked_actions(&game, &base::get_moves_config(), &generate_balance_config()) {
+                Err(GameError::WrongStatus { .. }) => (),
+                _ => panic!("Expected a wrong status"),
+            }
+        }
+    }
+}
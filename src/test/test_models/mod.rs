@@ -0,0 +1,5 @@
+pub mod test_game;
+
+pub mod test_history;
+
+pub mod test_schedule;
\ No newline at end of file
@@ -0,0 +1,158 @@
+#[cfg(test)]
+mod test {
+    #[cfg(test)]
+    mod test_reserve_gnome {
+        use test::base;
+
+        use errors::{GameError};
+
+        #[test]
+        fn test_reserve_all_gnomes() {
+            let mut game = base::get_game_with_2_players();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.reserve_gnome().unwrap();
+                player.reserve_gnome().unwrap();
+                assert_eq!(player.get_free_gnomes(), 0);
+                assert_eq!(
+                    player.reserve_gnome(),
+                    Err(GameError::NoGnome(String::from("All gnomes are moved")))
+                );
+            }
+            assert_eq!(game.find_player("p1").unwrap().moved_gnomes, 2);
+        }
+
+        #[test]
+        fn test_reserve_armed_gnome() {
+            let mut game = base::get_game_with_2_players();
+            let player = game.find_player_mut("p1").unwrap();
+            player.warriors.push(3);
+
+            player.reserve_gnome().unwrap();
+            assert!(player.moved_warriors.is_empty());
+            player.reserve_gnome().unwrap();
+            assert_eq!(player.moved_warriors, vec![3]);
+        }
+    }
+
+    #[cfg(test)]
+    mod test_validate {
+        use test::base;
+
+        use actions::{MoveAction, ReleaseMoves};
+        use constants::{ResourceType, InsideElement};
+        use errors::{GameError};
+        use models::game::{PlayerCavern};
+        use moves::{constants as MovesConstants};
+
+        fn assert_invalid(result: Result<(), GameError>, expected: &str) {
+            match result {
+                Err(GameError::InvalidState(message)) => assert!(message.contains(expected), "{}", message),
+                _ => panic!("Expected the game to be invalid"),
+            }
+        }
+
+        #[test]
+        fn test_valid_game() {
+            assert_eq!(base::get_game_with_2_players().validate(), Ok(()));
+        }
+
+        #[test]
+        fn test_too_many_moved_gnomes() {
+            let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p1").unwrap().moved_gnomes = 3;
+            assert_invalid(game.validate(), "Player p1 moved 3 of 2 gnomes");
+        }
+
+        #[test]
+        fn test_duplicated_positions() {
+            let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p2").unwrap().caverns = vec![
+                PlayerCavern { cavern_type: InsideElement::Room, position: 1 },
+                PlayerCavern { cavern_type: InsideElement::Hall, position: 1 },
+            ];
+            assert_invalid(game.validate(), "Player p2 has duplicated caverns on slot 1");
+        }
+
+        #[test]
+        fn test_animals_without_room() {
+            let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p1").unwrap().resources.insert(ResourceType::Sheep.str_key(), 1);
+            assert_invalid(game.validate(), "Player p1 keeps 1 sheep with room for 0");
+        }
+
+        #[test]
+        fn test_unknown_next_player() {
+            let mut game = base::get_game_with_2_players();
+            game.next = String::from("p3");
+            assert_invalid(game.validate(), "Next player p3 is not in the order");
+        }
+
+        #[test]
+        fn test_unavailable_blocked_move() {
+            let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p1").unwrap().moves.push(String::from(MovesConstants::SHEEP_FARMING));
+            assert_invalid(game.validate(), "Player p1 blocks unavailable move sheep_farming");
+        }
+
+        #[test]
+        fn test_release_moves_frees_gnomes() {
+            let mut game = base::get_game_with_2_players();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.reserve_gnome().unwrap();
+                player.moves.push(String::from(MovesConstants::LOGGING));
+            }
+            ReleaseMoves {}.perform(&mut game);
+
+            assert_eq!(game.find_player("p1").unwrap().get_free_gnomes(), 2);
+            assert!(game.find_player("p1").unwrap().moves.is_empty());
+        }
+    }
+
+    #[cfg(test)]
+    mod test_lookups {
+        use test::base;
+
+        use config::{Config};
+        use errors::{GameError};
+        use models::game::{Game};
+
+        #[test]
+        fn test_unknown_player() {
+            let game = base::get_game_with_2_players();
+            match game.find_player("p3") {
+                Err(GameError::UnknownPlayer(name)) => assert_eq!(name, "p3"),
+                _ => panic!("Expected an unknown player"),
+            }
+        }
+
+        #[test]
+        fn test_next_user_skips_players_without_gnomes() {
+            let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p2").unwrap().moved_gnomes = 2;
+            assert_eq!(game.get_next_user().unwrap(), "p1");
+        }
+
+        #[test]
+        fn test_no_next_user() {
+            let mut game = base::get_game_with_2_players();
+            for player in game.players.iter_mut() {
+                player.moved_gnomes = player.gnomes;
+            }
+            match game.get_next_user() {
+                Err(GameError::InvalidState(_)) => (),
+                _ => panic!("Expected no player to be next"),
+            }
+        }
+
+        #[test]
+        fn test_missing_games_folder() {
+            let config = Config { folder: String::from("missing_games_folder") };
+            match Game::get_last_game_file(&config) {
+                Err(GameError::File(_)) => (),
+                _ => panic!("Expected a file error"),
+            }
+        }
+    }
+}
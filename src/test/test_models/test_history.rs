@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod test {
+    #[cfg(test)]
+    mod test_get_chain {
+        use std::collections::HashMap;
+
+        use errors::{GameError};
+        use models::history::{HistoryEntry};
+
+        fn get_entry(parent: &str, snapshot: &str) -> HistoryEntry {
+            HistoryEntry {
+                parent: String::from(parent),
+                snapshot: String::from(snapshot),
+                player: String::from("p1"),
+                command: String::from("logging"),
+                args: HashMap::new(),
+                actions: vec![],
+            }
+        }
+
+        fn get_snapshots(chain: Vec<HistoryEntry>) -> Vec<String> {
+            chain.into_iter().map(|e| e.snapshot).collect()
+        }
+
+        #[test]
+        fn test_chain_in_play_order() {
+            let entries = vec![get_entry("1.yml", "2.yml"), get_entry("2.yml", "3.yml"), get_entry("3.yml", "4.yml")];
+            let chain = HistoryEntry::get_chain(&entries, "3.yml").unwrap();
+            assert_eq!(get_snapshots(chain), vec!["2.yml", "3.yml"]);
+        }
+
+        #[test]
+        fn test_chain_of_first_snapshot() {
+            let entries = vec![get_entry("1.yml", "2.yml")];
+            assert_eq!(HistoryEntry::get_chain(&entries, "1.yml"), Ok(vec![]));
+        }
+
+        #[test]
+        fn test_chain_skips_other_branches() {
+            let entries = vec![get_entry("1.yml", "2.yml"), get_entry("2.yml", "3.yml"), get_entry("2.yml", "4.yml")];
+            let chain = HistoryEntry::get_chain(&entries, "4.yml").unwrap();
+            assert_eq!(get_snapshots(chain), vec!["2.yml", "4.yml"]);
+        }
+
+        #[test]
+        fn test_chain_with_cycle() {
+            let entries = vec![get_entry("2.yml", "3.yml"), get_entry("3.yml", "2.yml")];
+            assert_eq!(
+                HistoryEntry::get_chain(&entries, "3.yml"),
+                Err(GameError::File(String::from("History of 3.yml has a cycle")))
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod test_head {
+        use std::collections::HashMap;
+
+        use errors::{GameError};
+        use models::history::{HistoryEntry, Head};
+
+        fn get_entries() -> Vec<HistoryEntry> {
+            vec![("1.yml", "2.yml"), ("2.yml", "3.yml"), ("3.yml", "4.yml")]
+                .into_iter()
+                .map(|(parent, snapshot)| HistoryEntry {
+                    parent: String::from(parent),
+                    snapshot: String::from(snapshot),
+                    player: String::from("p1"),
+                    command: String::from("logging"),
+                    args: HashMap::new(),
+                    actions: vec![],
+                })
+                .collect()
+        }
+
+        fn get_head(snapshot: &str) -> Head {
+            Head { snapshot: String::from(snapshot), redo: vec![] }
+        }
+
+        #[test]
+        fn test_undo_and_redo() {
+            let mut head = get_head("4.yml");
+            head.undo(&get_entries(), 2).unwrap();
+            assert_eq!(head.snapshot, "2.yml");
+            assert_eq!(head.redo, vec!["4.yml", "3.yml"]);
+
+            head.redo(1).unwrap();
+            assert_eq!(head.snapshot, "3.yml");
+            assert_eq!(head.redo, vec!["4.yml"]);
+        }
+
+        #[test]
+        fn test_undo_too_many() {
+            let mut head = get_head("3.yml");
+            assert_eq!(
+                head.undo(&get_entries(), 3),
+                Err(GameError::InvalidArgument(String::from("Can't undo 3 commands, only 2 lead to 3.yml")))
+            );
+            assert_eq!(head, get_head("3.yml"));
+        }
+
+        #[test]
+        fn test_redo_without_undo() {
+            let mut head = get_head("4.yml");
+            assert_eq!(
+                head.redo(1),
+                Err(GameError::InvalidArgument(String::from("Can't redo 1 commands, only 0 are undone")))
+            );
+        }
+
+        #[test]
+        fn test_advance_drops_redo() {
+            let mut head = get_head("4.yml");
+            head.undo(&get_entries(), 1).unwrap();
+            head.advance("5.yml");
+            assert_eq!(head, get_head("5.yml"));
+        }
+
+        #[test]
+        fn test_goto_undone_snapshot() {
+            let mut head = get_head("4.yml");
+            head.undo(&get_entries(), 3).unwrap();
+            head.goto("3.yml");
+            assert_eq!(head.snapshot, "3.yml");
+            assert_eq!(head.redo, vec!["4.yml"]);
+
+            head.goto("1.yml");
+            assert_eq!(head, get_head("1.yml"));
+        }
+    }
+}
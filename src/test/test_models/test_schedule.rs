@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod test {
+    #[cfg(test)]
+    mod test_get_rounds {
+        use serde_yaml;
+
+        use config::{Config};
+        use constants::{FeedingAndBreedingStatus};
+        use models::schedule::{RoundSchedule};
+
+        fn get_schedule() -> RoundSchedule {
+            serde_yaml::from_str("
+stages:
+  - shuffle: true
+    moves:
+      - name: wish_for_children
+        fixed: true
+      - name: donkey_farming
+      - name: ruby_mine_construction
+    rounds:
+      - harvest: FeedByOne
+      - harvest: ~
+      - harvest: Normal
+  - moves:
+      - name: ore_delivery
+      - name: family_life
+      - name: exploration
+        min_players: 3
+    rounds:
+      - harvest: NoBreeding
+      - {}
+      - harvest: Normal
+        min_players: 3
+").unwrap()
+        }
+
+        #[test]
+        fn test_two_players_rounds() {
+            let rounds = get_schedule().get_rounds(2).unwrap();
+
+            assert_eq!(rounds.len(), 5);
+            assert_eq!(rounds[0].new_move, "wish_for_children");
+            assert_eq!(rounds[0].harvest, Some(FeedingAndBreedingStatus::FeedByOne));
+            assert_eq!(rounds[1].harvest, None);
+            assert_eq!(rounds[3].new_move, "ore_delivery");
+            assert_eq!(rounds[4].new_move, "family_life");
+            assert_eq!(rounds[4].harvest, None);
+        }
+
+        #[test]
+        fn test_player_count_specific_rounds() {
+            let rounds = get_schedule().get_rounds(3).unwrap();
+
+            assert_eq!(rounds.len(), 6);
+            assert_eq!(rounds[5].new_move, "exploration");
+            assert_eq!(rounds[5].harvest, Some(FeedingAndBreedingStatus::Normal));
+        }
+
+        #[test]
+        fn test_shuffle_within_stage() {
+            let schedule = get_schedule();
+
+            for _ in 0..10 {
+                let rounds = schedule.get_rounds(2).unwrap();
+                let mut stage: Vec<String> = rounds[1..3].iter().map(|r| r.new_move.clone()).collect();
+                stage.sort();
+                assert_eq!(rounds[0].new_move, "wish_for_children");
+                assert_eq!(stage, vec!["donkey_farming", "ruby_mine_construction"]);
+            }
+        }
+
+        #[test]
+        fn test_game_schedule() {
+            let config = Config { folder: String::from("game") };
+            let schedule = RoundSchedule::try_read_from_yaml(&config, String::from("round_schedule.yml")).unwrap();
+
+            // Round 9 is skipped for 2 players
+            assert_eq!(schedule.get_rounds(2).unwrap().len(), 11);
+            assert_eq!(schedule.get_rounds(3).unwrap().len(), 12);
+        }
+
+        #[test]
+        fn test_moves_and_rounds_mismatch() {
+            let mut schedule = get_schedule();
+            schedule.stages[1].rounds.pop();
+
+            assert!(schedule.get_rounds(3).is_err());
+        }
+    }
+}
@@ -1 +1,7 @@
-pub mod test_core;
\ No newline at end of file
+pub mod test_core;
+
+pub mod test_feeding;
+
+pub mod test_expedition;
+
+pub mod test_ruby;
\ No newline at end of file
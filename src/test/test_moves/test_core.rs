@@ -12,9 +12,648 @@ mod test {
             let game = base::get_game_with_2_players();
             let moves_config = base::get_moves_config();
 
-            let actions = player_move.get_all_actions(game, &moves_config);
+            let actions = player_move.get_all_actions(game, &moves_config).unwrap();
 
 
         }
     }
+
+    #[cfg(test)]
+    mod test_accumulating_moves {
+        use std::collections::HashMap;
+
+        use test::base;
+
+        use constants::{ResourceType};
+        use moves::core::{Move, Logging, Excavation};
+
+        #[test]
+        fn test_logging_empties_pile() {
+            let player_move = Logging{};
+            let mut game = base::get_game_with_2_players();
+            let moves_config = base::get_moves_config();
+            game.moves.logging.wood = 3;
+
+            let actions = player_move.try_get_actions(game.clone(), &moves_config, &HashMap::new()).unwrap();
+            actions.perform(&mut game);
+
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Wood, 3);
+            assert_eq!(game.moves.logging.wood, 0);
+        }
+
+        #[test]
+        fn test_excavation_secondary_increment() {
+            let player_move = Excavation{};
+            let mut game = base::get_game_with_2_players();
+            let mut moves_config = base::get_moves_config();
+            moves_config.excavation.stone_incr = 2;
+            moves_config.excavation.secondary_stone_incr = 1;
+
+            game.moves.excavation.stone = 0;
+            player_move.on_next_turn(&mut game, &moves_config);
+            assert_eq!(game.moves.excavation.stone, 2);
+
+            player_move.on_next_turn(&mut game, &moves_config);
+            assert_eq!(game.moves.excavation.stone, 3);
+        }
+    }
+
+    #[cfg(test)]
+    mod test_get_furnish_actions {
+        use test::base;
+
+        use constants::{ResourceType, InsideElement};
+        use models::game::{PlayerCavern, PlayerRoom};
+        use moves::core::get_furnish_actions;
+        use rooms::{constants as RoomConstants};
+
+        fn _prepare_game() -> ::models::game::Game {
+            let mut game = base::get_game_with_2_players();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.caverns.push(PlayerCavern { cavern_type: InsideElement::Room, position: 1 });
+                player.caverns.push(PlayerCavern { cavern_type: InsideElement::Hall, position: 2 });
+                player.resources.insert(ResourceType::Stone.str_key(), 1);
+            }
+            game
+        }
+
+        #[test]
+        fn test_offers_affordable_rooms_on_caverns() {
+            let game = _prepare_game();
+
+            let actions = get_furnish_actions(&game, "p1", RoomConstants::ALL_ROOMS).unwrap();
+            let rooms: Vec<&String> = actions.iter().map(|a| a.args.get("room").unwrap()).collect();
+
+            assert!(rooms.contains(&&String::from(RoomConstants::CARPENTER)));
+            assert!(rooms.contains(&&String::from(RoomConstants::DOG_SCHOOL)));
+            assert!(!rooms.contains(&&String::from(RoomConstants::DWELLING)));
+            assert!(actions.iter().all(|a| a.args.get("slot").unwrap() == "1"));
+        }
+
+        #[test]
+        fn test_skips_taken_rooms() {
+            let mut game = _prepare_game();
+            game.find_player_mut("p2").unwrap().rooms.push(PlayerRoom {
+                room_type: String::from(RoomConstants::CARPENTER),
+                position: 0,
+            });
+
+            let actions = get_furnish_actions(&game, "p1", RoomConstants::ALL_ROOMS).unwrap();
+
+            assert!(actions.iter().all(|a| a.args.get("room").unwrap() != RoomConstants::CARPENTER));
+        }
+    }
+
+    #[cfg(test)]
+    mod test_get_excavation_actions {
+        use std::collections::HashMap;
+
+        use test::base;
+
+        use constants::{InsideElement};
+        use models::game::{Game, PlayerCavern};
+        use moves::core::{Move, Excavation, DriftMining, get_excavation_actions};
+
+        fn get_game() -> Game {
+            let mut game = base::get_game_with_2_players();
+            game.next = String::from("p1");
+            game.find_player_mut("p1").unwrap().caverns.push(PlayerCavern {
+                cavern_type: InsideElement::Room,
+                position: 0,
+            });
+            game
+        }
+
+        #[test]
+        fn test_twins_next_to_excavated_slots() {
+            let game = get_game();
+
+            let actions = get_excavation_actions(&game, "p1", false).unwrap();
+            // Pairs (1, 2), (1, 4), (3, 4), (3, 6), each in both orientations
+            assert_eq!(actions.len(), 8);
+            assert!(actions.iter().all(|a| a.args.get("two_rooms").unwrap() == "false"));
+
+            let actions = get_excavation_actions(&game, "p1", true).unwrap();
+            assert_eq!(actions.len(), 12);
+        }
+
+        #[test]
+        fn test_moves_offer_excavation() {
+            let moves_config = base::get_moves_config();
+
+            let drift_mining = DriftMining {}.get_all_actions(get_game(), &moves_config).unwrap();
+            assert_eq!(drift_mining.len(), 9);
+
+            let excavation = Excavation {}.get_all_actions(get_game(), &moves_config).unwrap();
+            assert_eq!(excavation.len(), 13);
+        }
+
+        #[test]
+        fn test_moves_select_excavation_by_args() {
+            let moves_config = base::get_moves_config();
+
+            let mut game = get_game();
+            let args = hash_map! {
+                String::from("room_slot") => String::from("1"),
+                String::from("second_slot") => String::from("2"),
+                String::from("two_rooms") => String::from("false")
+            };
+            Excavation {}.try_get_actions(game.clone(), &moves_config, &args).unwrap().perform(&mut game);
+            let caverns = &game.find_player("p1").unwrap().caverns;
+            assert!(caverns.contains(&PlayerCavern { cavern_type: InsideElement::Room, position: 1 }));
+            assert!(caverns.contains(&PlayerCavern { cavern_type: InsideElement::Hall, position: 2 }));
+
+            let mut game = get_game();
+            DriftMining {}.try_get_actions(game.clone(), &moves_config, &HashMap::new()).unwrap().perform(&mut game);
+            assert_eq!(game.find_player("p1").unwrap().caverns.len(), 1);
+        }
+    }
+
+    #[cfg(test)]
+    mod test_forest_actions {
+        use test::base;
+
+        use constants::{OutsideElement, ResourceType};
+        use models::game::{Game, PlayerField};
+        use moves::core::{Move, Clearing, get_clearing_actions, get_pasture_actions, get_stable_actions};
+
+        fn get_game() -> Game {
+            let mut game = base::get_game_with_2_players();
+            game.next = String::from("p1");
+            game.find_player_mut("p1").unwrap().fields = vec![
+                PlayerField { field_type: OutsideElement::Meadow, position: 0 },
+                PlayerField { field_type: OutsideElement::Meadow, position: 1 },
+            ];
+            game
+        }
+
+        #[test]
+        fn test_clearing_offers_twins() {
+            let game = get_game();
+
+            // Pairs (2, 5), (3, 4), (3, 6), (4, 5), (4, 7), each in both orientations
+            assert_eq!(get_clearing_actions(&game, "p1").unwrap().len(), 10);
+
+            let moves_config = base::get_moves_config();
+            assert_eq!(Clearing {}.get_all_actions(game, &moves_config).unwrap().len(), 11);
+        }
+
+        #[test]
+        fn test_pastures_depend_on_wood() {
+            let mut game = get_game();
+            assert_eq!(get_pasture_actions(&game, "p1").unwrap().len(), 0);
+
+            game.find_player_mut("p1").unwrap().resources.insert(ResourceType::Wood.str_key(), 2);
+            assert_eq!(get_pasture_actions(&game, "p1").unwrap().len(), 2);
+
+            game.find_player_mut("p1").unwrap().resources.insert(ResourceType::Wood.str_key(), 4);
+            assert_eq!(get_pasture_actions(&game, "p1").unwrap().len(), 3);
+        }
+
+        #[test]
+        fn test_stables_skip_fields() {
+            let mut game = get_game();
+            assert_eq!(get_stable_actions(&game, "p1").unwrap().len(), 0);
+
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.resources.insert(ResourceType::Stone.str_key(), 1);
+                player.fields.push(PlayerField { field_type: OutsideElement::Field, position: 2 });
+            }
+            assert_eq!(get_stable_actions(&game, "p1").unwrap().len(), 11);
+        }
+    }
+
+    #[cfg(test)]
+    mod test_stage_moves {
+        use std::collections::HashMap;
+
+        use test::base;
+
+        use actions::{Actions};
+        use constants::{InsideElement, OutsideElement, ResourceType};
+        use errors::GameError;
+        use models::game::{Game, PlayerCavern, PlayerField};
+        use moves::core::{
+            Move, SheepFarming, OreMineConstruction, OreDelivery, OreTrading, RubyDelivery, Blacksmithing,
+            get_farming_actions,
+        };
+
+        fn get_game() -> Game {
+            let mut game = base::get_game_with_2_players();
+            game.next = String::from("p1");
+            game
+        }
+
+        #[test]
+        fn test_sheep_farming_accumulates() {
+            let mut game = get_game();
+            game.find_player_mut("p1").unwrap().fields.push(PlayerField {
+                field_type: OutsideElement::Fence,
+                position: 0,
+            });
+            let moves_config = base::get_moves_config();
+
+            SheepFarming {}.on_next_turn(&mut game, &moves_config);
+            SheepFarming {}.on_next_turn(&mut game, &moves_config);
+
+            let actions = SheepFarming {}.try_get_actions(game.clone(), &moves_config, &HashMap::new()).unwrap();
+            actions.perform(&mut game);
+
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Sheep, 2);
+            assert_eq!(game.moves.sheep_farming.sheep, 0);
+        }
+
+        #[test]
+        fn test_farming_actions() {
+            let mut game = get_game();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.resources.insert(ResourceType::Wood.str_key(), 4);
+                player.resources.insert(ResourceType::Stone.str_key(), 1);
+                player.fields = vec![
+                    PlayerField { field_type: OutsideElement::Meadow, position: 0 },
+                    PlayerField { field_type: OutsideElement::Field, position: 1 },
+                ];
+            }
+
+            let actions = get_farming_actions(&game, "p1").unwrap();
+            // Pasture with stable inside, pasture alone and 11 stables
+            assert_eq!(actions.len(), 13);
+
+            let with_stable = actions
+                .iter()
+                .find(|a| a.args.get("pasture").is_some() && a.args.get("stable").is_some())
+                .unwrap();
+            with_stable.perform(&mut game);
+            assert_eq!(game.find_player("p1").unwrap().get_outdoor_max_slots(&ResourceType::Donkey), 4);
+        }
+
+        #[test]
+        fn test_ore_mine_construction() {
+            let mut game = get_game();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.caverns.push(PlayerCavern { cavern_type: InsideElement::Hall, position: 4 });
+                player.caverns.push(PlayerCavern { cavern_type: InsideElement::Hall, position: 7 });
+            }
+            let moves_config = base::get_moves_config();
+
+            let actions = OreMineConstruction {}.get_all_actions(game.clone(), &moves_config).unwrap();
+            assert_eq!(actions.len(), 2);
+
+            let args = hash_map! {
+                String::from("mine_slot") => String::from("7"),
+                String::from("mine_hall_slot") => String::from("4")
+            };
+            OreMineConstruction {}.try_get_actions(game.clone(), &moves_config, &args).unwrap().perform(&mut game);
+
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Coal, 3);
+            assert_eq!(game.find_player("p1").unwrap().get_caverns_count(InsideElement::Mine), 1);
+        }
+
+        #[test]
+        fn test_ore_mine_construction_without_tunnels() {
+            let game = get_game();
+            let moves_config = base::get_moves_config();
+
+            let args = hash_map! {
+                String::from("mine_slot") => String::from("7"),
+                String::from("mine_hall_slot") => String::from("4")
+            };
+            let result = OreMineConstruction {}.try_get_actions(game, &moves_config, &args);
+            match result {
+                Err(GameError::MoveNotPossible(_)) => (),
+                _ => panic!("Expected the move to be impossible"),
+            }
+        }
+
+        #[test]
+        fn test_ore_delivery_counts_mines() {
+            let mut game = get_game();
+            game.find_player_mut("p1").unwrap().caverns.push(
+                PlayerCavern { cavern_type: InsideElement::Mine, position: 4 }
+            );
+            let moves_config = base::get_moves_config();
+            OreDelivery {}.on_next_turn(&mut game, &moves_config);
+
+            OreDelivery {}.try_get_actions(game.clone(), &moves_config, &HashMap::new()).unwrap().perform(&mut game);
+
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Stone, 1);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Coal, 3);
+        }
+
+        #[test]
+        fn test_ore_trading() {
+            let mut game = get_game();
+            game.find_player_mut("p1").unwrap().resources.insert(ResourceType::Coal.str_key(), 5);
+            let moves_config = base::get_moves_config();
+
+            let actions = OreTrading {}.get_all_actions(game.clone(), &moves_config).unwrap();
+            assert_eq!(actions.len(), 2);
+
+            actions.last().unwrap().perform(&mut game);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Coal, 1);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Gold, 4);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Food, 2);
+        }
+
+        #[test]
+        fn test_ruby_delivery_bonus() {
+            let mut game = get_game();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.caverns.push(PlayerCavern { cavern_type: InsideElement::GemMine, position: 4 });
+                player.caverns.push(PlayerCavern { cavern_type: InsideElement::GemMine, position: 7 });
+            }
+            let moves_config = base::get_moves_config();
+            RubyDelivery {}.on_next_turn(&mut game, &moves_config);
+
+            RubyDelivery {}.try_get_actions(game.clone(), &moves_config, &HashMap::new()).unwrap().perform(&mut game);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Gem, 2);
+        }
+
+        #[test]
+        fn test_blacksmithing_forges_weapon() {
+            let mut game = get_game();
+            game.find_player_mut("p1").unwrap().resources.insert(ResourceType::Coal.str_key(), 3);
+            let moves_config = base::get_moves_config();
+
+            let actions = Blacksmithing {}.get_all_actions(game.clone(), &moves_config).unwrap();
+            let forges: Vec<&Actions> = actions.iter().filter(|a| a.args.len() == 1).collect();
+            assert_eq!(forges.len(), 3);
+            assert!(actions.iter().any(|a| a.args.contains_key("loot")));
+
+            let args = hash_map! {
+                String::from("strength") => String::from("2")
+            };
+            let actions = Blacksmithing {}.try_get_actions(game.clone(), &moves_config, &args).unwrap();
+            game.find_player_mut("p1").unwrap().place_next_gnome();
+            actions.perform(&mut game);
+            assert_eq!(game.find_player("p1").unwrap().warriors, vec![2]);
+            assert_eq!(game.find_player("p1").unwrap().moved_warriors, vec![2]);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Coal, 1);
+        }
+    }
+
+    #[cfg(test)]
+    mod test_sowing {
+        use std::collections::HashMap;
+
+        use test::base;
+
+        use constants::{ResourceType, OutsideElement};
+        use models::game::{Game, PlayerField};
+        use moves::core::{Move, SlashAndBurn, FamilyLife, get_sowing_actions};
+
+        fn get_game() -> Game {
+            let mut game = base::get_game_with_2_players();
+            game.next = String::from("p1");
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.fields = vec![
+                    PlayerField { field_type: OutsideElement::Field, position: 0 },
+                    PlayerField { field_type: OutsideElement::Field, position: 1 },
+                    PlayerField { field_type: OutsideElement::Field, position: 2 },
+                ];
+                player.resources.insert(ResourceType::Wheat.str_key(), 3);
+                player.resources.insert(ResourceType::Pumpkin.str_key(), 1);
+            }
+            game
+        }
+
+        #[test]
+        fn test_get_sowing_actions() {
+            let game = get_game();
+
+            // Up to 2 grain fields and a single vegetable field on 3 fields
+            let actions = get_sowing_actions(&game, "p1").unwrap();
+            assert_eq!(actions.len(), 5);
+        }
+
+        #[test]
+        fn test_slash_and_burn_sows_new_field() {
+            let mut game = base::get_game_with_2_players();
+            game.next = String::from("p1");
+            game.find_player_mut("p1").unwrap().resources.insert(ResourceType::Pumpkin.str_key(), 1);
+            let moves_config = base::get_moves_config();
+
+            let args = hash_map! {
+                String::from("meadow_slot") => String::from("0"),
+                String::from("field_slot") => String::from("1"),
+                String::from("grain") => String::from("0"),
+                String::from("vegetables") => String::from("1")
+            };
+            SlashAndBurn {}.try_get_actions(game.clone(), &moves_config, &args).unwrap().perform(&mut game);
+
+            let player = game.find_player("p1").unwrap();
+            assert_eq!(player.sown_fields.len(), 1);
+            assert_eq!(player.sown_fields[0].position, 1);
+            assert_eq!(player.get_crops(ResourceType::Pumpkin), 2);
+        }
+
+        #[test]
+        fn test_family_life_sows() {
+            let mut game = get_game();
+            let moves_config = base::get_moves_config();
+
+            let args = hash_map! {
+                String::from("grain") => String::from("2"),
+                String::from("vegetables") => String::from("1")
+            };
+            FamilyLife {}.try_get_actions(game.clone(), &moves_config, &args).unwrap().perform(&mut game);
+
+            assert_eq!(game.find_player("p1").unwrap().get_crops(ResourceType::Wheat), 7);
+            assert!(game.find_player("p1").unwrap().get_sowable_slots().is_empty());
+        }
+    }
+
+    #[cfg(test)]
+    mod test_family_growth {
+        use std::collections::HashMap;
+
+        use test::base;
+
+        use models::game::{Game, PlayerRoom};
+        use moves::core::{Move, WishForChildren, FamilyLife, get_family_growth_actions};
+        use rooms::{constants as RoomConstants};
+
+        fn get_game() -> Game {
+            let mut game = base::get_game_with_2_players();
+            game.next = String::from("p1");
+            game.find_player_mut("p1").unwrap().rooms = vec![
+                PlayerRoom { room_type: String::from(RoomConstants::ENTRY_LEVEL_DWELLING), position: 0 },
+            ];
+            game
+        }
+
+        #[test]
+        fn test_family_growth_requires_dwelling() {
+            let mut game = get_game();
+            assert!(get_family_growth_actions(&game, "p1").unwrap().is_empty());
+
+            game.find_player_mut("p1").unwrap().rooms.push(PlayerRoom {
+                room_type: String::from(RoomConstants::DWELLING),
+                position: 1,
+            });
+            assert_eq!(get_family_growth_actions(&game, "p1").unwrap().len(), 1);
+        }
+
+        #[test]
+        fn test_family_life_without_free_dwelling() {
+            let game = get_game();
+            let moves_config = base::get_moves_config();
+
+            let actions = FamilyLife {}.get_all_actions(game, &moves_config).unwrap();
+            assert_eq!(actions.len(), 1);
+            assert!(actions[0].actions.is_empty());
+        }
+
+        #[test]
+        fn test_wish_for_children_spawns_child() {
+            let mut game = get_game();
+            game.find_player_mut("p1").unwrap().rooms.push(PlayerRoom {
+                room_type: String::from(RoomConstants::DWELLING),
+                position: 1,
+            });
+            let moves_config = base::get_moves_config();
+
+            WishForChildren {}.try_get_actions(game.clone(), &moves_config, &HashMap::new()).unwrap().perform(&mut game);
+            assert_eq!(game.find_player("p1").unwrap().child_gnomes, 1);
+            assert_eq!(game.find_player("p1").unwrap().gnomes, 2);
+        }
+    }
+
+    #[cfg(test)]
+    mod test_affordable_actions {
+        use test::base;
+
+        use constants::{ResourceType};
+        use moves::core::{Move, OreTrading, collect_actions};
+
+        #[test]
+        fn test_nothing_to_trade() {
+            let game = base::get_game_with_2_players();
+            let moves_config = base::get_moves_config();
+
+            // Only skipping the trade is left
+            let actions = OreTrading {}.get_affordable_actions(game, &moves_config).unwrap();
+            assert_eq!(actions.len(), 1);
+            assert!(actions[0].args.is_empty());
+        }
+
+        #[test]
+        fn test_collected_actions_are_affordable() {
+            let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p1").unwrap().resources.insert(ResourceType::Coal.str_key(), 3);
+            let moves_config = base::get_moves_config();
+
+            // Two trades would need 4 coal
+            let options = collect_actions(&game, &moves_config, vec![&OreTrading {}]).unwrap();
+            assert_eq!(options.len(), 1);
+            assert!(options.iter().all(|o| o.actions.is_affordable(&game)));
+
+            options[0].actions.perform(&mut game);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Coal, 1);
+        }
+    }
+
+    #[cfg(test)]
+    mod test_player_count_moves {
+        use std::collections::HashMap;
+
+        use test::base;
+
+        use constants::{ResourceType};
+        use moves::constants::{self as MovesConstants, get_board_moves};
+        use moves::core::{Move, OreMining, Depot, Exploration};
+
+        #[test]
+        fn test_board_grows_with_players() {
+            let counts: Vec<usize> = (1..8).map(|n| get_board_moves(n).unwrap().len()).collect();
+            assert_eq!(counts, vec![6, 7, 10, 12, 13, 14, 14]);
+            assert!(!get_board_moves(1).unwrap().contains(&MovesConstants::STARTING_PLAYER));
+            assert!(get_board_moves(6).unwrap().contains(&MovesConstants::LARGE_DEPOT));
+        }
+
+        #[test]
+        fn test_board_rejects_eight_players() {
+            assert!(get_board_moves(8).is_err());
+        }
+
+        #[test]
+        fn test_ore_mining_counts_mines() {
+            let mut game = base::get_game_with_2_players();
+            let moves_config = base::get_moves_config();
+            OreMining {}.on_next_turn(&mut game, &moves_config);
+
+            OreMining {}.try_get_actions(game.clone(), &moves_config, &HashMap::new()).unwrap().perform(&mut game);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Coal, 1);
+            assert_eq!(game.moves.ore_mining.coal, 0);
+        }
+
+        #[test]
+        fn test_depot_accumulates() {
+            let mut game = base::get_game_with_2_players();
+            let moves_config = base::get_moves_config();
+            Depot {}.on_next_turn(&mut game, &moves_config);
+            Depot {}.on_next_turn(&mut game, &moves_config);
+
+            Depot {}.try_get_actions(game.clone(), &moves_config, &HashMap::new()).unwrap().perform(&mut game);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Wood, 2);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Coal, 2);
+            assert_eq!(game.moves.depot.wood, 0);
+        }
+
+        #[test]
+        fn test_exploration_requires_weapon() {
+            let game = base::get_game_with_2_players();
+            let moves_config = base::get_moves_config();
+            assert!(Exploration {}.get_all_actions(game, &moves_config).unwrap().is_empty());
+        }
+    }
+
+    #[cfg(test)]
+    mod test_errors {
+        use std::collections::HashMap;
+
+        use test::base;
+
+        use constants::{ResourceType};
+        use errors::{GameError};
+        use moves::core::{Move, OreMineConstruction, DriftMining, get_from_string};
+
+        #[test]
+        fn test_unknown_move() {
+            assert_eq!(get_from_string("unknown").err(), Some(GameError::UnknownMove(String::from("unknown"))));
+        }
+
+        #[test]
+        fn test_try_get_actions() {
+            let mut game = base::get_game_with_2_players();
+            let moves_config = base::get_moves_config();
+
+            DriftMining {}.try_get_actions(game.clone(), &moves_config, &HashMap::new()).unwrap().perform(&mut game);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Stone, 1);
+        }
+
+        #[test]
+        fn test_try_get_actions_not_possible() {
+            let game = base::get_game_with_2_players();
+            let moves_config = base::get_moves_config();
+
+            let args = hash_map! {
+                String::from("mine_slot") => String::from("7"),
+                String::from("mine_hall_slot") => String::from("4")
+            };
+            let result = OreMineConstruction {}.try_get_actions(game, &moves_config, &args);
+            match result {
+                Err(GameError::MoveNotPossible(message)) => {
+                    assert!(message.starts_with("Move ore_mine_construction is not possible"));
+                },
+                _ => panic!("Expected the move to be impossible"),
+            }
+        }
+    }
 }
\ No newline at end of file
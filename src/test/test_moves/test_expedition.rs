@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod test {
+    #[cfg(test)]
+    mod test_expedition_actions {
+        use std::collections::HashMap;
+
+        use test::base;
+
+        use constants::{InsideElement, ResourceType};
+        use models::game::{Game, PlayerCavern};
+        use moves::config::{MovesConfig};
+        use moves::core::{Move, Adventure, OreMineConstruction};
+        use moves::expedition::{get_expedition_actions, get_expedition_picks, get_upgraded_weapon, UPGRADE_WEAPONS};
+
+        fn get_game() -> Game {
+            let mut game = base::get_game_with_2_players();
+            game.next = String::from("p1");
+            game
+        }
+
+        fn get_armed_game(weapon: u32) -> Game {
+            let mut game = get_game();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.warriors = vec![weapon];
+                player.gnomes = 1;
+                player.place_next_gnome();
+            }
+            game
+        }
+
+        fn get_moves_config() -> MovesConfig {
+            base::get_moves_config()
+        }
+
+        #[test]
+        fn test_weakest_items_picks() {
+            let game = get_armed_game(1);
+
+            // upgrade_weapons, wood and dog taken in any combination
+            assert_eq!(get_expedition_picks(&game, "p1", 1, 1).unwrap().len(), 3);
+            assert_eq!(get_expedition_picks(&game, "p1", 1, 2).unwrap().len(), 6);
+            assert_eq!(get_expedition_picks(&game, "p1", 1, 3).unwrap().len(), 7);
+        }
+
+        #[test]
+        fn test_upgrade_weapons_requires_warriors() {
+            let mut game = get_game();
+            game.find_player_mut("p1").unwrap().place_next_gnome();
+
+            let picks = get_expedition_picks(&game, "p1", 1, 1).unwrap();
+            assert_eq!(picks.len(), 2);
+            assert!(picks.iter().all(|p| p[0].0 != UPGRADE_WEAPONS));
+        }
+
+        #[test]
+        fn test_expedition_upgrades_weapon() {
+            let mut game = get_armed_game(3);
+
+            let actions = get_expedition_actions(&game, "p1", 3, 2).unwrap();
+            let stone = actions.into_iter().find(|a| a.args["loot"] == "wood,stone").unwrap();
+            stone.perform(&mut game);
+
+            assert_eq!(game.find_player("p1").unwrap().warriors, vec![4]);
+            assert_eq!(game.find_player("p1").unwrap().moved_warriors, vec![4]);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Wood, 1);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Stone, 1);
+        }
+
+        #[test]
+        fn test_upgrade_all_weapons_loot() {
+            let mut game = get_armed_game(14);
+            game.find_player_mut("p1").unwrap().warriors.push(5);
+
+            let actions = get_expedition_actions(&game, "p1", 14, 1).unwrap();
+            let upgrade = actions.into_iter().find(|a| a.args["loot"] == UPGRADE_WEAPONS).unwrap();
+            upgrade.perform(&mut game);
+
+            assert_eq!(game.find_player("p1").unwrap().warriors, vec![14, 6]);
+            assert_eq!(get_upgraded_weapon(13, &vec![(UPGRADE_WEAPONS, None)]), 14);
+        }
+
+        #[test]
+        fn test_tunnel_loot_is_excavated() {
+            let mut game = get_armed_game(8);
+            game.find_player_mut("p1").unwrap().caverns.push(PlayerCavern {
+                cavern_type: InsideElement::Room,
+                position: 0,
+            });
+
+            let actions = get_expedition_actions(&game, "p1", 8, 1).unwrap();
+            let tunnel = actions.into_iter().find(|a| a.args["loot"] == "tunnel").unwrap();
+            tunnel.perform(&mut game);
+
+            let player = game.find_player("p1").unwrap();
+            assert!(player.caverns.iter().any(|c| c.cavern_type == InsideElement::Hall));
+        }
+
+        #[test]
+        fn test_adventure_requires_weapon() {
+            let game = get_game();
+
+            let actions = Adventure {}.get_all_actions(game.clone(), &get_moves_config()).unwrap();
+            assert_eq!(actions.len(), 1);
+            assert!(actions[0].actions.is_empty());
+        }
+
+        #[test]
+        fn test_adventure_makes_two_expeditions() {
+            let mut game = get_game();
+            game.find_player_mut("p1").unwrap().warriors = vec![1];
+            game.find_player_mut("p1").unwrap().gnomes = 1;
+
+            let args = hash_map! {
+                String::from("loot") => String::from("wood"),
+                String::from("second_loot") => String::from("wheat")
+            };
+            let actions = Adventure {}.try_get_actions(game.clone(), &get_moves_config(), &args).unwrap();
+            game.find_player_mut("p1").unwrap().place_next_gnome();
+            actions.perform(&mut game);
+
+            assert_eq!(game.find_player("p1").unwrap().warriors, vec![3]);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Wood, 1);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Wheat, 1);
+        }
+
+        #[test]
+        fn test_ore_mine_construction_with_armed_gnome() {
+            let mut game = get_game();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.warriors = vec![1];
+                player.gnomes = 1;
+                player.caverns = vec![
+                    PlayerCavern { cavern_type: InsideElement::Hall, position: 3 },
+                    PlayerCavern { cavern_type: InsideElement::Hall, position: 6 },
+                ];
+            }
+
+            let actions = OreMineConstruction {}.get_all_actions(game.clone(), &get_moves_config()).unwrap();
+            // Two orders of the mine pair, each without expedition or with 6 level 2 loots
+            assert_eq!(actions.len(), 14);
+        }
+    }
+}
@@ -0,0 +1,305 @@
+#[cfg(test)]
+mod test {
+    #[cfg(test)]
+    mod test_get_feeding_plans {
+        use test::base;
+
+        use constants::{ResourceType};
+        use moves::feeding::get_feeding_plans;
+
+        #[test]
+        fn test_enough_food() {
+            let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p1").unwrap().resources.insert(ResourceType::Food.str_key(), 5);
+
+            let plans = get_feeding_plans(game.find_player("p1").unwrap(), 4);
+
+            assert_eq!(plans.len(), 1);
+            assert_eq!(plans[0].food, 4);
+            assert_eq!(plans[0].fines, 0);
+            assert!(plans[0].conversions.is_empty());
+        }
+
+        #[test]
+        fn test_begging_without_resources() {
+            let game = base::get_game_with_2_players();
+
+            let plans = get_feeding_plans(game.find_player("p1").unwrap(), 4);
+
+            assert_eq!(plans.len(), 1);
+            assert_eq!(plans[0].food, 0);
+            assert_eq!(plans[0].fines, 4);
+        }
+
+        #[test]
+        fn test_conversions() {
+            let mut game = base::get_game_with_2_players();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.resources.insert(ResourceType::Food.str_key(), 2);
+                player.resources.insert(ResourceType::Cow.str_key(), 1);
+                player.resources.insert(ResourceType::Gold.str_key(), 3);
+            }
+
+            let plans = get_feeding_plans(game.find_player("p1").unwrap(), 4);
+
+            // Cow: 0 or 1, gold: 0..2 food when cow is not converted
+            assert_eq!(plans.len(), 4);
+            assert!(plans.iter().any(|p| p.fines == 0 && p.conversions.get("cow") == Some(&1)));
+            assert!(plans.iter().any(|p| p.fines == 0 && p.conversions.get("gold") == Some(&3)));
+            assert!(plans.iter().any(|p| p.fines == 2 && p.conversions.is_empty()));
+        }
+
+        #[test]
+        fn test_donkey_pair() {
+            let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p1").unwrap().resources.insert(ResourceType::Donkey.str_key(), 2);
+
+            let plans = get_feeding_plans(game.find_player("p1").unwrap(), 3);
+
+            assert!(plans.iter().any(|p| p.fines == 0 && p.conversions.get("donkey") == Some(&2)));
+        }
+    }
+
+    #[cfg(test)]
+    mod test_breeding_with_dogs {
+        use test::base;
+
+        use constants::{ResourceType, OutsideElement};
+        use models::game::{PlayerField};
+        use moves::feeding::get_breeding_options;
+
+        #[test]
+        fn test_sheep_breed_on_guarded_meadow() {
+            let mut game = base::get_game_with_2_players();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.fields = vec![PlayerField { field_type: OutsideElement::Meadow, position: 0 }];
+                player.resources.insert(ResourceType::Sheep.str_key(), 2);
+                player.resources.insert(ResourceType::Dog.str_key(), 1);
+            }
+            assert_eq!(get_breeding_options(game.find_player("p1").unwrap()).len(), 1);
+
+            // Two dogs keep three sheep, so the pair can breed
+            game.find_player_mut("p1").unwrap().resources.insert(ResourceType::Dog.str_key(), 2);
+            game.find_player_mut("p1").unwrap().place_dogs(vec![0, 0]).unwrap();
+            assert_eq!(get_breeding_options(game.find_player("p1").unwrap()).len(), 2);
+        }
+    }
+
+    #[cfg(test)]
+    mod test_conversions {
+        use std::collections::HashMap;
+
+        use test::base;
+
+        use actions::{constants as ActionsConstants};
+        use constants::{ResourceType, FeedingAndBreedingStatus, OutsideElement};
+        use models::game::{PlayerField};
+        use moves::feeding::{
+            CONVERT_ARG, BREED_ARG, get_converted_food, parse_conversions, get_conversion_actions,
+            get_feeding_and_breeding_actions, get_feeding_args,
+        };
+
+        #[test]
+        fn test_get_converted_food() {
+            assert_eq!(get_converted_food(&ResourceType::Cow, 2), 6);
+            assert_eq!(get_converted_food(&ResourceType::Donkey, 3), 4);
+            assert_eq!(get_converted_food(&ResourceType::Gold, 1), 0);
+            assert_eq!(get_converted_food(&ResourceType::Gold, 4), 3);
+            assert_eq!(get_converted_food(&ResourceType::Dog, 1), 0);
+        }
+
+        #[test]
+        fn test_parse_conversions() {
+            let conversions = parse_conversions("gem:1,wheat:2").unwrap();
+            assert_eq!(conversions.get("gem"), Some(&1));
+            assert_eq!(conversions.get("wheat"), Some(&2));
+
+            assert!(parse_conversions("dog:1").is_err());
+            assert!(parse_conversions("gem").is_err());
+        }
+
+        #[test]
+        fn test_get_feeding_args() {
+            let args = hash_map! {
+                String::from(CONVERT_ARG) => String::from("wheat:1,gem:2"),
+                String::from(BREED_ARG) => String::from("sheep,cow")
+            };
+            let feeding_args = get_feeding_args(&args).unwrap();
+            assert_eq!(feeding_args.get(CONVERT_ARG), Some(&String::from("gem:2,wheat:1")));
+            assert_eq!(feeding_args.get(BREED_ARG), Some(&String::from("cow,sheep")));
+
+            assert_eq!(get_feeding_args(&HashMap::new()), Ok(HashMap::new()));
+        }
+
+        #[test]
+        fn test_get_conversion_actions() {
+            let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p1").unwrap().resources.insert(ResourceType::Gem.str_key(), 1);
+            let conversions = hash_map! { ResourceType::Gem.str_key() => 1 };
+
+            get_conversion_actions(game.find_player("p1").unwrap(), &conversions).unwrap().perform(&mut game);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Gem, 0);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Food, 2);
+
+            assert!(get_conversion_actions(game.find_player("p1").unwrap(), &conversions).is_err());
+        }
+
+        #[test]
+        fn test_converted_before_feeding() {
+            let mut game = base::get_game_with_2_players();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.resources.insert(ResourceType::Cow.str_key(), 2);
+                player.fields.push(PlayerField { field_type: OutsideElement::Fence, position: 0 });
+            }
+
+            let actions = get_feeding_and_breeding_actions(game.find_player("p1").unwrap(), FeedingAndBreedingStatus::NoBreeding);
+            let converting: Vec<_> = actions
+                .iter()
+                .filter(|a| a.actions[0].get_name() == ActionsConstants::CONVERT_RESOURCES)
+                .collect();
+            assert!(converting.iter().all(|a| a.actions[1].get_name() == ActionsConstants::FEED));
+
+            // Both cows cover the 4 food without begging
+            let without_begging = converting.iter().any(|a| {
+                let mut state = game.clone();
+                a.perform(&mut state);
+                state.find_player("p1").unwrap().fines == 0
+            });
+            assert!(without_begging);
+        }
+    }
+
+    #[cfg(test)]
+    mod test_get_feeding_and_breeding_actions {
+        use test::base;
+
+        use actions::{constants as ActionsConstants};
+        use constants::{ResourceType, FeedingAndBreedingStatus, OutsideElement};
+        use models::game::{PlayerRoom, PlayerField, PlayerSownField};
+        use moves::feeding::get_feeding_and_breeding_actions;
+        use rooms::{constants as RoomConstants};
+
+        fn _prepare_game() -> ::models::game::Game {
+            let mut game = base::get_game_with_2_players();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.rooms.push(PlayerRoom {
+                    room_type: String::from(RoomConstants::ENTRY_LEVEL_DWELLING),
+                    position: 0,
+                });
+                player.rooms.push(PlayerRoom {
+                    room_type: String::from(RoomConstants::ENTRY_LEVEL_DWELLING),
+                    position: 1,
+                });
+                player.resources.insert(ResourceType::Food.str_key(), 4);
+                player.resources.insert(ResourceType::Sheep.str_key(), 2);
+            }
+            game
+        }
+
+        #[test]
+        fn test_normal() {
+            let game = _prepare_game();
+            let actions = get_feeding_and_breeding_actions(game.find_player("p1").unwrap(), FeedingAndBreedingStatus::Normal);
+
+            assert_eq!(actions.len(), 2);
+            assert!(actions.iter().all(|a| a.actions[0].get_name() == ActionsConstants::FEED));
+        }
+
+        #[test]
+        fn test_no_breeding() {
+            let game = _prepare_game();
+            let actions = get_feeding_and_breeding_actions(game.find_player("p1").unwrap(), FeedingAndBreedingStatus::NoBreeding);
+
+            assert_eq!(actions.len(), 1);
+            assert_eq!(actions[0].actions.len(), 1);
+        }
+
+        #[test]
+        fn test_no_feeding() {
+            let game = _prepare_game();
+            let actions = get_feeding_and_breeding_actions(game.find_player("p1").unwrap(), FeedingAndBreedingStatus::NoFeeding);
+
+            assert_eq!(actions.len(), 2);
+            assert!(actions.iter().all(|a| a.actions.iter().all(|m| m.get_name() != ActionsConstants::FEED)));
+        }
+
+        #[test]
+        fn test_feed_by_one() {
+            let mut game = _prepare_game();
+            let actions = get_feeding_and_breeding_actions(game.find_player("p1").unwrap(), FeedingAndBreedingStatus::FeedByOne);
+            assert_eq!(actions.len(), 1);
+
+            actions[0].perform(&mut game);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Food, 2);
+        }
+
+        #[test]
+        fn test_child_eats_one_food() {
+            let mut game = _prepare_game();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.child_gnomes = 1;
+                player.resources.insert(ResourceType::Food.str_key(), 5);
+            }
+            let actions = get_feeding_and_breeding_actions(game.find_player("p1").unwrap(), FeedingAndBreedingStatus::NoBreeding);
+            assert_eq!(actions.len(), 1);
+
+            actions[0].perform(&mut game);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Food, 0);
+            assert_eq!(game.find_player("p1").unwrap().fines, 0);
+        }
+
+        #[test]
+        fn test_feeding_or_breeding() {
+            let game = _prepare_game();
+            let actions = get_feeding_and_breeding_actions(game.find_player("p1").unwrap(), FeedingAndBreedingStatus::FeedingOrBreeding);
+
+            assert_eq!(actions.len(), 2);
+            assert!(actions.iter().all(|a| a.actions[0].get_name() == ActionsConstants::FEED));
+            assert_eq!(actions[1].actions[1].get_name(), ActionsConstants::BREED_ANIMALS);
+        }
+
+        #[test]
+        fn test_field_phase_or_breeding() {
+            let mut game = _prepare_game();
+            game.find_player_mut("p1").unwrap().sown_fields.push(PlayerSownField {
+                position: 0,
+                crop: ResourceType::Wheat,
+                count: 3,
+            });
+            let actions = get_feeding_and_breeding_actions(game.find_player("p1").unwrap(), FeedingAndBreedingStatus::FeedingOrBreeding);
+
+            assert_eq!(actions.len(), 3);
+            let names: Vec<Vec<&str>> = actions
+                .iter()
+                .map(|a| a.actions.iter().map(|m| m.get_name()).collect())
+                .collect();
+            assert_eq!(names[0], vec![ActionsConstants::HARVEST_FIELDS, ActionsConstants::FEED]);
+            assert!(names.iter().all(|n| n.contains(&ActionsConstants::FEED)));
+            assert!(names.iter().all(|n| !(n.contains(&ActionsConstants::HARVEST_FIELDS) && n.contains(&ActionsConstants::BREED_ANIMALS))));
+        }
+
+        #[test]
+        fn test_breeding_in_pastures() {
+            let mut game = base::get_game_with_2_players();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.resources.insert(ResourceType::Food.str_key(), 4);
+                player.resources.insert(ResourceType::Cow.str_key(), 2);
+                player.fields = vec![
+                    PlayerField { field_type: OutsideElement::Fence, position: 0 },
+                ];
+            }
+            let actions = get_feeding_and_breeding_actions(game.find_player("p1").unwrap(), FeedingAndBreedingStatus::Normal);
+            assert_eq!(actions.len(), 1);
+
+            game.find_player_mut("p1").unwrap().stables.push(0);
+            let actions = get_feeding_and_breeding_actions(game.find_player("p1").unwrap(), FeedingAndBreedingStatus::Normal);
+            assert_eq!(actions.len(), 2);
+        }
+    }
+}
@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod test {
+    #[cfg(test)]
+    mod test_ruby_trade {
+        use std::collections::HashMap;
+
+        use test::base;
+
+        use constants::{InsideElement, OutsideElement, ResourceType};
+        use models::game::{Game, PlayerCavern, PlayerField};
+        use moves::ruby::{get_ruby_trade, get_ruby_trade_actions};
+
+        fn get_game(rubies: u32) -> Game {
+            let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p1").unwrap().resources.insert(ResourceType::Gem.str_key(), rubies);
+            game
+        }
+
+        #[test]
+        fn test_nothing_without_rubies() {
+            let game = get_game(0);
+            assert!(get_ruby_trade_actions(&game, "p1").unwrap().is_empty());
+        }
+
+        #[test]
+        fn test_buy_resource() {
+            let mut game = get_game(1);
+            let args = hash_map! { String::from("buy") => ResourceType::Stone.str_key() };
+
+            get_ruby_trade(&game, "p1", &args).unwrap().perform(&mut game);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Gem, 0);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Stone, 1);
+        }
+
+        #[test]
+        fn test_empty_slot_is_ignored() {
+            let mut game = get_game(1);
+            let args = hash_map! {
+                String::from("buy") => ResourceType::Stone.str_key(),
+                String::from("slot") => String::new()
+            };
+
+            get_ruby_trade(&game, "p1", &args).unwrap().perform(&mut game);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Stone, 1);
+        }
+
+        #[test]
+        fn test_cow_costs_food() {
+            let mut game = get_game(1);
+            game.find_player_mut("p1").unwrap().fields.push(PlayerField {
+                field_type: OutsideElement::Fence,
+                position: 0,
+            });
+            let args = hash_map! { String::from("buy") => ResourceType::Cow.str_key() };
+            assert!(get_ruby_trade(&game, "p1", &args).is_err());
+
+            game.find_player_mut("p1").unwrap().resources.insert(ResourceType::Food.str_key(), 1);
+            get_ruby_trade(&game, "p1", &args).unwrap().perform(&mut game);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Cow, 1);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Food, 0);
+        }
+
+        #[test]
+        fn test_animals_need_space() {
+            let game = get_game(1);
+            let bought: Vec<String> = get_ruby_trade_actions(&game, "p1").unwrap()
+                .into_iter()
+                .map(|a| a.args.get("buy").unwrap().clone())
+                .collect();
+            assert!(!bought.contains(&ResourceType::Cow.str_key()));
+            assert!(bought.contains(&ResourceType::Wheat.str_key()));
+        }
+
+        #[test]
+        fn test_buy_tiles() {
+            let mut game = get_game(3);
+            let meadow = hash_map! {
+                String::from("buy") => String::from("meadow"),
+                String::from("slot") => String::from("4")
+            };
+            get_ruby_trade(&game, "p1", &meadow).unwrap().perform(&mut game);
+            assert_eq!(game.find_player("p1").unwrap().get_field_type(4), Some(OutsideElement::Meadow));
+
+            game.find_player_mut("p1").unwrap().caverns.push(PlayerCavern {
+                cavern_type: InsideElement::Room,
+                position: 0,
+            });
+            let cavern = hash_map! {
+                String::from("buy") => String::from("cavern"),
+                String::from("slot") => String::from("1")
+            };
+            get_ruby_trade(&game, "p1", &cavern).unwrap().perform(&mut game);
+            assert_eq!(game.find_player("p1").unwrap().get_caverns_count(InsideElement::Room), 2);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Gem, 0);
+        }
+    }
+
+    #[cfg(test)]
+    mod test_out_of_order {
+        use test::base;
+
+        use constants::{ResourceType};
+        use models::game::{Game};
+        use moves::{constants as MovesConstants};
+        use moves::ruby::{get_out_of_order_moves, collect_out_of_order_actions, collect_ruby_trade_actions, RUBY_TRADE};
+
+        fn get_game() -> Game {
+            let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p2").unwrap().moves.push(String::from(MovesConstants::LOGGING));
+            game
+        }
+
+        #[test]
+        fn test_requires_ruby() {
+            let mut game = get_game();
+            assert!(get_out_of_order_moves(&game).unwrap().is_empty());
+
+            game.find_player_mut("p1").unwrap().resources.insert(ResourceType::Gem.str_key(), 1);
+            let moves: Vec<&str> = get_out_of_order_moves(&game).unwrap().iter().map(|m| m.get_name()).collect();
+            assert_eq!(moves, vec![MovesConstants::LOGGING]);
+        }
+
+        #[test]
+        fn test_own_move_is_not_repeated() {
+            let mut game = get_game();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.resources.insert(ResourceType::Gem.str_key(), 1);
+                player.moves.push(String::from(MovesConstants::DRIFT_MINING));
+            }
+            let moves: Vec<&str> = get_out_of_order_moves(&game).unwrap().iter().map(|m| m.get_name()).collect();
+            assert_eq!(moves, vec![MovesConstants::LOGGING]);
+        }
+
+        #[test]
+        fn test_out_of_order_actions_pay_ruby() {
+            let mut game = get_game();
+            game.find_player_mut("p1").unwrap().resources.insert(ResourceType::Gem.str_key(), 1);
+            let moves_config = base::get_moves_config();
+
+            let actions = collect_out_of_order_actions(&game, &moves_config).unwrap();
+            assert!(!actions.is_empty());
+
+            actions[0].actions.perform(&mut game);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Gem, 0);
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Wood, 1);
+        }
+
+        #[test]
+        fn test_ruby_trades_are_listed() {
+            let mut game = get_game();
+            game.find_player_mut("p1").unwrap().resources.insert(ResourceType::Gem.str_key(), 1);
+
+            let actions = collect_ruby_trade_actions(&game).unwrap();
+            assert!(!actions.is_empty());
+            assert!(actions.iter().all(|a| a.move_name == RUBY_TRADE));
+        }
+    }
+}
@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod test {
+    #[cfg(test)]
+    mod test_command_completer {
+        use play::{CommandCompleter};
+
+        fn get_completer() -> CommandCompleter {
+            CommandCompleter {
+                commands: vec![String::from("supplies"), String::from("show"), String::from("sheep_farming")],
+            }
+        }
+
+        #[test]
+        fn test_command_prefix() {
+            assert_eq!(get_completer().get_candidates("sh", 2), vec!["sheep_farming", "show"]);
+        }
+
+        #[test]
+        fn test_empty_line() {
+            assert_eq!(get_completer().get_candidates("", 0).len(), 3);
+        }
+
+        #[test]
+        fn test_args_are_not_completed() {
+            assert!(get_completer().get_candidates("show -", 6).is_empty());
+        }
+
+        #[test]
+        fn test_leading_spaces() {
+            assert_eq!(get_completer().get_candidates("  su", 4), vec!["supplies"]);
+        }
+    }
+}
@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod test {
+    #[cfg(test)]
+    mod test_render_grid {
+        use std::collections::HashMap;
+
+        use render::{render_grid};
+
+        #[test]
+        fn test_slot_numbering() {
+            let slots: HashMap<u32, String> = hash_map! {
+                1 => String::from("room"),
+                5 => String::from("hall")
+            };
+            assert_eq!(render_grid(&slots), vec![
+                "    0 .    |  1 room |  2 .",
+                "    3 .    |  4 .    |  5 hall",
+                "    6 .    |  7 .    |  8 .",
+                "    9 .    | 10 .    | 11 .",
+            ]);
+        }
+    }
+
+    #[cfg(test)]
+    mod test_render_board {
+        use test::base;
+
+        use moves::{constants as MovesConstants};
+        use render::{render_board};
+
+        #[test]
+        fn test_goods_and_occupants() {
+            let mut game = base::get_game_with_2_players();
+            game.available_moves = vec![String::from(MovesConstants::LOGGING), String::from(MovesConstants::SUPPLIES)];
+            game.moves.logging.wood = 3;
+            game.find_player_mut("p2").unwrap().moves.push(String::from(MovesConstants::SUPPLIES));
+
+            assert_eq!(render_board(&game), vec![
+                "  logging   wood: 3",
+                "  supplies  [p2]",
+            ]);
+        }
+    }
+
+    #[cfg(test)]
+    mod test_render_player {
+        use test::base;
+
+        use constants::{ResourceType, OutsideElement};
+        use models::game::{PlayerField, PlayerSownField};
+        use render::{render_player};
+
+        #[test]
+        fn test_forest_slots() {
+            let mut game = base::get_game_with_2_players();
+            let player = game.find_player_mut("p1").unwrap();
+            player.fields = vec![
+                PlayerField { field_type: OutsideElement::Field, position: 2 },
+                PlayerField { field_type: OutsideElement::Meadow, position: 3 },
+            ];
+            player.sown_fields = vec![PlayerSownField { position: 2, crop: ResourceType::Wheat, count: 3 }];
+            player.stables = vec![3];
+            player.dogs = vec![3, 3];
+
+            let lines = render_player(player);
+            let forest = lines.iter().position(|l| l == "  Forest:").unwrap();
+            assert_eq!(lines[forest + 1], "    0 .                    |  1 .                    |  2 field wheat:3");
+            assert_eq!(lines[forest + 2], "    3 meadow stable dogs:2 |  4 .                    |  5 .");
+        }
+    }
+}
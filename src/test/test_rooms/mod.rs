@@ -0,0 +1 @@
+pub mod test_core;
\ No newline at end of file
@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod test {
+    #[cfg(test)]
+    mod test_get_from_string {
+        use rooms::constants::{ALL_ROOMS, GREEN_ROOMS, YELLOW_ROOMS, GINGER_ROOMS};
+        use rooms::core::get_from_string;
+
+        #[test]
+        fn test_all_rooms_registered() {
+            for room in ALL_ROOMS.iter() {
+                assert_eq!(get_from_string(room).unwrap().get_name(), *room);
+            }
+        }
+
+        #[test]
+        fn test_all_rooms_have_color() {
+            assert_eq!(ALL_ROOMS.len(), GREEN_ROOMS.len() + YELLOW_ROOMS.len() + GINGER_ROOMS.len());
+            for room in ALL_ROOMS.iter() {
+                let colors = vec![GREEN_ROOMS, YELLOW_ROOMS, GINGER_ROOMS]
+                    .iter()
+                    .filter(|c| c.contains(room))
+                    .count();
+                assert_eq!(colors, 1);
+            }
+        }
+
+        #[test]
+        fn test_unknown_room() {
+            assert!(get_from_string("unknown").is_err());
+        }
+    }
+
+    #[cfg(test)]
+    mod test_room_price {
+        use test::base;
+
+        use constants::{ResourceType};
+        use models::game::{PlayerRoom};
+        use rooms::{constants as RoomConstants};
+        use rooms::core::get_from_string;
+
+        #[test]
+        fn test_carpenter_discount() {
+            let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p1").unwrap().rooms.push(PlayerRoom {
+                room_type: String::from(RoomConstants::CARPENTER),
+                position: 1,
+            });
+
+            let player = game.find_player("p1").unwrap();
+            let price = player.get_room_price(get_from_string(RoomConstants::DWELLING).unwrap());
+
+            assert_eq!(*price.get(&ResourceType::Wood.str_key()).unwrap(), 3);
+            assert_eq!(*price.get(&ResourceType::Stone.str_key()).unwrap(), 3);
+        }
+    }
+
+    #[cfg(test)]
+    mod test_bonus_points {
+        use test::base;
+
+        use constants::{ResourceType, InsideElement, OutsideElement, TRIBAL_ANIMALS};
+        use models::game::{PlayerRoom, PlayerCavern, PlayerField};
+        use rooms::{constants as RoomConstants};
+        use rooms::core::get_from_string;
+
+        #[test]
+        fn test_state_parlor() {
+            let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p1").unwrap().rooms = vec![
+                PlayerRoom { room_type: String::from(RoomConstants::STATE_PARLOR), position: 4 },
+                PlayerRoom { room_type: String::from(RoomConstants::DWELLING), position: 1 },
+                PlayerRoom { room_type: String::from(RoomConstants::DWELLING), position: 5 },
+                PlayerRoom { room_type: String::from(RoomConstants::DWELLING), position: 8 },
+            ];
+
+            let room = get_from_string(RoomConstants::STATE_PARLOR).unwrap();
+            assert_eq!(room.get_bonus_points(game.find_player("p1").unwrap()), 8);
+        }
+
+        #[test]
+        fn test_weaving_parlor() {
+            let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p1").unwrap().resources.insert(ResourceType::Sheep.str_key(), 5);
+
+            let room = get_from_string(RoomConstants::WEAVING_PARLOR).unwrap();
+            assert_eq!(room.get_bonus_points(game.find_player("p1").unwrap()), 2);
+        }
+
+        #[test]
+        fn test_writing_chamber_unused_spaces() {
+            let mut game = base::get_game_with_2_players();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                for animal in TRIBAL_ANIMALS.iter() {
+                    player.resources.insert(animal.str_key(), 1);
+                }
+                // Three forest spaces are left unused
+                player.caverns = (0..12).map(|position| PlayerCavern { cavern_type: InsideElement::Room, position }).collect();
+                player.fields = (0..9).map(|position| PlayerField { field_type: OutsideElement::Meadow, position }).collect();
+            }
+
+            let room = get_from_string(RoomConstants::WRITING_CHAMBER).unwrap();
+            assert_eq!(room.get_bonus_points(game.find_player("p1").unwrap()), 3);
+        }
+
+        #[test]
+        fn test_cuddle_room_slots() {
+            let mut game = base::get_game_with_2_players();
+            game.find_player_mut("p1").unwrap().rooms.push(PlayerRoom {
+                room_type: String::from(RoomConstants::CUDDLE_ROOM),
+                position: 1,
+            });
+
+            let player = game.find_player("p1").unwrap();
+            assert_eq!(player.get_resource_max_slots(&ResourceType::Sheep), 2);
+            assert_eq!(player.get_resource_max_slots(&ResourceType::Cow), 0);
+        }
+    }
+}
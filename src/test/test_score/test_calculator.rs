@@ -5,31 +5,103 @@ mod test {
     mod test_calculate_score {
         use test::base;
 
+        use constants::{ResourceType, InsideElement, OutsideElement};
+        use errors::{GameError};
+        use models::game::{PlayerCavern, PlayerField, PlayerRoom};
+        use rooms::{constants as RoomConstants};
         use score::calculator;
 
         #[test]
         fn test_no_resources() {
             let game = base::get_game_with_2_players();
-            let score = calculator::get_final_score(game.clone(), game.players.first().unwrap().name.as_str());
-            assert_eq!(score, -6); // 2 gnomes, no animals and no resources
+            let score = calculator::get_final_score(game.clone(), game.players.first().unwrap().name.as_str()).unwrap();
+            assert_eq!(score.gnomes, 2);
+            assert_eq!(score.missing_animals, -8);
+            assert_eq!(score.unused_spaces, -24);
+            assert_eq!(score.total(), -30); // 2 gnomes, no animals and empty boards
+        }
+
+        #[test]
+        fn test_unknown_player() {
+            let game = base::get_game_with_2_players();
+            match calculator::get_final_score(game, "p3") {
+                Err(GameError::UnknownPlayer(_)) => (),
+                _ => panic!("Expected an unknown player"),
+            }
         }
 
         #[test]
         fn test_gnomes_effect_on_score() {
             let mut game = base::get_game_with_2_players().clone();
-            game.get_player_mut(&String::from("p1")).gnomes += 1;
+            game.find_player_mut("p1").unwrap().gnomes += 1;
+            game.find_player_mut("p1").unwrap().child_gnomes += 1;
 
-            let score = calculator::get_final_score(game.clone(), &game.next.as_str());
-            assert_eq!(score, -5);
+            let score = calculator::get_final_score(game.clone(), &game.next.as_str()).unwrap();
+            assert_eq!(score.gnomes, 4);
+            assert_eq!(score.total(), -28);
         }
 
         #[test]
         fn test_animals_effect_on_score() {
             let mut game = base::get_game_with_2_players().clone();
-            game.get_player_mut(&String::from("p1")).gnomes += 1;
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.resources.insert(ResourceType::Sheep.str_key(), 3);
+                player.resources.insert(ResourceType::Dog.str_key(), 2);
+            }
+
+            let score = calculator::get_final_score(game.clone(), &game.next.as_str()).unwrap();
+            assert_eq!(score.animals, 5);
+            assert_eq!(score.missing_animals, -6);
+        }
+
+        #[test]
+        fn test_crops_and_treasures() {
+            let mut game = base::get_game_with_2_players().clone();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.resources.insert(ResourceType::Wheat.str_key(), 3);
+                player.resources.insert(ResourceType::Pumpkin.str_key(), 2);
+                player.resources.insert(ResourceType::Gem.str_key(), 4);
+                player.resources.insert(ResourceType::Gold.str_key(), 5);
+                player.fines = 1;
+            }
+
+            let score = calculator::get_final_score(game.clone(), &game.next.as_str()).unwrap();
+            assert_eq!(score.grain, 2);
+            assert_eq!(score.vegetables, 2);
+            assert_eq!(score.rubies, 4);
+            assert_eq!(score.gold, 5);
+            assert_eq!(score.begging, -3);
+        }
+
+        #[test]
+        fn test_boards_effect_on_score() {
+            let mut game = base::get_game_with_2_players().clone();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.caverns = vec![
+                    PlayerCavern { cavern_type: InsideElement::Room, position: 0 },
+                    PlayerCavern { cavern_type: InsideElement::Mine, position: 1 },
+                    PlayerCavern { cavern_type: InsideElement::MineHall, position: 2 },
+                    PlayerCavern { cavern_type: InsideElement::GemMine, position: 3 },
+                ];
+                player.rooms = vec![
+                    PlayerRoom { room_type: String::from(RoomConstants::ENTRY_LEVEL_DWELLING), position: 0 },
+                ];
+                player.fields = vec![
+                    PlayerField { field_type: OutsideElement::Fence, position: 0 },
+                    PlayerField { field_type: OutsideElement::LargeFence, position: 1 },
+                    PlayerField { field_type: OutsideElement::LargeFence, position: 2 },
+                    PlayerField { field_type: OutsideElement::Meadow, position: 3 },
+                ];
+                player.large_pastures = vec![(1, 2)];
+            }
 
-            let score = calculator::get_final_score(game.clone(), &game.next.as_str());
-            assert_eq!(score, -5);
+            let score = calculator::get_final_score(game.clone(), &game.next.as_str()).unwrap();
+            assert_eq!(score.unused_spaces, -16);
+            assert_eq!(score.pastures, 6);
+            assert_eq!(score.mines, 7);
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod test {
+    #[cfg(test)]
+    mod test_run_one_round {
+        use std::collections::HashMap;
+        use test::base;
+
+        use balance::utils::{BalanceConfig, generate_balance_config};
+        use constants::{GameStatus};
+        use simulation::{run_one_round};
+
+        #[test]
+        fn test_gnomes_left_without_free_space() {
+            let mut game = base::get_game_with_2_players();
+            game.available_moves = vec![game.available_moves[0].clone()];
+            game.players[1].moves = game.available_moves.clone();
+            game.players[1].moved_gnomes = 2;
+            let balance_config = generate_balance_config();
+            let configs: HashMap<String, &BalanceConfig> = hash_map! {
+                String::from("p1") => &balance_config,
+                String::from("p2") => &balance_config
+            };
+
+            run_one_round(&mut game, &base::get_moves_config(), &configs).unwrap();
+
+            assert_eq!(game.status, GameStatus::NextTurnPending);
+            assert_eq!(game.players[0].moved_gnomes, 0);
+        }
+    }
+    #[cfg(test)]
+    mod test_player_counts {
+        use std::collections::HashMap;
+        use serde_yaml;
+        use test::base;
+
+        use balance::utils::{BalanceConfig, generate_balance_config};
+        use constants::{GameStatus, ResourceType};
+        use models::schedule::{RoundSchedule};
+        use moves::feeding::{GNOME_FOOD, CHILD_GNOME_FOOD};
+        use simulation::{instantiate_game, run_one_round, run_feed_and_breed_round};
+
+        // Single round with a harvest, every player is left with food only, so feeding spends exactly what is needed
+        fn test_round_for_players(players_count: u32) {
+            let schedule: RoundSchedule = serde_yaml::from_str("
+stages:
+  - moves:
+      - name: blacksmithing
+    rounds:
+      - harvest: Normal
+").unwrap();
+            let moves_config = base::get_moves_config();
+            let balance_config = generate_balance_config();
+            let mut game = instantiate_game(players_count).unwrap();
+            let configs: HashMap<String, &BalanceConfig> = game.order
+                .iter()
+                .map(|name| (name.clone(), &balance_config))
+                .collect();
+
+            run_one_round(&mut game, &moves_config, &configs).unwrap();
+            assert_eq!(game.status, GameStatus::NextTurnPending);
+
+            for player in game.players.iter_mut() {
+                player.resources = hash_map! {
+                    ResourceType::Food.str_key() => 20
+                };
+            }
+            let before = game.clone();
+            run_feed_and_breed_round(&mut game, &moves_config, &schedule, &configs).unwrap();
+
+            assert_eq!(game.status, GameStatus::NextTurnCalculating);
+            for (player, fed) in before.players.iter().zip(game.players.iter()) {
+                let food_needed = player.gnomes * GNOME_FOOD + player.child_gnomes * CHILD_GNOME_FOOD;
+                assert_eq!(fed.get_resource(ResourceType::Food), 20 - food_needed);
+                assert_eq!(fed.fines, player.fines);
+            }
+        }
+
+        #[test]
+        fn test_solo_game() {
+            test_round_for_players(1);
+        }
+
+        #[test]
+        fn test_3_players_game() {
+            test_round_for_players(3);
+        }
+
+        #[test]
+        fn test_5_players_game() {
+            test_round_for_players(5);
+        }
+
+        #[test]
+        fn test_7_players_game() {
+            test_round_for_players(7);
+        }
+    }
+}
\ No newline at end of file
@@ -62,4 +62,80 @@ mod test {
             });
         }
     }
+
+    #[cfg(test)]
+    mod test_get_neighbour_slots {
+        use utils::get_neighbour_slots;
+
+        #[test]
+        fn test_corner_slot() {
+            assert_eq!(get_neighbour_slots(0), vec![1, 3]);
+        }
+
+        #[test]
+        fn test_middle_slot() {
+            assert_eq!(get_neighbour_slots(4), vec![3, 5, 1, 7]);
+        }
+    }
+    #[cfg(test)]
+    mod test_get_start_feeding_and_breeding_actions {
+        use test::base;
+
+        use constants::{ResourceType, OutsideElement, FeedingAndBreedingStatus};
+        use models::game::{PlayerSownField, PlayerField};
+        use utils::get_start_feeding_and_breeding_actions;
+
+        fn get_game() -> ::models::game::Game {
+            let mut game = base::get_game_with_2_players();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.fields = vec![PlayerField { field_type: OutsideElement::Field, position: 0 }];
+                player.sown_fields = vec![PlayerSownField { position: 0, crop: ResourceType::Wheat, count: 3 }];
+            }
+            game
+        }
+
+        #[test]
+        fn test_normal_harvest() {
+            let mut game = get_game();
+
+            get_start_feeding_and_breeding_actions(&game.clone(), FeedingAndBreedingStatus::Normal).unwrap().perform(&mut game);
+
+            base::assert_player_has_resource(&game, String::from("p1"), ResourceType::Wheat, 1);
+            assert_eq!(game.find_player("p1").unwrap().sown_fields[0].count, 2);
+        }
+
+        #[test]
+        fn test_no_field_phase() {
+            let mut game = get_game();
+
+            get_start_feeding_and_breeding_actions(&game.clone(), FeedingAndBreedingStatus::FeedByOne).unwrap().perform(&mut game);
+
+            assert_eq!(game.find_player("p1").unwrap().sown_fields[0].count, 3);
+        }
+
+        #[test]
+        fn test_field_phase_chosen_by_players() {
+            let mut game = get_game();
+
+            get_start_feeding_and_breeding_actions(&game.clone(), FeedingAndBreedingStatus::FeedingOrBreeding).unwrap().perform(&mut game);
+
+            assert_eq!(game.find_player("p1").unwrap().sown_fields[0].count, 3);
+        }
+
+        #[test]
+        fn test_dogs_placed_before_breeding() {
+            let mut game = get_game();
+            {
+                let player = game.find_player_mut("p1").unwrap();
+                player.fields.push(PlayerField { field_type: OutsideElement::Meadow, position: 1 });
+                player.resources.insert(ResourceType::Dog.str_key(), 2);
+            }
+
+            get_start_feeding_and_breeding_actions(&game.clone(), FeedingAndBreedingStatus::Normal).unwrap().perform(&mut game);
+
+            assert_eq!(game.find_player("p1").unwrap().dogs, vec![1, 1]);
+            assert_eq!(game.find_player("p1").unwrap().get_resource_max_slots(&ResourceType::Sheep), 3);
+        }
+    }
 }
\ No newline at end of file
@@ -1,13 +1,15 @@
 use actions::{
     MoveAction, Actions, NextUser, ReserveGnome, BlockMove, ChangeStatus, ReleaseMoves, OpenNewMove,
+    GrowUpChildren, HarvestFields, PlaceDogs, IncreaseTurn,
     SetFeedingAndBreedingStatus,
 };
-use constants::{GameStatus, FeedingAndBreedingStatus};
+use constants::{GameStatus, FeedingAndBreedingStatus, FieldPhase};
+use errors::{GameError};
 use models::game::{Game};
 use std::collections::{HashSet};
 
 
-pub fn get_player_move_actions(move_name: String, game: &Game) -> Actions {
+pub fn get_player_move_actions(move_name: String, game: &Game) -> Result<Actions, GameError> {
     let mut actions: Vec<Box<MoveAction>> = vec![
         Box::from(ReserveGnome {
             player: game.next.clone(),
@@ -17,7 +19,7 @@ pub fn get_player_move_actions(move_name: String, game: &Game) -> Actions {
             player_move: move_name,
         }),
         Box::from(NextUser {
-            player: game.get_next_user(),
+            player: game.get_next_user()?,
         })
     ];
 
@@ -27,18 +29,20 @@ pub fn get_player_move_actions(move_name: String, game: &Game) -> Actions {
         }));
     }
 
-    Actions::from_vec(actions)
+    Ok(Actions::from_vec(actions))
 }
 
 
-pub fn get_game_turn_actions(game: &Game, new_move: Option<&str>) -> Actions {
+pub fn get_game_turn_actions(game: &Game, new_move: Option<&str>) -> Result<Actions, GameError> {
     let mut actions: Vec<Box<MoveAction>> = vec![
         Box::from(ChangeStatus {
             status: GameStatus::PlayerMove,
         }),
+        Box::from(IncreaseTurn {}),
+        Box::from(GrowUpChildren {}),
         Box::from(ReleaseMoves {}),
         Box::from(NextUser {
-            player: (*game.order.first().unwrap()).clone(),
+            player: game.get_first_user()?,
         }),
     ];
     match new_move {
@@ -47,21 +51,40 @@ pub fn get_game_turn_actions(game: &Game, new_move: Option<&str>) -> Actions {
         })),
         None => (),
     };
-    Actions::from_vec(actions)
+    Ok(Actions::from_vec(actions))
 }
 
-pub fn get_start_feeding_and_breeding_actions(game: &Game, status: FeedingAndBreedingStatus) -> Actions {
-    Actions::from_vec(vec![
+pub fn get_start_feeding_and_breeding_actions(game: &Game, status: FeedingAndBreedingStatus) -> Result<Actions, GameError> {
+    let field_phase = status.get_field_phase() == FieldPhase::Harvest;
+    let mut actions: Vec<Box<MoveAction>> = vec![
         Box::from(ChangeStatus {
             status: GameStatus::FeedingAndBreeding,
         }),
         Box::from(NextUser {
-            player: (*game.order.first().unwrap()).clone(),
+            player: game.get_first_user()?,
         }),
         Box::from(SetFeedingAndBreedingStatus {
             status,
         }),
-    ])
+    ];
+    if field_phase {
+        for player in game.players.iter().rev() {
+            actions.insert(0, Box::from(HarvestFields {
+                player: player.name.clone(),
+            }));
+        }
+    }
+    // Dogs are moved to guard as many sheep as possible before breeding
+    for player in game.players.iter() {
+        let dogs = player.get_best_dog_slots();
+        if dogs != player.dogs {
+            actions.push(Box::from(PlaceDogs {
+                player: player.name.clone(),
+                dogs,
+            }));
+        }
+    }
+    Ok(Actions::from_vec(actions))
 }
 
 pub fn get_available_slots(reserved_slots: Vec<u32>) -> HashSet<u32> {
@@ -89,6 +112,24 @@ pub fn get_available_slots(reserved_slots: Vec<u32>) -> HashSet<u32> {
     result
 }
 
+pub fn get_neighbour_slots(slot: u32) -> Vec<u32> {
+    let mut result: Vec<u32> = Vec::new();
+    let (i, j) = (slot % 3, slot / 3);
+    if i > 0 {
+        result.push(slot - 1);
+    }
+    if i < 2 {
+        result.push(slot + 1);
+    }
+    if j > 0 {
+        result.push(slot - 3);
+    }
+    if j < 3 {
+        result.push(slot + 3);
+    }
+    result
+}
+
 pub fn get_available_pair_slots(reserved_slots: Vec<u32>) -> HashSet<(u32, u32)> {
     let mut result: HashSet<(u32, u32)> = HashSet::new();
     let available_slots = get_available_slots(reserved_slots.clone());